@@ -1,24 +1,46 @@
 /* IMPORTS FROM LIBRARIES */
+use actix_multipart::Multipart;
 use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use lazy_static::lazy_static;
 use serde::Serialize;
 use std::sync::Mutex;
 
 /* IMPORTS FROM OTHER MODULES */
+use crate::api::api_messages::{
+    serialize_error, serialize_evaluation_error, serialize_evaluation_result,
+    serialize_model_does_not_fit,
+};
+use crate::api::evaluation_job::{evaluate_and_store_order, EvaluationOutcome};
+use crate::api::multipart_form_impl::read_order_from_multipart;
 use crate::api::web_socket_impl::PriceEvaluationWebSocketImpl;
+use crate::common_utils::background_job_pool::{BackgroundJob, BackgroundJobPool};
 use crate::common_utils::global_traits::WebSocketInterfaceImpl;
-use crate::common_utils::global_types::{EvaluationResult, PrintMaterialType};
+use crate::common_utils::global_types::{
+    validate_copies_nbr, EvaluatedPrintingParameters, EvaluationResult, ModelStatistics,
+    PriceBreakdown, PriceSimulation, PricingUpdateError, PrinterConfiguration, SlicerError,
+    MAX_FILAMENT_G, MAX_FILAMENT_MM, MAX_PRINT_TIME_S,
+};
+use crate::common_utils::material_catalogue::MaterialPrice;
+use crate::common_utils::model_storage::received_model_path;
+use crate::common_utils::money::Money;
+use crate::common_utils::pricing_versions::{diff_configurations, PricingVersion};
 use crate::database_handler::{
     add_evaluation_to_db, modify_completed_order_in_database, modify_new_order_in_database,
-    read_completed_orders_from_db, read_orders_from_db,
+    read_completed_orders_from_db, read_order_from_db, read_orders_from_db,
+    read_pricing_version_from_db, read_pricing_versions_from_db,
+};
+use crate::prusa_slicer_interface::{
+    find_catalogued_material, get_material_catalogue, get_pricing_parameters, get_pricing_version,
+    get_prusa_slicer_evaluation, preview_pricing_update, reload_printer_configuration,
+    reprice_order, simulate_price, update_pricing_parameters,
 };
-use crate::prusa_slicer_interface::get_prusa_slicer_evaluation;
 use serde::Deserialize;
 
 /* PRIVATE TYPES AND VARIABLES */
 struct State {
     app_init_status: Mutex<bool>,
     websocket_session: Mutex<PriceEvaluationWebSocketImpl>,
+    slicing_job_pool: Mutex<Option<BackgroundJobPool>>,
 }
 
 lazy_static! {
@@ -27,13 +49,56 @@ lazy_static! {
         websocket_session: Mutex::new(PriceEvaluationWebSocketImpl {
             add_evaluation_to_db_cb: add_evaluation_to_db,
             evaluate_order_cb: get_prusa_slicer_evaluation,
+            submit_slicing_job_cb: submit_slicing_job,
+            find_material_cb: find_catalogued_material,
         }),
+        slicing_job_pool: Mutex::new(None),
     };
 }
 
 /* PUBLIC TYPES AND VARIABLES */
 
 /* PRIVATE FUNCTIONS */
+fn submit_slicing_job(job: BackgroundJob) -> std::io::Result<usize> {
+    match API_HANDLER_STATE.slicing_job_pool.lock().unwrap().as_ref() {
+        Some(slicing_job_pool) => slicing_job_pool.submit(job),
+        None => Err(std::io::Error::new(
+            std::io::ErrorKind::NotConnected,
+            "Slicing job pool is not initialized",
+        )),
+    }
+}
+
+/**
+ * @brief Checks that a simulated print job is within the range prices can be calculated for.
+ *
+ * @param simulation Print job to price.
+ * @return Option<String> Why the job is rejected, None if it can be priced.
+ */
+fn simulation_input_error(simulation: &PriceSimulation) -> Option<String> {
+    if let Err(message) = validate_copies_nbr(simulation.copies_nbr) {
+        return Some(message);
+    }
+    if simulation.time > MAX_PRINT_TIME_S {
+        return Some(format!("time must be at most {} seconds", MAX_PRINT_TIME_S));
+    }
+    if simulation.material_mm.is_none() && simulation.material_g.is_none() {
+        return Some("Either material_mm or material_g is required".to_string());
+    }
+    if simulation
+        .material_mm
+        .is_some_and(|material_mm| material_mm > MAX_FILAMENT_MM)
+    {
+        return Some(format!("material_mm must be at most {}", MAX_FILAMENT_MM));
+    }
+    match simulation.material_g {
+        Some(material_g) if !(0.0..=MAX_FILAMENT_G).contains(&material_g) => Some(format!(
+            "material_g must be a number between 0 and {}",
+            MAX_FILAMENT_G
+        )),
+        _ => None,
+    }
+}
 
 /* PUBLIC FUNCTIONS */
 /**
@@ -48,6 +113,21 @@ pub fn initialize_api_handler(app_init_status: bool) {
     *app_init_status_lock = app_init_status;
 }
 
+/**
+ * @brief Starts the pool of background workers that slice submitted models.
+ *
+ * @param worker_count Number of models sliced concurrently.
+ * @param queue_capacity Maximum number of models waiting for a free worker.
+ */
+pub fn initialize_slicing_job_pool(worker_count: usize, queue_capacity: usize) {
+    let mut slicing_job_pool_lock = API_HANDLER_STATE.slicing_job_pool.lock().unwrap();
+    *slicing_job_pool_lock = Some(BackgroundJobPool::new(
+        "slicing-worker",
+        worker_count,
+        queue_capacity,
+    ));
+}
+
 /**
  * @brief Handles the application initialization status API endpoint.
  *
@@ -82,6 +162,176 @@ pub async fn eval_result_websocket_handler(req: HttpRequest, stream: web::Payloa
         .await
 }
 
+/**
+ * @brief Handles the multipart HTTP evaluation endpoint.
+ *
+ * This function is the HTTP counterpart of the WebSocket evaluation flow. It reads the order
+ * fields and the STL model from multipart form data, queues the order for slicing, and once
+ * it is priced and stored responds with the same JSON message the WebSocket session sends.
+ *
+ * @param payload Multipart form data with the order fields and the `file` field.
+ * @return impl Responder HTTP response with the evaluation result in JSON format.
+ */
+pub async fn evaluate_order_handler(payload: Multipart) -> impl Responder {
+    let order = match read_order_from_multipart(payload).await {
+        Ok(order) => order,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidInput => {
+            return HttpResponse::BadRequest().body(e.to_string());
+        }
+        Err(e) => {
+            return HttpResponse::InternalServerError()
+                .body(format!("Failed to store the uploaded file: {}", e));
+        }
+    };
+    if find_catalogued_material(&order.material_type).is_none() {
+        if let Ok(model_path) = received_model_path(".", &order.model_key) {
+            std::fs::remove_file(model_path).ok();
+        }
+        return HttpResponse::BadRequest()
+            .content_type("application/json")
+            .body(serialize_error(
+                "unknown_material",
+                &SlicerError::UnknownMaterial(order.material_type).to_string(),
+            ));
+    }
+    let file_name = order.file_name.clone();
+    let (outcome_sender, outcome_receiver) = tokio::sync::oneshot::channel();
+    let slicing_job: BackgroundJob = Box::new(move || {
+        let outcome = evaluate_and_store_order(
+            ".",
+            &order,
+            get_prusa_slicer_evaluation,
+            add_evaluation_to_db,
+        );
+        outcome_sender.send(outcome).ok();
+    });
+    if let Err(e) = submit_slicing_job(slicing_job) {
+        return HttpResponse::ServiceUnavailable()
+            .content_type("application/json")
+            .body(serialize_error("queue_full", &e.to_string()));
+    }
+    let evaluation_result = match outcome_receiver.await {
+        Ok(EvaluationOutcome::Evaluated(evaluation_result)) => *evaluation_result,
+        Ok(EvaluationOutcome::ModelRejected(message)) => {
+            return HttpResponse::BadRequest()
+                .content_type("application/json")
+                .body(serialize_error("invalid_model", &message));
+        }
+        Ok(EvaluationOutcome::ModelDoesNotFit(fit)) => {
+            return HttpResponse::UnprocessableEntity()
+                .content_type("application/json")
+                .body(serialize_model_does_not_fit(&file_name, fit));
+        }
+        Ok(EvaluationOutcome::EvaluationFailed(e)) => {
+            let response_body = serialize_evaluation_error(&e);
+            return match e {
+                SlicerError::ModelUnprintable(_) => HttpResponse::UnprocessableEntity(),
+                SlicerError::UnknownMaterial(_) | SlicerError::PriceOutOfRange(_) => {
+                    HttpResponse::BadRequest()
+                }
+                _ => HttpResponse::InternalServerError(),
+            }
+            .content_type("application/json")
+            .body(response_body);
+        }
+        Ok(EvaluationOutcome::DatabaseFailed) => {
+            return HttpResponse::InternalServerError().body("Internal database error");
+        }
+        Ok(EvaluationOutcome::JobPanicked) | Err(_) => {
+            return HttpResponse::InternalServerError().body("Slicing job was aborted");
+        }
+    };
+    HttpResponse::Ok()
+        .content_type("application/json")
+        .body(serialize_evaluation_result(evaluation_result))
+}
+
+/**
+ * @brief Handles the API endpoint listing the materials orders may be printed in.
+ *
+ * @return impl Responder HTTP response with the material catalogue in JSON format.
+ */
+pub async fn get_materials_handler() -> impl Responder {
+    #[derive(Serialize)]
+    struct CatalogueEntry {
+        name: String,
+        density_g_cm3: f64,
+        price: MaterialPrice,
+    }
+    let materials: Vec<CatalogueEntry> = get_material_catalogue()
+        .into_iter()
+        .map(|material| CatalogueEntry {
+            name: material.name,
+            density_g_cm3: material.density_g_cm3,
+            price: material.price,
+        })
+        .collect();
+    HttpResponse::Ok().json(materials)
+}
+
+/**
+ * @brief Handles the admin API endpoint reloading the pricing configuration from its file.
+ *
+ * @return impl Responder HTTP response confirming the reload, or describing why the new
+ *         configuration was rejected while the current one stays in use.
+ */
+pub async fn reload_pricing_handler() -> impl Responder {
+    match reload_printer_configuration() {
+        Ok(()) => HttpResponse::Ok().json(serde_json::json!({
+            "status": "reloaded",
+            "materials": get_material_catalogue().len(),
+            "pricing_version": get_pricing_version(),
+        })),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            HttpResponse::NotFound().body(e.to_string())
+        }
+        Err(e) => HttpResponse::UnprocessableEntity().body(e.to_string()),
+    }
+}
+
+/**
+ * @brief Handles the admin API endpoint returning the current pricing parameters.
+ *
+ * @return impl Responder HTTP response with the configuration and its pricing version.
+ */
+pub async fn get_pricing_parameters_handler() -> impl Responder {
+    let (configuration, pricing_version) = get_pricing_parameters();
+    HttpResponse::Ok().json(serde_json::json!({
+        "pricing_version": pricing_version,
+        "configuration": configuration,
+    }))
+}
+
+/**
+ * @brief Handles the admin API endpoint changing some of the pricing parameters.
+ *
+ * The changed parameters are saved to the configuration file and used for all new quotes.
+ *
+ * @param update Changed fields of the configuration, other fields keep their values.
+ * @return impl Responder HTTP response with the new configuration, or the invalid fields.
+ */
+pub async fn update_pricing_parameters_handler(
+    update: web::Json<serde_json::Value>,
+) -> impl Responder {
+    match update_pricing_parameters(&update) {
+        Ok(()) => {
+            let (configuration, pricing_version) = get_pricing_parameters();
+            HttpResponse::Ok().json(serde_json::json!({
+                "pricing_version": pricing_version,
+                "configuration": configuration,
+            }))
+        }
+        Err(PricingUpdateError::Invalid(errors)) => {
+            HttpResponse::UnprocessableEntity().json(serde_json::json!({ "errors": errors }))
+        }
+        Err(PricingUpdateError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            HttpResponse::NotFound().body(e.to_string())
+        }
+        Err(PricingUpdateError::Io(e)) => HttpResponse::InternalServerError()
+            .body(format!("Failed to save the pricing parameters: {}", e)),
+    }
+}
+
 /**
  * @brief Handles the API endpoint to retrieve orders.
  *
@@ -92,21 +342,28 @@ pub async fn eval_result_websocket_handler(req: HttpRequest, stream: web::Payloa
 pub async fn get_orders_handler() -> impl Responder {
     #[derive(Serialize)]
     struct Order {
+        id: i64,
         date: String,
         name: String,
         email: String,
         copies_nbr: u32,
         file_name: String,
-        price: f64,
-        material_type: PrintMaterialType,
+        price: Money,
+        material_type: String,
         print_type: String,
         status: String,
+        model_key: String,
+        model_statistics: Option<ModelStatistics>,
+        price_breakdown: Option<PriceBreakdown>,
+        print_parameters: Option<EvaluatedPrintingParameters>,
+        pricing_version: Option<i64>,
     }
     match read_orders_from_db() {
         Ok(orders) => {
             let orders_json: Vec<Order> = orders
                 .into_iter()
                 .map(|order: EvaluationResult| Order {
+                    id: order.id.unwrap_or_default(),
                     date: order.date.to_string(),
                     name: order.name,
                     email: order.email,
@@ -116,6 +373,11 @@ pub async fn get_orders_handler() -> impl Responder {
                     material_type: order.material_type,
                     print_type: order.print_type.to_string(),
                     status: order.status.to_string(),
+                    model_key: order.model_key,
+                    model_statistics: order.model_statistics,
+                    price_breakdown: order.price_breakdown,
+                    print_parameters: order.print_parameters,
+                    pricing_version: order.pricing_version,
                 })
                 .collect();
             HttpResponse::Ok().json(orders_json)
@@ -129,21 +391,28 @@ pub async fn get_orders_handler() -> impl Responder {
 pub async fn get_completed_orders_handler() -> impl Responder {
     #[derive(Serialize)]
     struct Order {
+        id: i64,
         date: String,
         name: String,
         email: String,
         copies_nbr: u32,
         file_name: String,
-        price: f64,
-        material_type: PrintMaterialType,
+        price: Money,
+        material_type: String,
         print_type: String,
         status: String,
+        model_key: String,
+        model_statistics: Option<ModelStatistics>,
+        price_breakdown: Option<PriceBreakdown>,
+        print_parameters: Option<EvaluatedPrintingParameters>,
+        pricing_version: Option<i64>,
     }
     match read_completed_orders_from_db() {
         Ok(orders) => {
             let orders_json: Vec<Order> = orders
                 .into_iter()
                 .map(|order: EvaluationResult| Order {
+                    id: order.id.unwrap_or_default(),
                     date: order.date.to_string(),
                     name: order.name,
                     email: order.email,
@@ -153,6 +422,11 @@ pub async fn get_completed_orders_handler() -> impl Responder {
                     material_type: order.material_type,
                     print_type: order.print_type.to_string(),
                     status: order.status.to_string(),
+                    model_key: order.model_key,
+                    model_statistics: order.model_statistics,
+                    price_breakdown: order.price_breakdown,
+                    print_parameters: order.print_parameters,
+                    pricing_version: order.pricing_version,
                 })
                 .collect();
             HttpResponse::Ok().json(orders_json)
@@ -162,22 +436,224 @@ pub async fn get_completed_orders_handler() -> impl Responder {
     }
 }
 
+/**
+ * @brief Reads a stored pricing version.
+ *
+ * @param version_id ID of the version.
+ * @return std::io::Result<PricingVersion> The version, or an error of kind `NotFound` if no
+ *         version has the ID.
+ */
+fn find_pricing_version(version_id: i64) -> std::io::Result<PricingVersion> {
+    read_pricing_version_from_db(version_id)?.ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("Pricing version {} does not exist", version_id),
+        )
+    })
+}
+
+fn pricing_version_error_response(e: std::io::Error) -> HttpResponse {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        HttpResponse::NotFound().body(e.to_string())
+    } else {
+        HttpResponse::InternalServerError().body(format!("Failed to read pricing version: {}", e))
+    }
+}
+
+/**
+ * @brief Handles the admin API endpoint listing the stored pricing configuration versions.
+ *
+ * @return impl Responder HTTP response with every version, oldest first, and the ID of the
+ *         version orders are currently priced with.
+ */
+pub async fn get_pricing_versions_handler() -> impl Responder {
+    #[derive(Serialize)]
+    struct Version {
+        id: i64,
+        hash: String,
+        created_at: String,
+        configuration: serde_json::Value,
+    }
+    match read_pricing_versions_from_db() {
+        Ok(versions) => {
+            let versions: Vec<Version> = versions
+                .into_iter()
+                .map(|version| Version {
+                    id: version.id,
+                    hash: version.hash,
+                    created_at: version.created_at.to_string(),
+                    configuration: version.configuration,
+                })
+                .collect();
+            HttpResponse::Ok().json(serde_json::json!({
+                "current_version": get_pricing_version(),
+                "versions": versions,
+            }))
+        }
+        Err(e) => HttpResponse::InternalServerError()
+            .body(format!("Failed to retrieve pricing versions: {}", e)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PricingVersionsDiffQuery {
+    from: i64,
+    to: i64,
+}
+
+/**
+ * @brief Handles the admin API endpoint comparing two pricing configuration versions.
+ *
+ * @param query IDs of the older (`from`) and newer (`to`) version.
+ * @return impl Responder HTTP response with every value that differs between the versions.
+ */
+pub async fn pricing_versions_diff_handler(
+    query: web::Query<PricingVersionsDiffQuery>,
+) -> impl Responder {
+    let from = match find_pricing_version(query.from) {
+        Ok(version) => version,
+        Err(e) => return pricing_version_error_response(e),
+    };
+    let to = match find_pricing_version(query.to) {
+        Ok(version) => version,
+        Err(e) => return pricing_version_error_response(e),
+    };
+    HttpResponse::Ok().json(serde_json::json!({
+        "from": from.id,
+        "to": to.id,
+        "changes": diff_configurations(&from.configuration, &to.configuration),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct OrderRepricing {
+    order_id: i64,
+    version: i64,
+}
+
+/**
+ * @brief Handles the admin API endpoint pricing a stored order under another pricing version.
+ *
+ * The stored order keeps its price; the response only shows what it would cost.
+ *
+ * @param payload ID of the order and of the pricing version to price it with.
+ * @return impl Responder HTTP response with the original and the new price.
+ */
+pub async fn reprice_order_handler(payload: web::Json<OrderRepricing>) -> impl Responder {
+    let order = match read_order_from_db(payload.order_id) {
+        Ok(Some(order)) => order,
+        Ok(None) => {
+            return HttpResponse::NotFound()
+                .body(format!("Order {} does not exist", payload.order_id))
+        }
+        Err(e) => {
+            return HttpResponse::InternalServerError()
+                .body(format!("Failed to retrieve order: {}", e))
+        }
+    };
+    if order.print_parameters.is_none() {
+        return HttpResponse::Conflict().body(format!(
+            "Order {} was priced before print parameters were recorded and cannot be re-priced",
+            payload.order_id
+        ));
+    }
+    let version = match find_pricing_version(payload.version) {
+        Ok(version) => version,
+        Err(e) => return pricing_version_error_response(e),
+    };
+    let printer_configuration: PrinterConfiguration =
+        match serde_json::from_value(version.configuration) {
+            Ok(printer_configuration) => printer_configuration,
+            Err(e) => {
+                return HttpResponse::InternalServerError().body(format!(
+                    "Pricing version {} cannot be read: {}",
+                    version.id, e
+                ))
+            }
+        };
+    match reprice_order(&order, &printer_configuration) {
+        Ok(price_breakdown) => HttpResponse::Ok().json(serde_json::json!({
+            "order_id": payload.order_id,
+            "original_version": order.pricing_version,
+            "original_price": order.price,
+            "version": version.id,
+            "price": price_breakdown.total,
+            "price_breakdown": price_breakdown,
+        })),
+        Err(e) => HttpResponse::UnprocessableEntity().json(serde_json::json!({
+            "code": e.code(),
+            "message": e.to_string(),
+        })),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PriceSimulationRequest {
+    #[serde(flatten)]
+    simulation: PriceSimulation,
+    /// Changed pricing parameters to price the job with instead of the current configuration.
+    #[serde(default)]
+    configuration: Option<serde_json::Value>,
+}
+
+/**
+ * @brief Handles the API endpoint pricing a print job from its print time and filament usage.
+ *
+ * Nothing is sliced or stored, so rates can be tried out on known jobs. With a candidate
+ * `configuration`, in the form accepted by the pricing admin API, the job is priced as if the
+ * configuration was changed, while new quotes keep the current one.
+ *
+ * @param payload Print job, and optionally the candidate pricing parameters.
+ * @return impl Responder HTTP response with the price and its breakdown.
+ */
+pub async fn simulate_price_handler(payload: web::Json<PriceSimulationRequest>) -> impl Responder {
+    let simulation = &payload.simulation;
+    if let Some(message) = simulation_input_error(simulation) {
+        return HttpResponse::BadRequest()
+            .content_type("application/json")
+            .body(serialize_error("invalid_simulation", &message));
+    }
+    let candidate_configuration = match &payload.configuration {
+        Some(update) => match preview_pricing_update(update) {
+            Ok(candidate_configuration) => Some(candidate_configuration),
+            Err(errors) => {
+                return HttpResponse::UnprocessableEntity()
+                    .json(serde_json::json!({ "errors": errors }))
+            }
+        },
+        None => None,
+    };
+    match simulate_price(simulation, candidate_configuration.as_ref()) {
+        Ok((print_parameters, price_breakdown)) => HttpResponse::Ok().json(serde_json::json!({
+            "pricing_version": match candidate_configuration {
+                Some(_) => None,
+                None => get_pricing_version(),
+            },
+            "print_parameters": print_parameters,
+            "price": price_breakdown.total,
+            "price_breakdown": price_breakdown,
+        })),
+        Err(e) => HttpResponse::BadRequest()
+            .content_type("application/json")
+            .body(serialize_error(e.code(), &e.to_string())),
+    }
+}
+
 #[derive(Deserialize)]
 pub struct OrderModification {
-    datetime: String,
+    id: i64,
     new_status: String,
     // Add any other modifiable fields
 }
 
-pub async fn modify_order_handler(payload: web::Json<OrderModification>) -> impl Responder {
-    // Process the modification
-    match modify_new_order_in_database(&payload.datetime, &payload.new_status) {
-        Ok(_) => {
-            // For now, just return success (replace with actual implementation)
-            HttpResponse::Ok().json(serde_json::json!({
-                "success": true,
-                "message": "Order modified successfully",
-            }))
+fn order_modification_response(result: std::io::Result<()>) -> HttpResponse {
+    match result {
+        Ok(_) => HttpResponse::Ok().json(serde_json::json!({
+            "success": true,
+            "message": "Order modified successfully",
+        })),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            HttpResponse::NotFound().body(format!("Failed to modify order: {}", e))
         }
         Err(e) => {
             HttpResponse::InternalServerError().body(format!("Failed to modify order: {}", e))
@@ -185,22 +661,20 @@ pub async fn modify_order_handler(payload: web::Json<OrderModification>) -> impl
     }
 }
 
+pub async fn modify_order_handler(payload: web::Json<OrderModification>) -> impl Responder {
+    order_modification_response(modify_new_order_in_database(
+        payload.id,
+        &payload.new_status,
+    ))
+}
+
 pub async fn modify_completed_order_handler(
     payload: web::Json<OrderModification>,
 ) -> impl Responder {
-    // Process the modification
-    match modify_completed_order_in_database(&payload.datetime, &payload.new_status) {
-        Ok(_) => {
-            // For now, just return success (replace with actual implementation)
-            HttpResponse::Ok().json(serde_json::json!({
-                "success": true,
-                "message": "Order modified successfully",
-            }))
-        }
-        Err(e) => {
-            HttpResponse::InternalServerError().body(format!("Failed to modify order: {}", e))
-        }
-    }
+    order_modification_response(modify_completed_order_in_database(
+        payload.id,
+        &payload.new_status,
+    ))
 }
 
 /* TESTS */
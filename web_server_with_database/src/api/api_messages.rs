@@ -0,0 +1,232 @@
+/* IMPORTS FROM LIBRARIES */
+use serde::Serialize;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::{
+    BuildVolumeFit, EvaluationResult, PriceBreakdown, SlicerError,
+};
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Envelope wrapping every message sent to clients.
+#[derive(Serialize)]
+struct Envelope<'a> {
+    version: u32,
+    #[serde(flatten)]
+    message: &'a ServerMessage,
+}
+
+/* PUBLIC TYPES AND VARIABLES */
+/// Version of the message protocol, bumped on incompatible changes to the envelope or payloads.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Messages sent to clients of the evaluation API.
+///
+/// Each message is serialized as `{"version": .., "type": .., "data": {..}}`, where `data` is
+/// omitted for messages without a payload.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// The upload was registered; `token` resumes it after the connection drops.
+    UploadToken {
+        token: String,
+        file_name: String,
+        total: u32,
+    },
+    /// An interrupted upload was found; the client continues after the `received` intact chunks.
+    UploadResumed {
+        token: String,
+        file_name: String,
+        received: u32,
+        total: u32,
+        checksums: Vec<String>,
+    },
+    /// A chunk of the model was stored; `checksum` is the CRC32 of the chunk as hex.
+    ChunkAck {
+        received: u32,
+        total: u32,
+        checksum: String,
+    },
+    /// All chunks of the model were stored.
+    UploadComplete { file_name: String },
+    /// The order waits for a free slicing worker behind `position` other orders.
+    Queued { file_name: String, position: usize },
+    /// A worker started slicing the model.
+    SlicingStarted { file_name: String },
+    /// The slicer finished, successfully or not; the outcome follows.
+    SlicingFinished { file_name: String },
+    /// The order was priced and stored.
+    EvaluationResult {
+        id: Option<i64>,
+        name: String,
+        email: String,
+        copies_nbr: u32,
+        file_name: String,
+        price: String,
+        price_breakdown: Option<Box<PriceBreakdown>>,
+        material_type: String,
+        print_type: String,
+        status: String,
+        /// The price was estimated from the geometry of the model instead of a sliced G-code.
+        estimated: bool,
+        message: String,
+    },
+    /// The model is larger than the printer; `fit` tells whether rotating or scaling it helps.
+    ModelDoesNotFit {
+        file_name: String,
+        message: String,
+        fit: BuildVolumeFit,
+    },
+    /// The request failed; `code` is a stable identifier of the failure.
+    Error { code: String, message: String },
+}
+
+/* PRIVATE FUNCTIONS */
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Formats a chunk checksum the way it is sent to clients.
+ *
+ * @param checksum CRC32 of the chunk.
+ * @return String Checksum as 8 lowercase hex digits.
+ */
+pub fn format_checksum(checksum: u32) -> String {
+    format!("{:08x}", checksum)
+}
+
+impl ServerMessage {
+    /**
+     * @brief Serializes the message into its versioned JSON envelope.
+     *
+     * @return String JSON representation of the message.
+     */
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&Envelope {
+            version: PROTOCOL_VERSION,
+            message: self,
+        })
+        .expect("Server messages are always serializable")
+    }
+}
+
+/**
+ * @brief Serializes an evaluation result into the JSON message sent to clients.
+ *
+ * The same message is sent over the WebSocket and returned by the HTTP evaluation endpoint.
+ * Prices estimated without slicing are flagged and explained in the message.
+ *
+ * @param eval_result Evaluation result to serialize.
+ * @return String JSON representation of the evaluation result.
+ */
+pub fn serialize_evaluation_result(eval_result: EvaluationResult) -> String {
+    let estimated = eval_result
+        .print_parameters
+        .as_ref()
+        .is_some_and(|print_parameters| print_parameters.estimated);
+    let message = if estimated {
+        "Evaluation completed. The price is an estimate, the model was not sliced."
+    } else {
+        "Evaluation completed successfully."
+    };
+    ServerMessage::EvaluationResult {
+        id: eval_result.id,
+        name: eval_result.name,
+        email: eval_result.email,
+        copies_nbr: eval_result.copies_nbr,
+        file_name: eval_result.file_name,
+        price: eval_result.price.to_string(),
+        price_breakdown: eval_result.price_breakdown.map(Box::new),
+        material_type: eval_result.material_type.to_string(),
+        print_type: eval_result.print_type.to_string(),
+        status: "success".to_string(),
+        estimated,
+        message: message.to_string(),
+    }
+    .to_json()
+}
+
+/**
+ * @brief Serializes a failed evaluation into the JSON error message sent to clients.
+ *
+ * @param error Reason why the order could not be evaluated.
+ * @return String JSON representation of the error.
+ */
+pub fn serialize_evaluation_error(error: &SlicerError) -> String {
+    serialize_error(error.code(), &error.to_string())
+}
+
+/**
+ * @brief Serializes the rejection of a model larger than the build volume of the printer.
+ *
+ * @param file_name Name of the rejected model.
+ * @param fit Result of the build volume check.
+ * @return String JSON representation of the rejection.
+ */
+pub fn serialize_model_does_not_fit(file_name: &str, fit: BuildVolumeFit) -> String {
+    let [x, y, z] = fit.model_size;
+    let [max_x, max_y, max_z] = fit.build_volume;
+    let hint = if fit.fits_when_rotated {
+        "It fits when rotated, please reorient it.".to_string()
+    } else {
+        format!(
+            "Scaling it down to {:.0}% would make it fit.",
+            (fit.max_uniform_scale * 100.0).floor()
+        )
+    };
+    ServerMessage::ModelDoesNotFit {
+        file_name: file_name.to_string(),
+        message: format!(
+            "The model ({:.1} x {:.1} x {:.1} mm) does not fit the build volume of the printer ({:.0} x {:.0} x {:.0} mm). {}",
+            x, y, z, max_x, max_y, max_z, hint
+        ),
+        fit,
+    }
+    .to_json()
+}
+
+/**
+ * @brief Serializes an error into the JSON error message sent to clients.
+ *
+ * @param code Stable identifier of the error kind.
+ * @param message Human-readable description of the error.
+ * @return String JSON representation of the error.
+ */
+pub fn serialize_error(code: &str, message: &str) -> String {
+    ServerMessage::Error {
+        code: code.to_string(),
+        message: message.to_string(),
+    }
+    .to_json()
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_use_versioned_envelope() {
+        let chunk_ack: serde_json::Value = serde_json::from_str(
+            &ServerMessage::ChunkAck {
+                received: 2,
+                total: 5,
+                checksum: format_checksum(0xbeef),
+            }
+            .to_json(),
+        )
+        .unwrap();
+        assert_eq!(
+            chunk_ack,
+            serde_json::json!({
+                "version": PROTOCOL_VERSION,
+                "type": "chunk_ack",
+                "data": {"received": 2, "total": 5, "checksum": "0000beef"}
+            })
+        );
+
+        let error: serde_json::Value =
+            serde_json::from_str(&serialize_error("queue_full", "Try again later")).unwrap();
+        assert_eq!(error["version"], PROTOCOL_VERSION);
+        assert_eq!(error["type"], "error");
+        assert_eq!(error["data"]["code"], "queue_full");
+    }
+}
@@ -0,0 +1,201 @@
+/* IMPORTS FROM LIBRARIES */
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::{
+    BuildVolumeFit, EvaluationResult, SlicerError, SubmittedOrderData,
+};
+use crate::common_utils::model_storage::received_model_path;
+use crate::model_inspection::inspect_stl_file;
+use crate::prusa_slicer_interface::check_model_fits_build_volume;
+
+/* PRIVATE TYPES AND VARIABLES */
+
+/* PUBLIC TYPES AND VARIABLES */
+pub type EvaluateOrderCb = fn(&SubmittedOrderData) -> Result<EvaluationResult, SlicerError>;
+pub type AddEvaluationToDbCb = fn(&EvaluationResult) -> io::Result<i64>;
+
+/// Result of a slicing job, handed back to whoever submitted the order.
+pub enum EvaluationOutcome {
+    /// The order was priced and stored under the ID set in the result.
+    Evaluated(Box<EvaluationResult>),
+    /// The uploaded file is not a usable STL model; it was deleted and nothing was stored.
+    ModelRejected(String),
+    /// The model is larger than the build volume of the printer; it was deleted and nothing
+    /// was stored.
+    ModelDoesNotFit(BuildVolumeFit),
+    /// The model could not be sliced; it was deleted and nothing was stored.
+    EvaluationFailed(SlicerError),
+    /// The order was priced but could not be stored; the error has been logged.
+    DatabaseFailed,
+    /// The job panicked before it could report an outcome; the panic has been logged.
+    JobPanicked,
+}
+
+/* PRIVATE FUNCTIONS */
+fn discard_received_model(ws_path: &str, order: &SubmittedOrderData) {
+    if let Ok(model_path) = received_model_path(ws_path, &order.model_key) {
+        std::fs::remove_file(model_path).ok();
+    }
+}
+
+fn evaluate_and_store_order_unguarded(
+    ws_path: &str,
+    order: &SubmittedOrderData,
+    evaluate_order_cb: EvaluateOrderCb,
+    add_evaluation_to_db_cb: AddEvaluationToDbCb,
+) -> EvaluationOutcome {
+    let model_statistics = match received_model_path(ws_path, &order.model_key)
+        .and_then(|model_path| inspect_stl_file(&model_path))
+    {
+        Ok(model_statistics) => model_statistics,
+        Err(e) => {
+            println!("Rejected the uploaded model. Error: {}", e);
+            discard_received_model(ws_path, order);
+            return EvaluationOutcome::ModelRejected(e.to_string());
+        }
+    };
+    let build_volume_fit = check_model_fits_build_volume(&model_statistics);
+    if !build_volume_fit.fits {
+        println!(
+            "Rejected the uploaded model, it does not fit the build volume: {:?}",
+            build_volume_fit
+        );
+        discard_received_model(ws_path, order);
+        return EvaluationOutcome::ModelDoesNotFit(build_volume_fit);
+    }
+    let mut evaluation_result = match evaluate_order_cb(order) {
+        Ok(evaluation_result) => EvaluationResult {
+            model_statistics: Some(model_statistics),
+            ..evaluation_result
+        },
+        Err(e) => {
+            println!("Failed to evaluate the order. Error: {}", e);
+            discard_received_model(ws_path, order);
+            return EvaluationOutcome::EvaluationFailed(e);
+        }
+    };
+    match add_evaluation_to_db_cb(&evaluation_result) {
+        Ok(order_id) => {
+            evaluation_result.id = Some(order_id);
+            EvaluationOutcome::Evaluated(Box::new(evaluation_result))
+        }
+        Err(e) => {
+            println!("Failed to write evaluation to database. Error: {:?}", e);
+            EvaluationOutcome::DatabaseFailed
+        }
+    }
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Inspects the model of an order, checks it is an STL that fits the printer, evaluates
+ * the order and stores the result together with the model statistics.
+ *
+ * This is the body of every slicing job. It runs on a background worker, so it may block for
+ * as long as the slicer needs. A panic is caught here, so the submitter always gets an outcome.
+ *
+ * @param ws_path Path to the workspace directory holding the received models.
+ * @param order Submitted order whose model is already stored.
+ * @param evaluate_order_cb Callback slicing and pricing the order.
+ * @param add_evaluation_to_db_cb Callback storing the priced order.
+ * @return EvaluationOutcome Outcome to report to the client, `JobPanicked` if the job panicked.
+ */
+pub fn evaluate_and_store_order(
+    ws_path: &str,
+    order: &SubmittedOrderData,
+    evaluate_order_cb: EvaluateOrderCb,
+    add_evaluation_to_db_cb: AddEvaluationToDbCb,
+) -> EvaluationOutcome {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        evaluate_and_store_order_unguarded(
+            ws_path,
+            order,
+            evaluate_order_cb,
+            add_evaluation_to_db_cb,
+        )
+    }))
+    .unwrap_or_else(|_| {
+        println!("Evaluation of the order '{}' panicked", order.file_name);
+        EvaluationOutcome::JobPanicked
+    })
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_utils::global_types::PrintType;
+    use crate::common_utils::model_storage::generate_model_key;
+    use std::path::{Path, PathBuf};
+
+    fn store_received_model(ws_path: &Path) -> (SubmittedOrderData, PathBuf) {
+        let model_key = generate_model_key("part.stl");
+        let model_path = received_model_path(ws_path.to_str().unwrap(), &model_key).unwrap();
+        std::fs::create_dir_all(model_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &model_path,
+            "solid part\n\
+             facet normal 0 0 1\n outer loop\n\
+             vertex 0 0 0\n vertex 10 0 0\n vertex 0 10 0\n\
+             endloop\n endfacet\n\
+             endsolid part\n",
+        )
+        .unwrap();
+        let order = SubmittedOrderData {
+            name: "John Doe".to_string(),
+            email: "john.doe@example.com".to_string(),
+            copies_nbr: 1,
+            file_name: "part.stl".to_string(),
+            nbr_of_chunks: 1,
+            print_type: PrintType::ThickStrong,
+            material_type: "PLA".to_string(),
+            model_key,
+        };
+        (order, model_path)
+    }
+
+    fn panicking_evaluation(_order: &SubmittedOrderData) -> Result<EvaluationResult, SlicerError> {
+        panic!("slicer interface panicked");
+    }
+
+    fn failing_evaluation(_order: &SubmittedOrderData) -> Result<EvaluationResult, SlicerError> {
+        Err(SlicerError::ModelUnprintable("no layers".to_string()))
+    }
+
+    fn unused_database(_evaluation_result: &EvaluationResult) -> io::Result<i64> {
+        unreachable!("nothing is stored for orders that were not evaluated")
+    }
+
+    #[test]
+    fn test_panicking_evaluation_is_reported() {
+        let ws_path = std::env::temp_dir().join(format!("eval_panic_{}", std::process::id()));
+        let (order, _) = store_received_model(&ws_path);
+        let outcome = evaluate_and_store_order(
+            ws_path.to_str().unwrap(),
+            &order,
+            panicking_evaluation,
+            unused_database,
+        );
+        assert!(matches!(outcome, EvaluationOutcome::JobPanicked));
+        std::fs::remove_dir_all(&ws_path).unwrap();
+    }
+    #[test]
+    fn test_model_is_discarded_after_a_failed_evaluation() {
+        let ws_path = std::env::temp_dir().join(format!("eval_failed_{}", std::process::id()));
+        let (order, model_path) = store_received_model(&ws_path);
+        let outcome = evaluate_and_store_order(
+            ws_path.to_str().unwrap(),
+            &order,
+            failing_evaluation,
+            unused_database,
+        );
+        assert!(matches!(
+            outcome,
+            EvaluationOutcome::EvaluationFailed(SlicerError::ModelUnprintable(_))
+        ));
+        assert!(!model_path.exists());
+        std::fs::remove_dir_all(&ws_path).unwrap();
+    }
+}
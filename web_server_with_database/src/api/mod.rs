@@ -3,4 +3,8 @@ pub mod api;
 pub use api::*;
 
 // PRIVATE MODULES
+mod api_messages;
+mod evaluation_job;
+mod multipart_form_impl;
+mod upload_registry;
 mod web_socket_impl;
@@ -0,0 +1,170 @@
+/* IMPORTS FROM LIBRARIES */
+use actix_multipart::{Field, Multipart};
+use futures::StreamExt;
+use std::fs::File;
+use std::io::{self, Write};
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::{validate_copies_nbr, SubmittedOrderData};
+use crate::common_utils::model_storage::{
+    generate_model_key, received_model_path, sanitize_client_file_name,
+};
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Name of the multipart field carrying the STL model.
+const FILE_FIELD_NAME: &str = "file";
+/// Upper bound for the size of a single text field of the order form.
+const MAX_TEXT_FIELD_SIZE: usize = 64 * 1024;
+
+/* PUBLIC TYPES AND VARIABLES */
+
+/* PRIVATE FUNCTIONS */
+fn invalid_form(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+async fn read_text_field(field: &mut Field) -> io::Result<String> {
+    let mut content = Vec::new();
+    while let Some(chunk) = field.next().await {
+        let chunk = chunk.map_err(|e| invalid_form(format!("Failed to read form field: {}", e)))?;
+        if content.len() + chunk.len() > MAX_TEXT_FIELD_SIZE {
+            return Err(invalid_form(format!(
+                "Form field '{}' is too large",
+                field.name()
+            )));
+        }
+        content.extend_from_slice(&chunk);
+    }
+    String::from_utf8(content)
+        .map_err(|_| invalid_form(format!("Form field '{}' is not valid UTF-8", field.name())))
+}
+
+async fn save_file_field(field: &mut Field, model_key: &str) -> io::Result<()> {
+    let file_path = received_model_path(".", model_key)?;
+    if let Some(parent) = file_path.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    let mut file = File::create(&file_path)?;
+    while let Some(chunk) = field.next().await {
+        let chunk =
+            chunk.map_err(|e| invalid_form(format!("Failed to read uploaded file: {}", e)))?;
+        file.write_all(&chunk)?;
+    }
+    Ok(())
+}
+
+/**
+ * @brief Builds the submitted order from the text fields of the form.
+ *
+ * The fields are deserialized exactly like the JSON text frame of the WebSocket protocol.
+ * The whole file arrives in a single request, so the order always consists of one chunk.
+ *
+ * @param fields Text fields of the form, keyed by field name.
+ * @param file_name Sanitized name of the uploaded file.
+ * @param model_key Key under which the uploaded file was stored.
+ * @return io::Result<SubmittedOrderData> The order, or an `InvalidInput` error.
+ */
+fn submitted_order_from_fields(
+    mut fields: serde_json::Map<String, serde_json::Value>,
+    file_name: &str,
+    model_key: &str,
+) -> io::Result<SubmittedOrderData> {
+    if let Some(serde_json::Value::String(copies_nbr)) = fields.get("copies_nbr") {
+        let copies_nbr = copies_nbr
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| invalid_form(format!("Invalid number of copies: '{}'", copies_nbr)))?;
+        fields.insert("copies_nbr".to_string(), copies_nbr.into());
+    }
+    fields.insert("file_name".to_string(), file_name.into());
+    fields.insert("nbr_of_chunks".to_string(), 1.into());
+    let mut order: SubmittedOrderData =
+        serde_json::from_value(serde_json::Value::Object(fields))
+            .map_err(|e| invalid_form(format!("Invalid order form: {}", e)))?;
+    validate_copies_nbr(order.copies_nbr).map_err(invalid_form)?;
+    order.model_key = model_key.to_string();
+    Ok(order)
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Reads an order submitted as multipart form data.
+ *
+ * The form carries the `SubmittedOrderData` fields as text fields and the STL model in the
+ * `file` field. The model is stored in the received orders directory under a server-generated
+ * key, where the slicer expects it.
+ *
+ * @param payload Multipart payload of the request.
+ * @return io::Result<SubmittedOrderData> The submitted order. Malformed forms yield an
+ *         `InvalidInput` error, failures to store the file any other error kind.
+ */
+pub async fn read_order_from_multipart(mut payload: Multipart) -> io::Result<SubmittedOrderData> {
+    let mut fields = serde_json::Map::new();
+    let mut uploaded_file: Option<(String, String)> = None;
+    while let Some(field) = payload.next().await {
+        let mut field =
+            field.map_err(|e| invalid_form(format!("Malformed multipart payload: {}", e)))?;
+        if field.name() == FILE_FIELD_NAME {
+            let file_name = field
+                .content_disposition()
+                .get_filename()
+                .ok_or_else(|| invalid_form("Uploaded file has no file name".to_string()))
+                .and_then(sanitize_client_file_name)?;
+            let model_key = generate_model_key(&file_name);
+            save_file_field(&mut field, &model_key).await?;
+            uploaded_file = Some((file_name, model_key));
+        } else {
+            let name = field.name().to_string();
+            let value = read_text_field(&mut field).await?;
+            fields.insert(name, value.into());
+        }
+    }
+    let (file_name, model_key) =
+        uploaded_file.ok_or_else(|| invalid_form("No file was uploaded".to_string()))?;
+    submitted_order_from_fields(fields, &file_name, &model_key)
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn form_fields(copies_nbr: &str) -> serde_json::Map<String, serde_json::Value> {
+        let mut fields = serde_json::Map::new();
+        fields.insert("name".to_string(), "John Doe".into());
+        fields.insert("email".to_string(), "john.doe@example.com".into());
+        fields.insert("copies_nbr".to_string(), copies_nbr.into());
+        fields.insert("material_type".to_string(), "PET".into());
+        fields.insert("print_type".to_string(), "PreciseSoft".into());
+        fields
+    }
+
+    #[test]
+    fn test_submitted_order_from_fields() {
+        let order = submitted_order_from_fields(form_fields("3"), "part.stl", "key.stl").unwrap();
+        assert_eq!(order.copies_nbr, 3);
+        assert_eq!(order.file_name, "part.stl");
+        assert_eq!(order.model_key, "key.stl");
+        assert_eq!(order.nbr_of_chunks, 1);
+        assert_eq!(order.material_type.to_string(), "PET");
+        assert_eq!(order.print_type.to_string(), "PreciseSoft");
+    }
+
+    #[test]
+    fn test_submitted_order_from_fields_rejects_invalid_input() {
+        let err =
+            submitted_order_from_fields(form_fields("three"), "part.stl", "key.stl").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        for copies_nbr in ["0", "10001"] {
+            let err = submitted_order_from_fields(form_fields(copies_nbr), "part.stl", "key.stl")
+                .unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        }
+
+        let mut fields = form_fields("1");
+        fields.remove("email");
+        let err = submitted_order_from_fields(fields, "part.stl", "key.stl").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
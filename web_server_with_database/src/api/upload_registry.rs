@@ -0,0 +1,276 @@
+/* IMPORTS FROM LIBRARIES */
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::SubmittedOrderData;
+use crate::common_utils::model_storage::received_model_path;
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Uploads untouched for this long are forgotten and their partial files removed.
+const UPLOAD_EXPIRY: Duration = Duration::from_secs(60 * 60);
+
+struct PendingUpload {
+    order: SubmittedOrderData,
+    chunk_checksums: Vec<u32>,
+    chunk_sizes: Vec<u64>,
+    last_activity: Instant,
+    /// Set while a session uploads the chunks, so no other session can resume the upload.
+    claimed: bool,
+}
+
+lazy_static! {
+    static ref PENDING_UPLOADS: Mutex<HashMap<String, PendingUpload>> = Mutex::new(HashMap::new());
+}
+
+/* PUBLIC TYPES AND VARIABLES */
+/// State of an upload returned to a reconnecting client.
+pub struct ResumedUpload {
+    pub order: SubmittedOrderData,
+    pub chunk_checksums: Vec<u32>,
+}
+
+/* PRIVATE FUNCTIONS */
+/**
+ * @brief Generates a token resuming an upload.
+ *
+ * Anyone holding the token can append to the upload, so it is 128 bits read from the
+ * random number generator of the OS rather than anything derived from time or counters.
+ *
+ * @return io::Result<String> The token as 32 hexadecimal digits.
+ */
+fn generate_upload_token() -> io::Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).map_err(|e| io::Error::other(e.to_string()))?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+fn remove_expired_uploads(uploads: &mut HashMap<String, PendingUpload>, ws_path: &str) {
+    uploads.retain(|_, upload| {
+        let expired = upload.last_activity.elapsed() > UPLOAD_EXPIRY;
+        if expired {
+            if let Ok(path) = received_model_path(ws_path, &upload.order.model_key) {
+                std::fs::remove_file(path).ok();
+            }
+        }
+        !expired
+    });
+}
+
+fn unknown_token_error() -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, "Unknown or expired upload token")
+}
+
+fn upload_in_use_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::ResourceBusy,
+        "The upload is being continued by another connection",
+    )
+}
+
+/**
+ * @brief Counts the leading chunks of a partial file matching the recorded checksums.
+ *
+ * @param file_path Path of the partially uploaded model.
+ * @param chunk_sizes Recorded size of every persisted chunk.
+ * @param chunk_checksums Recorded CRC32 of every persisted chunk.
+ * @return io::Result<usize> Number of intact chunks at the beginning of the file.
+ */
+fn count_intact_chunks(
+    file_path: &std::path::Path,
+    chunk_sizes: &[u64],
+    chunk_checksums: &[u32],
+) -> io::Result<usize> {
+    let mut file = match File::open(file_path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+    let mut intact = 0;
+    for (size, checksum) in chunk_sizes.iter().zip(chunk_checksums) {
+        let mut chunk = Vec::with_capacity(*size as usize);
+        (&mut file).take(*size).read_to_end(&mut chunk)?;
+        if chunk.len() as u64 != *size || crc32fast::hash(&chunk) != *checksum {
+            break;
+        }
+        intact += 1;
+    }
+    Ok(intact)
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Registers a new upload and issues the token used to resume it.
+ *
+ * The upload is claimed by the calling session until it is finished or released.
+ *
+ * @param order Order whose model is about to be uploaded.
+ * @param ws_path Workspace directory the model is stored in.
+ * @return io::Result<String> Token identifying the upload, or an error if the OS provides
+ *         no randomness.
+ */
+pub fn start_upload(order: SubmittedOrderData, ws_path: &str) -> io::Result<String> {
+    let token = generate_upload_token()?;
+    let mut uploads = PENDING_UPLOADS.lock().unwrap();
+    remove_expired_uploads(&mut uploads, ws_path);
+    uploads.insert(
+        token.clone(),
+        PendingUpload {
+            order,
+            chunk_checksums: Vec::new(),
+            chunk_sizes: Vec::new(),
+            last_activity: Instant::now(),
+            claimed: true,
+        },
+    );
+    Ok(token)
+}
+
+/**
+ * @brief Records the checksum of a chunk that was persisted to the partial file.
+ *
+ * @param token Token of the upload.
+ * @param chunk Content of the persisted chunk.
+ * @return io::Result<u32> CRC32 of the chunk.
+ */
+pub fn record_chunk(token: &str, chunk: &[u8]) -> io::Result<u32> {
+    let mut uploads = PENDING_UPLOADS.lock().unwrap();
+    let upload = uploads.get_mut(token).ok_or_else(unknown_token_error)?;
+    let checksum = crc32fast::hash(chunk);
+    upload.chunk_checksums.push(checksum);
+    upload.chunk_sizes.push(chunk.len() as u64);
+    upload.last_activity = Instant::now();
+    Ok(checksum)
+}
+
+/**
+ * @brief Looks up an interrupted upload and verifies the chunks persisted so far.
+ *
+ * Chunks whose content no longer matches the recorded checksum, and every chunk after them,
+ * are dropped from the partial file so the client uploads them again. The upload is claimed
+ * by the calling session, so it cannot be resumed by two connections at once.
+ *
+ * @param token Token issued when the upload started.
+ * @param ws_path Workspace directory the model is stored in.
+ * @return io::Result<ResumedUpload> Order and checksums of the intact chunks, or a
+ *         `ResourceBusy` error if another session holds the upload.
+ */
+pub fn resume_upload(token: &str, ws_path: &str) -> io::Result<ResumedUpload> {
+    let mut uploads = PENDING_UPLOADS.lock().unwrap();
+    remove_expired_uploads(&mut uploads, ws_path);
+    let upload = uploads.get_mut(token).ok_or_else(unknown_token_error)?;
+    if upload.claimed {
+        return Err(upload_in_use_error());
+    }
+    let file_path = received_model_path(ws_path, &upload.order.model_key)?;
+    let intact = count_intact_chunks(&file_path, &upload.chunk_sizes, &upload.chunk_checksums)?;
+    upload.chunk_sizes.truncate(intact);
+    upload.chunk_checksums.truncate(intact);
+    if file_path.exists() {
+        OpenOptions::new()
+            .write(true)
+            .open(&file_path)?
+            .set_len(upload.chunk_sizes.iter().sum())?;
+    }
+    upload.last_activity = Instant::now();
+    upload.claimed = true;
+    Ok(ResumedUpload {
+        order: upload.order.clone(),
+        chunk_checksums: upload.chunk_checksums.clone(),
+    })
+}
+
+/**
+ * @brief Releases an unfinished upload held by a session, so a new connection can resume it.
+ *
+ * @param token Token of the upload.
+ */
+pub fn release_upload(token: &str) {
+    if let Some(upload) = PENDING_UPLOADS.lock().unwrap().get_mut(token) {
+        upload.claimed = false;
+    }
+}
+
+/**
+ * @brief Forgets an upload once all of its chunks were received.
+ *
+ * @param token Token of the finished upload.
+ */
+pub fn finish_upload(token: &str) {
+    PENDING_UPLOADS.lock().unwrap().remove(token);
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_utils::global_types::PrintType;
+    use std::io::Write;
+
+    fn test_order(model_key: &str) -> SubmittedOrderData {
+        SubmittedOrderData {
+            name: "John".to_string(),
+            email: "john@example.com".to_string(),
+            material_type: "PLA".to_string(),
+            print_type: PrintType::ThickStrong,
+            copies_nbr: 1,
+            file_name: "part.stl".to_string(),
+            nbr_of_chunks: 3,
+            model_key: model_key.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resume_drops_corrupted_chunks() {
+        let ws_path = std::env::temp_dir().join(format!("upload_registry_{}", std::process::id()));
+        let ws_path = ws_path.to_str().unwrap();
+        let model_key = "20240101000000000000-1-0001.stl";
+        let file_path = received_model_path(ws_path, model_key).unwrap();
+        std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+
+        let token = start_upload(test_order(model_key), ws_path).unwrap();
+        assert_eq!(token.len(), 32);
+        assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(token, generate_upload_token().unwrap());
+        let mut file = File::create(&file_path).unwrap();
+        for chunk in [b"first".as_slice(), b"second", b"third"] {
+            file.write_all(chunk).unwrap();
+            record_chunk(&token, chunk).unwrap();
+        }
+        drop(file);
+        // The session that started the upload still holds it
+        assert_eq!(
+            resume_upload(&token, ws_path).err().unwrap().kind(),
+            io::ErrorKind::ResourceBusy
+        );
+        release_upload(&token);
+        assert_eq!(
+            resume_upload(&token, ws_path)
+                .unwrap()
+                .chunk_checksums
+                .len(),
+            3
+        );
+
+        // Corrupt the second chunk on disk
+        let mut content = std::fs::read(&file_path).unwrap();
+        content[6] = b'X';
+        std::fs::write(&file_path, content).unwrap();
+        release_upload(&token);
+        let resumed = resume_upload(&token, ws_path).unwrap();
+        assert_eq!(resumed.chunk_checksums, vec![crc32fast::hash(b"first")]);
+        assert_eq!(resumed.order.model_key, model_key);
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"first");
+
+        finish_upload(&token);
+        assert_eq!(
+            resume_upload(&token, ws_path).err().unwrap().kind(),
+            io::ErrorKind::NotFound
+        );
+        std::fs::remove_dir_all(ws_path).ok();
+    }
+}
@@ -1,36 +1,73 @@
 /* IMPORTS FROM LIBRARIES */
 use actix::AsyncContext;
-use actix::{Actor, Addr, StreamHandler};
+use actix::{Actor, Addr, Handler, Message, StreamHandler};
 use actix_web::{web, HttpRequest, HttpResponse};
 use actix_web_actors::ws::{self, CloseReason};
 use bytes::Bytes;
+use serde::Deserialize;
 use std::io;
 
 /* IMPORTS FROM OTHER MODULES */
+use crate::api::api_messages::{
+    format_checksum, serialize_error, serialize_evaluation_error, serialize_evaluation_result,
+    serialize_model_does_not_fit, ServerMessage,
+};
+use crate::api::evaluation_job::{
+    evaluate_and_store_order, AddEvaluationToDbCb, EvaluateOrderCb, EvaluationOutcome,
+};
+use crate::api::upload_registry;
+use crate::common_utils::background_job_pool::BackgroundJob;
 use crate::common_utils::global_traits::WebSocketInterfaceImpl;
-use crate::common_utils::global_types::{EvaluationResult, SubmittedOrderData};
+use crate::common_utils::global_types::{validate_copies_nbr, SlicerError, SubmittedOrderData};
+use crate::common_utils::material_catalogue::Material;
+use crate::common_utils::model_storage::{
+    generate_model_key, received_model_path, sanitize_client_file_name,
+};
 use std::fs::OpenOptions;
 use std::io::Write;
-use std::path::Path;
 
 /* PRIVATE TYPES AND VARIABLES */
 struct WebSocketSession {
     pub my_addr: Option<Addr<WebSocketSession>>,
     submitted_form: Option<SubmittedOrderData>,
+    upload_token: Option<String>,
     chunks_received: u32,
-    pub evaluate_order_cb: fn(&SubmittedOrderData) -> EvaluationResult,
-    pub add_evaluation_to_db_cb: fn(&EvaluationResult) -> io::Result<()>,
+    pub evaluate_order_cb: EvaluateOrderCb,
+    pub add_evaluation_to_db_cb: AddEvaluationToDbCb,
+    pub submit_slicing_job_cb: fn(BackgroundJob) -> io::Result<usize>,
+    pub find_material_cb: fn(&str) -> Option<Material>,
+}
+
+/// Text frame sent by a reconnecting client to continue an interrupted upload.
+#[derive(Deserialize)]
+struct ResumeRequest {
+    resume_token: String,
+}
+
+/// Progress of a slicing job, reported by the background worker.
+#[derive(Message)]
+#[rtype(result = "()")]
+enum SlicingJobEvent {
+    Started {
+        file_name: String,
+    },
+    Finished {
+        file_name: String,
+        outcome: Box<EvaluationOutcome>,
+    },
 }
 
 /* PUBLIC TYPES AND VARIABLES */
 pub struct PriceEvaluationWebSocketImpl {
-    pub evaluate_order_cb: fn(&SubmittedOrderData) -> EvaluationResult,
-    pub add_evaluation_to_db_cb: fn(&EvaluationResult) -> io::Result<()>,
+    pub evaluate_order_cb: EvaluateOrderCb,
+    pub add_evaluation_to_db_cb: AddEvaluationToDbCb,
+    pub submit_slicing_job_cb: fn(BackgroundJob) -> io::Result<usize>,
+    pub find_material_cb: fn(&str) -> Option<Material>,
 }
 
 /* HELPER FUNCTIONS */
-fn append_the_file(filename: &String, chunks_received: &u32, bin: Bytes) -> io::Result<u32> {
-    let file_path = Path::new("data_files/received_orders/").join(filename);
+fn append_the_file(model_key: &str, chunks_received: &u32, bin: &[u8]) -> io::Result<u32> {
+    let file_path = received_model_path(".", model_key)?;
     if let Some(parent) = file_path.parent() {
         std::fs::create_dir_all(parent).ok();
     }
@@ -43,26 +80,10 @@ fn append_the_file(filename: &String, chunks_received: &u32, bin: Bytes) -> io::
         .create(true)
         .append(true)
         .open(&file_path)?;
-    file.write_all(&bin)?;
+    file.write_all(bin)?;
     Ok(chunks_received + 1)
 }
 
-fn serialize_evaluation_result(eval_result: EvaluationResult) -> String {
-    // Serialize the EvaluationResult to a JSON string
-    serde_json::json!({
-        "type": "evaluation_result",
-        "name": eval_result.name,
-        "email": eval_result.email,
-        "copies_nbr": eval_result.copies_nbr,
-        "file_name": eval_result.file_name,
-        "price": format!("{:.2}", eval_result.price),
-        "material_type": eval_result.material_type.to_string(),
-        "status": "success",
-        "message": "Evaluation completed successfully."
-    })
-    .to_string()
-}
-
 /* PRIVATE FUNCTIONS */
 impl Actor for WebSocketSession {
     type Context = ws::WebsocketContext<Self>;
@@ -70,11 +91,19 @@ impl Actor for WebSocketSession {
     fn started(&mut self, ctx: &mut Self::Context) {
         self.my_addr = Some(ctx.address());
     }
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        // A dropped connection leaves its unfinished upload free to be resumed
+        self.reset_session();
+    }
 }
 
 impl WebSocketSession {
     // Private function to reset the session state
     fn reset_session(&mut self) {
+        if let Some(token) = self.upload_token.take() {
+            upload_registry::release_upload(&token);
+        }
         self.submitted_form = None;
         self.chunks_received = 0;
     }
@@ -92,39 +121,138 @@ impl WebSocketSession {
         ctx.close(Some(close_reason));
     }
 
+    // Private function to report an error the session cannot recover from and close it
+    fn fail_session(&mut self, ctx: &mut ws::WebsocketContext<Self>, code: &str, message: &str) {
+        ctx.text(serialize_error(code, message));
+        self.close_session(ctx, Some(message));
+    }
+
     // Private function for handling the Text payload and parsing it into a SubmittedOrderData struct
     fn handle_text_payload(&mut self, text: String, ctx: &mut ws::WebsocketContext<Self>) {
+        // A new order or resume request replaces the upload in progress
+        self.reset_session();
+        if let Ok(request) = serde_json::from_str::<ResumeRequest>(&text) {
+            self.resume_upload(&request.resume_token, ctx);
+            return;
+        }
         match serde_json::from_str::<SubmittedOrderData>(&text) {
-            Ok(data) => {
-                self.submitted_form = Some(data);
-                return;
+            Ok(data) if (self.find_material_cb)(&data.material_type).is_none() => {
+                let message = SlicerError::UnknownMaterial(data.material_type).to_string();
+                self.fail_session(ctx, "unknown_material", &message)
             }
-            _ => self.close_session(
+            Ok(mut data) => {
+                if let Err(message) = validate_copies_nbr(data.copies_nbr) {
+                    return self.fail_session(ctx, "invalid_order", &message);
+                }
+                match sanitize_client_file_name(&data.file_name) {
+                    Ok(file_name) => {
+                        data.model_key = generate_model_key(&file_name);
+                        data.file_name = file_name;
+                        let token = match upload_registry::start_upload(data.clone(), ".") {
+                            Ok(token) => token,
+                            Err(e) => {
+                                return self.fail_session(ctx, "upload_failed", &e.to_string())
+                            }
+                        };
+                        ctx.text(
+                            ServerMessage::UploadToken {
+                                token: token.clone(),
+                                file_name: data.file_name.clone(),
+                                total: data.nbr_of_chunks,
+                            }
+                            .to_json(),
+                        );
+                        self.submitted_form = Some(data);
+                        self.upload_token = Some(token);
+                        self.chunks_received = 0;
+                    }
+                    Err(e) => self.fail_session(ctx, "invalid_file_name", &e.to_string()),
+                }
+            }
+            _ => self.fail_session(
                 ctx,
-                Some("Failed to parse SubmittedOrderData from text payload"),
+                "invalid_order",
+                "Failed to parse SubmittedOrderData from text payload",
             ),
         }
     }
 
+    // Private function for continuing an upload interrupted by a dropped connection
+    fn resume_upload(&mut self, token: &str, ctx: &mut ws::WebsocketContext<Self>) {
+        let resumed = match upload_registry::resume_upload(token, ".") {
+            Ok(resumed) => resumed,
+            Err(e) if e.kind() == io::ErrorKind::ResourceBusy => {
+                self.fail_session(ctx, "upload_in_use", &e.to_string());
+                return;
+            }
+            Err(e) => {
+                self.fail_session(ctx, "unknown_upload_token", &e.to_string());
+                return;
+            }
+        };
+        let form = resumed.order;
+        self.chunks_received = resumed.chunk_checksums.len() as u32;
+        ctx.text(
+            ServerMessage::UploadResumed {
+                token: token.to_string(),
+                file_name: form.file_name.clone(),
+                received: self.chunks_received,
+                total: form.nbr_of_chunks,
+                checksums: resumed
+                    .chunk_checksums
+                    .into_iter()
+                    .map(format_checksum)
+                    .collect(),
+            }
+            .to_json(),
+        );
+        self.upload_token = Some(token.to_string());
+        if self.chunks_received == form.nbr_of_chunks {
+            upload_registry::finish_upload(token);
+            self.process_stl_model_when_all_chunks_received(ctx, form);
+        } else {
+            self.submitted_form = Some(form);
+        }
+    }
+
     fn process_stl_model_when_all_chunks_received(
         &mut self,
         ctx: &mut ws::WebsocketContext<Self>,
         form: SubmittedOrderData,
     ) {
-        let evaluate_order_function = self.evaluate_order_cb;
-        let add_evaluation_to_db_function = self.add_evaluation_to_db_cb;
-
-        let order_evaluation_result = evaluate_order_function(&form);
-        if let Err(e) = add_evaluation_to_db_function(&order_evaluation_result) {
-            println!("Failed to write evaluation to database. Error: {:?}", e);
-            self.close_session(ctx, Some(&format!("Internal database error")));
-            return;
+        let file_name = form.file_name.clone();
+        ctx.text(
+            ServerMessage::UploadComplete {
+                file_name: file_name.clone(),
+            }
+            .to_json(),
+        );
+        let evaluate_order_cb = self.evaluate_order_cb;
+        let add_evaluation_to_db_cb = self.add_evaluation_to_db_cb;
+        let session_addr = ctx.address();
+        let slicing_job: BackgroundJob = Box::new(move || {
+            // The session may be gone by now; the order is stored regardless
+            session_addr.do_send(SlicingJobEvent::Started {
+                file_name: form.file_name.clone(),
+            });
+            let outcome =
+                evaluate_and_store_order(".", &form, evaluate_order_cb, add_evaluation_to_db_cb);
+            session_addr.do_send(SlicingJobEvent::Finished {
+                file_name: form.file_name.clone(),
+                outcome: Box::new(outcome),
+            });
+        });
+        match (self.submit_slicing_job_cb)(slicing_job) {
+            Ok(position) => ctx.text(
+                ServerMessage::Queued {
+                    file_name,
+                    position,
+                }
+                .to_json(),
+            ),
+            Err(e) => ctx.text(serialize_error("queue_full", &e.to_string())),
         }
-        // Serialize the evaluation result to a JSON string
-        let json_result = serialize_evaluation_result(order_evaluation_result);
-        // Send the evaluation result back to the client
-        ctx.text(json_result);
-        // Reset the session state after processing
+        // The session is ready for the next order while this one is being sliced
         self.reset_session();
     }
 
@@ -135,36 +263,94 @@ impl WebSocketSession {
         ctx: &mut ws::WebsocketContext<Self>,
         form: SubmittedOrderData,
     ) {
-        let filename = &form.file_name;
+        let model_key = &form.model_key;
         let total_chunks = form.nbr_of_chunks;
         if self.chunks_received >= total_chunks {
-            self.close_session(
+            self.fail_session(
                 ctx,
-                Some(&format!(
+                "unexpected_chunk",
+                &format!(
                     "Incorrect number of chunks received {} out of {}",
                     self.chunks_received, total_chunks
-                )),
+                ),
             );
             return;
         }
-        if let Ok(chunks_received) = append_the_file(filename, &self.chunks_received, bin) {
+        let token = self.upload_token.clone().unwrap_or_default();
+        let stored_chunk =
+            append_the_file(model_key, &self.chunks_received, &bin).and_then(|received| {
+                upload_registry::record_chunk(&token, &bin).map(|checksum| (received, checksum))
+            });
+        let checksum = if let Ok((chunks_received, checksum)) = stored_chunk {
             self.chunks_received = chunks_received;
+            checksum
         } else {
-            self.close_session(
+            self.fail_session(
                 ctx,
-                Some(&format!(
+                "upload_failed",
+                &format!(
                     "Internal error while processing the file chunk {}",
                     self.chunks_received + 1
-                )),
+                ),
             );
             return;
-        }
+        };
+        ctx.text(
+            ServerMessage::ChunkAck {
+                received: self.chunks_received,
+                total: total_chunks,
+                checksum: format_checksum(checksum),
+            }
+            .to_json(),
+        );
         if self.chunks_received == total_chunks {
+            upload_registry::finish_upload(&token);
             self.process_stl_model_when_all_chunks_received(ctx, form);
         }
     }
 }
 
+// Receive the progress of a slicing job from the background worker
+impl Handler<SlicingJobEvent> for WebSocketSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: SlicingJobEvent, ctx: &mut Self::Context) {
+        let (file_name, outcome) = match msg {
+            SlicingJobEvent::Started { file_name } => {
+                ctx.text(ServerMessage::SlicingStarted { file_name }.to_json());
+                return;
+            }
+            SlicingJobEvent::Finished { file_name, outcome } => (file_name, outcome),
+        };
+        ctx.text(
+            ServerMessage::SlicingFinished {
+                file_name: file_name.clone(),
+            }
+            .to_json(),
+        );
+        match *outcome {
+            EvaluationOutcome::Evaluated(evaluation_result) => {
+                ctx.text(serialize_evaluation_result(*evaluation_result))
+            }
+            // Nothing is stored; the client may fix the model and submit it again
+            EvaluationOutcome::ModelRejected(message) => {
+                ctx.text(serialize_error("invalid_model", &message))
+            }
+            EvaluationOutcome::ModelDoesNotFit(fit) => {
+                ctx.text(serialize_model_does_not_fit(&file_name, fit))
+            }
+            EvaluationOutcome::EvaluationFailed(e) => ctx.text(serialize_evaluation_error(&e)),
+            EvaluationOutcome::DatabaseFailed => {
+                self.fail_session(ctx, "database_error", "Internal database error")
+            }
+            EvaluationOutcome::JobPanicked => ctx.text(serialize_error(
+                "slicing_job_failed",
+                "The order could not be evaluated because of an internal error",
+            )),
+        }
+    }
+}
+
 // Implement StreamHandler to handle incoming WebSocket messages
 impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WebSocketSession {
     fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
@@ -172,14 +358,23 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WebSocketSession
             Ok(ws::Message::Text(text)) => self.handle_text_payload(text.to_string(), ctx),
             Ok(ws::Message::Binary(bin)) => {
                 if self.submitted_form.is_none() {
-                    ctx.text("No submitted form data available to process the STL model.");
+                    ctx.text(serialize_error(
+                        "no_order_submitted",
+                        "No submitted form data available to process the STL model.",
+                    ));
                     return;
                 }
                 let form = self.submitted_form.clone().unwrap();
                 self.handle_binary_payload(bin, ctx, form);
             }
+            Ok(ws::Message::Ping(payload)) => ctx.pong(&payload),
+            Ok(ws::Message::Pong(_)) => {}
+            Ok(ws::Message::Close(_)) => self.close_session(ctx, None),
             _ => {
-                ctx.text("Unsupported message type. Only text and binary messages are supported.");
+                ctx.text(serialize_error(
+                    "unsupported_message",
+                    "Unsupported message type. Only text and binary messages are supported.",
+                ));
             }
         }
     }
@@ -196,9 +391,12 @@ impl WebSocketInterfaceImpl for PriceEvaluationWebSocketImpl {
             WebSocketSession {
                 my_addr: None,
                 submitted_form: None,
+                upload_token: None,
                 chunks_received: 0,
                 add_evaluation_to_db_cb: self.add_evaluation_to_db_cb,
                 evaluate_order_cb: self.evaluate_order_cb,
+                submit_slicing_job_cb: self.submit_slicing_job_cb,
+                find_material_cb: self.find_material_cb,
             },
             &req,
             stream,
@@ -0,0 +1,144 @@
+/* IMPORTS FROM LIBRARIES */
+use std::collections::VecDeque;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+/* IMPORTS FROM OTHER MODULES */
+
+/* PRIVATE TYPES AND VARIABLES */
+struct SharedQueue {
+    pending_jobs: Mutex<VecDeque<BackgroundJob>>,
+    job_available: Condvar,
+    capacity: usize,
+}
+
+/* PUBLIC TYPES AND VARIABLES */
+pub type BackgroundJob = Box<dyn FnOnce() + Send + 'static>;
+
+/// Fixed-size pool of worker threads fed from a bounded FIFO queue.
+pub struct BackgroundJobPool {
+    shared_queue: Arc<SharedQueue>,
+}
+
+/* PRIVATE FUNCTIONS */
+fn run_worker(shared_queue: Arc<SharedQueue>) {
+    loop {
+        let job = {
+            let mut pending_jobs = shared_queue.pending_jobs.lock().unwrap();
+            loop {
+                if let Some(job) = pending_jobs.pop_front() {
+                    break job;
+                }
+                pending_jobs = shared_queue.job_available.wait(pending_jobs).unwrap();
+            }
+        };
+        // A panicking job must not take the worker down with it
+        if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+            println!("Background job panicked, worker continues with the next job");
+        }
+    }
+}
+
+/* PUBLIC FUNCTIONS */
+impl BackgroundJobPool {
+    /**
+     * @brief Creates the pool and starts its worker threads.
+     *
+     * @param name Prefix of the worker thread names.
+     * @param worker_count Number of jobs executed concurrently, at least one.
+     * @param capacity Maximum number of jobs waiting for a free worker.
+     * @return BackgroundJobPool The running pool.
+     */
+    pub fn new(name: &str, worker_count: usize, capacity: usize) -> Self {
+        let shared_queue = Arc::new(SharedQueue {
+            pending_jobs: Mutex::new(VecDeque::new()),
+            job_available: Condvar::new(),
+            capacity,
+        });
+        for worker_nbr in 0..worker_count.max(1) {
+            let worker_queue = shared_queue.clone();
+            thread::Builder::new()
+                .name(format!("{}-{}", name, worker_nbr))
+                .spawn(move || run_worker(worker_queue))
+                .expect("Failed to spawn background worker thread");
+        }
+        BackgroundJobPool { shared_queue }
+    }
+
+    /**
+     * @brief Queues a job for execution on the next free worker.
+     *
+     * @param job Job to execute.
+     * @return io::Result<usize> Number of jobs queued ahead of this one, or a `WouldBlock`
+     *         error if the queue is full.
+     */
+    pub fn submit(&self, job: BackgroundJob) -> io::Result<usize> {
+        let mut pending_jobs = self.shared_queue.pending_jobs.lock().unwrap();
+        if pending_jobs.len() >= self.shared_queue.capacity {
+            return Err(io::Error::new(
+                io::ErrorKind::WouldBlock,
+                "Too many orders are waiting to be sliced, please try again later",
+            ));
+        }
+        pending_jobs.push_back(job);
+        self.shared_queue.job_available.notify_one();
+        Ok(pending_jobs.len() - 1)
+    }
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_jobs_run_on_workers() {
+        let pool = BackgroundJobPool::new("test-pool", 2, 8);
+        let (sender, receiver) = mpsc::channel();
+        for job_nbr in 0..4 {
+            let sender = sender.clone();
+            pool.submit(Box::new(move || sender.send(job_nbr).unwrap()))
+                .unwrap();
+        }
+        let mut results: Vec<i32> = (0..4)
+            .map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect();
+        results.sort();
+        assert_eq!(results, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_full_queue_rejects_jobs() {
+        let pool = BackgroundJobPool::new("test-pool", 1, 1);
+        let (release_sender, release_receiver) = mpsc::channel::<()>();
+        let (started_sender, started_receiver) = mpsc::channel();
+        // Occupy the only worker until released
+        pool.submit(Box::new(move || {
+            started_sender.send(()).unwrap();
+            release_receiver.recv().ok();
+        }))
+        .unwrap();
+        started_receiver
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap();
+
+        assert_eq!(pool.submit(Box::new(|| {})).unwrap(), 0);
+        let err = pool.submit(Box::new(|| {})).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+        release_sender.send(()).unwrap();
+    }
+
+    #[test]
+    fn test_worker_survives_panicking_job() {
+        let pool = BackgroundJobPool::new("test-pool", 1, 4);
+        let (sender, receiver) = mpsc::channel();
+        pool.submit(Box::new(|| panic!("job failure"))).unwrap();
+        pool.submit(Box::new(move || sender.send(()).unwrap()))
+            .unwrap();
+        assert!(receiver.recv_timeout(Duration::from_secs(5)).is_ok());
+    }
+}
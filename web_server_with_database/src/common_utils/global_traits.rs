@@ -1,29 +1,38 @@
 use actix_web::{web, HttpRequest, HttpResponse};
 
 use crate::common_utils::global_types::{
-    EvaluatedPrintingParameters, EvaluationResult, SubmittedOrderData,
+    EvaluatedPrintingParameters, EvaluationResult, SlicerError, SubmittedOrderData,
 };
+use crate::common_utils::material_catalogue::Material;
+use crate::common_utils::pricing_versions::PricingVersion;
 use std::io::Result;
+use std::time::Duration;
 
 pub trait SlicerInterfaceImpl: Send + Sync {
     fn initialize_slicer_int_impl(&self, prusa_path: &str, ws_path: &str) -> Result<()>;
     fn get_expected_print_parameters(
         &self,
         order: &SubmittedOrderData,
+        material: &Material,
         slicer_path: &str,
         ws_path: &str,
-    ) -> EvaluatedPrintingParameters;
+        timeout: Duration,
+    ) -> std::result::Result<EvaluatedPrintingParameters, SlicerError>;
 }
 
 pub trait DatabaseInterfaceImpl: Send + Sync {
     fn initialize_db(&self, db_name: &str) -> Result<()>;
     fn read_orders_from_db(&self) -> Result<Vec<EvaluationResult>>;
     fn read_completed_orders_from_db(&self) -> Result<Vec<EvaluationResult>>;
-    fn add_evaluation_to_db(&self, eval_result: &EvaluationResult) -> Result<()>;
+    fn read_order_from_db(&self, order_id: i64) -> Result<Option<EvaluationResult>>;
+    fn add_evaluation_to_db(&self, eval_result: &EvaluationResult) -> Result<i64>;
+    fn add_pricing_version_to_db(&self, hash: &str, configuration: &str) -> Result<i64>;
+    fn read_pricing_versions_from_db(&self, version_id: Option<i64>)
+        -> Result<Vec<PricingVersion>>;
     fn modify_order_in_database(
         &self,
         table_name: &str,
-        order_id: &str,
+        order_id: i64,
         new_status: &str,
     ) -> Result<()>;
 }
@@ -1,16 +1,12 @@
 use chrono;
 use serde::{Deserialize, Serialize};
+
+use crate::common_utils::material_catalogue::{LegacyMaterialRates, Material, MaterialPrice};
+use crate::common_utils::money::{Money, RoundingPolicy};
 use std::fmt::Display;
 use strum_macros::EnumIter;
 
 /* PUBLIC TYPES */
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub enum PrintMaterialType {
-    PLA,
-    PET,
-    ASA,
-}
-
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum StatusType {
     New,
@@ -19,7 +15,7 @@ pub enum StatusType {
     Canceled,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, EnumIter)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, EnumIter)]
 pub enum PrintType {
     ThickStrong,
     ThickSoft,
@@ -27,16 +23,85 @@ pub enum PrintType {
     PreciseSoft,
 }
 
+/// Slicer orders are quoted with, selected by the "slicer" entry of the configuration file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlicerBackend {
+    #[default]
+    PrusaSlicer,
+    /// Fork of PrusaSlicer with the same command line and G-code statistics.
+    SuperSlicer,
+    OrcaSlicer,
+    CuraEngine,
+    /// No slicer; filament use and print time are estimated from the geometry of the model.
+    GeometricEstimate,
+}
 
+/// Coefficients the geometric estimator uses for one print type.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EstimatorCoefficients {
+    pub print_type: PrintType,
+    /// Thickness of the walls and of the top and bottom layers in millimetres.
+    pub wall_thickness_mm: f64,
+    /// Share of the inside of the model filled by infill, in percent.
+    pub infill_percent: f64,
+    /// Plastic extruded per second on average, travel moves included, in mm3/s.
+    pub volumetric_speed_mm3_s: f64,
+}
 
-impl Display for PrintMaterialType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            PrintMaterialType::PLA => write!(f, "PLA"),
-            PrintMaterialType::PET => write!(f, "PET"),
-            PrintMaterialType::ASA => write!(f, "ASA"),
-        }
-    }
+/// Settings of the estimator that prices models without slicing them.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct GeometricEstimatorSettings {
+    /// Estimate orders when the selected slicer cannot be started or fails on a model.
+    #[serde(default)]
+    pub fallback: bool,
+    /// Coefficients of the print types, print types not listed use built-in coefficients.
+    #[serde(default)]
+    pub print_types: Vec<EstimatorCoefficients>,
+}
+
+/// Slicer entries of the configuration file, read once at startup.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SlicerSettings {
+    #[serde(default)]
+    pub slicer: SlicerBackend,
+    /// Executable of the selected slicer.
+    #[serde(default)]
+    pub slicer_path: Option<String>,
+    /// Executable of PrusaSlicer, used when `slicer_path` is not set.
+    #[serde(default)]
+    pub prusa_path: Option<String>,
+    /// Printer profile in the profile directory of the selected slicer, loaded before the
+    /// print profile. Only PrusaSlicer and SuperSlicer load it.
+    #[serde(default)]
+    pub printer_profile: Option<String>,
+    /// Estimator used by the `geometric_estimate` slicer and as a fallback of the others.
+    #[serde(default)]
+    pub geometric_estimator: GeometricEstimatorSettings,
+}
+
+/// Reasons why a model could not be turned into printing parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlicerError {
+    /// The slicer executable could not be started.
+    SlicerNotFound(String),
+    /// The slicer did not finish within the allowed time and was killed.
+    SlicerTimeout(std::time::Duration),
+    /// The slicer ran but exited with a non-zero status.
+    SlicerFailed {
+        exit_code: Option<i32>,
+        stderr: String,
+    },
+    /// The slicer refused the model, e.g. because it is empty or does not fit on the bed.
+    ModelUnprintable(String),
+    /// The generated G-code lacks the print time or filament usage.
+    MetadataMissing(String),
+    /// Any other I/O failure while preparing or reading the slicer files.
+    Io(String),
+    /// The order names a material that is not in the material catalogue.
+    UnknownMaterial(String),
+    /// The price of the order is too large to be represented.
+    PriceOutOfRange(String),
 }
 
 impl Display for StatusType {
@@ -61,6 +126,100 @@ impl Display for PrintType {
     }
 }
 
+impl Display for SlicerBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlicerBackend::PrusaSlicer => write!(f, "PrusaSlicer"),
+            SlicerBackend::SuperSlicer => write!(f, "SuperSlicer"),
+            SlicerBackend::OrcaSlicer => write!(f, "OrcaSlicer"),
+            SlicerBackend::CuraEngine => write!(f, "CuraEngine"),
+            SlicerBackend::GeometricEstimate => write!(f, "geometric estimator"),
+        }
+    }
+}
+
+impl SlicerBackend {
+    /// Prefix of the profile directory and profile files of the slicer in `data_files`, e.g.
+    /// "orca" for `orca_config_files/orca_config_ThickStrong.json`.
+    pub fn profile_prefix(&self) -> &'static str {
+        match self {
+            SlicerBackend::PrusaSlicer => "prusa",
+            SlicerBackend::SuperSlicer => "superslicer",
+            SlicerBackend::OrcaSlicer => "orca",
+            SlicerBackend::CuraEngine => "cura",
+            // Reads no profiles, but keeps the filament profiles of a PrusaSlicer setup valid
+            SlicerBackend::GeometricEstimate => "prusa",
+        }
+    }
+
+    /// Directory of the profiles of the slicer, relative to the workspace.
+    pub fn profile_dir(&self) -> String {
+        format!("data_files/{}_config_files", self.profile_prefix())
+    }
+
+    /// Whether the backend runs a slicer, which needs a filament profile for every material.
+    pub fn slices_models(&self) -> bool {
+        *self != SlicerBackend::GeometricEstimate
+    }
+}
+
+impl SlicerSettings {
+    /// Executable of the selected slicer, falling back to `prusa_path`.
+    pub fn executable(&self) -> Option<&str> {
+        self.slicer_path.as_deref().or(self.prusa_path.as_deref())
+    }
+}
+
+impl SlicerError {
+    /// Stable identifier of the error kind, sent to clients next to the message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SlicerError::SlicerNotFound(_) => "slicer_not_found",
+            SlicerError::SlicerTimeout(_) => "slicer_timeout",
+            SlicerError::SlicerFailed { .. } => "slicer_failed",
+            SlicerError::ModelUnprintable(_) => "model_unprintable",
+            SlicerError::MetadataMissing(_) => "metadata_missing",
+            SlicerError::Io(_) => "io_error",
+            SlicerError::UnknownMaterial(_) => "unknown_material",
+            SlicerError::PriceOutOfRange(_) => "price_out_of_range",
+        }
+    }
+}
+
+impl Display for SlicerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlicerError::SlicerNotFound(details) => {
+                write!(f, "Slicer executable could not be started: {}", details)
+            }
+            SlicerError::SlicerTimeout(timeout) => write!(
+                f,
+                "Slicing did not finish within {} seconds",
+                timeout.as_secs()
+            ),
+            SlicerError::SlicerFailed { exit_code, stderr } => match exit_code {
+                Some(code) => write!(f, "Slicer exited with status {}: {}", code, stderr),
+                None => write!(f, "Slicer was terminated by a signal: {}", stderr),
+            },
+            SlicerError::ModelUnprintable(details) => {
+                write!(f, "The model cannot be printed: {}", details)
+            }
+            SlicerError::MetadataMissing(details) => {
+                write!(f, "Slicer output is missing print metadata: {}", details)
+            }
+            SlicerError::Io(details) => write!(f, "Slicer I/O error: {}", details),
+            SlicerError::UnknownMaterial(name) => {
+                write!(f, "Material '{}' is not in the material catalogue", name)
+            }
+            SlicerError::PriceOutOfRange(details) => {
+                write!(f, "The price cannot be calculated: {}", details)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SlicerError {}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SubmittedOrderData {
     pub name: String,
@@ -69,34 +228,576 @@ pub struct SubmittedOrderData {
     pub file_name: String,
     pub nbr_of_chunks: u32,
     pub print_type: PrintType,
-    pub material_type: PrintMaterialType,
+    /// Name of a material from the material catalogue.
+    pub material_type: String,
+    /// Server-generated key of the stored model, never accepted from the client.
+    #[serde(skip)]
+    pub model_key: String,
+}
+
+/// Geometry of an uploaded STL model, in the units of the file (millimetres for slicers).
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ModelStatistics {
+    pub triangle_count: u32,
+    pub bounding_box_min: [f64; 3],
+    pub bounding_box_max: [f64; 3],
+    pub volume: f64,
+    pub surface_area: f64,
+    /// Every edge is shared by exactly two triangles with opposite orientation.
+    pub is_watertight: bool,
+}
+
+/// How a model relates to the build volume of the printer.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BuildVolumeFit {
+    /// Size of the model's bounding box along X, Y and Z in millimetres.
+    pub model_size: [f64; 3],
+    /// Printable size along X, Y and Z in millimetres.
+    pub build_volume: [f64; 3],
+    /// The model fits in the orientation it was uploaded in.
+    pub fits: bool,
+    /// Rotating the model by 90 degree steps makes it fit.
+    pub fits_when_rotated: bool,
+    /// Largest uniform scale factor with which the model fits in its uploaded orientation.
+    pub max_uniform_scale: f64,
+}
+
+/// Components of a quote, so customers understand it and operators can audit it.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PriceBreakdown {
+    /// Cost of the filament for one copy.
+    pub material_cost: Money,
+    /// Catalogue price of the material the cost was calculated with.
+    #[serde(default)]
+    pub material_price: Option<MaterialPrice>,
+    /// Mass of the filament used by one copy in grams.
+    #[serde(default)]
+    pub material_mass_g: Option<f64>,
+    /// Filament cost of one copy as computed by the slicer, for comparison only.
+    #[serde(default)]
+    pub slicer_filament_cost: Option<Money>,
+    /// Cost of the printer time for one copy.
+    pub machine_time_cost: Money,
+    /// How the print time was billed against the hourly tiers.
+    #[serde(default)]
+    pub hourly_billing: HourlyBilling,
+    /// Index of the hourly rate tier the print time falls in.
+    pub hourly_tier: usize,
+    /// Print time in hours from which that tier starts.
+    pub hourly_tier_from_hours: u32,
+    /// Hourly rate of that tier.
+    pub hourly_rate: Money,
+    /// Cost of every tier the print time passed through, for marginal billing only.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hourly_bands: Vec<HourlyBandCost>,
+    /// Factor the material and machine time cost of a copy were multiplied by for its print type.
+    #[serde(default = "default_print_type_multiplier")]
+    pub print_type_multiplier: f64,
+    /// Amount added to the price of every copy for its print type.
+    #[serde(default)]
+    pub print_type_surcharge: Money,
+    /// Amount the multiplier and surcharge of the print type added to the price of one copy.
+    #[serde(default)]
+    pub print_type_adjustment: Money,
+    /// Fee charged once per order.
+    pub order_fee: Money,
+    /// Price of a single copy.
+    pub per_copy_subtotal: Money,
+    pub copies: u32,
+    /// Quantity discount in percent of the price of all copies, 0 below the first bracket.
+    #[serde(default)]
+    pub quantity_discount_percent: f64,
+    /// Amount the quantity discount took off the price of all copies.
+    #[serde(default)]
+    pub quantity_discount: Money,
+    /// Amount added to raise the order to the minimum order value.
+    #[serde(default)]
+    pub minimum_order_adjustment: Money,
+    /// Difference between the total and the sum of its components due to the rounding policy.
+    #[serde(default)]
+    pub rounding_adjustment: Money,
+    /// Price of the whole order.
+    pub total: Money,
+}
+
+/// Part of the print time billed at the rate of one tier.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct HourlyBandCost {
+    pub from_hours: u32,
+    /// Seconds of the print time falling into the tier.
+    pub seconds: u32,
+    pub rate: Money,
+    pub cost: Money,
 }
 
 pub struct EvaluationResult {
+    pub id: Option<i64>,
     pub date: chrono::DateTime<chrono::Utc>,
     pub name: String,
     pub email: String,
     pub copies_nbr: u32,
     pub file_name: String,
-    pub price: f64,
-    pub material_type: PrintMaterialType,
+    pub price: Money,
+    pub material_type: String,
     pub print_type: PrintType,
     pub status: StatusType,
+    pub model_key: String,
+    pub model_statistics: Option<ModelStatistics>,
+    /// Missing for orders priced before breakdowns were recorded.
+    pub price_breakdown: Option<PriceBreakdown>,
+    /// Print time and filament usage of one copy the price was calculated from, missing for
+    /// orders priced before they were recorded.
+    pub print_parameters: Option<EvaluatedPrintingParameters>,
+    /// ID of the stored pricing configuration the order was priced with.
+    pub pricing_version: Option<i64>,
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct EvaluatedPrintingParameters {
+    /// Print time in seconds, in normal mode unless the slicer only reports the silent mode.
     pub time: u32,
     pub material_mm: u32,
-    pub material_type: PrintMaterialType,
+    /// Mass of the filament in grams, when the slicer reports it.
+    pub material_g: Option<f64>,
+    /// Volume of the filament in cm3, when the slicer reports it.
+    pub material_cm3: Option<f64>,
+    /// Filament cost computed by the slicer from the price in its filament profile.
+    pub filament_cost: Option<Money>,
+    /// Print time in silent (stealth) mode in seconds, when the slicer reports it.
+    #[serde(default)]
+    pub silent_mode_time: Option<u32>,
+    /// Number of layers of the print.
+    #[serde(default)]
+    pub layer_count: Option<u32>,
+    /// Height of the top layer in millimetres.
+    #[serde(default)]
+    pub max_z_mm: Option<f64>,
+    /// Mass of the filament purged into the wipe tower in grams, for multi-material prints.
+    #[serde(default)]
+    pub wipe_tower_g: Option<f64>,
+    /// Filament extruded for perimeters, infill, support and the wipe tower.
+    #[serde(default)]
+    pub extrusion: Option<FeatureExtrusion>,
+    /// The parameters were estimated from the geometry of the model instead of being read
+    /// from sliced G-code.
+    #[serde(default)]
+    pub estimated: bool,
 }
 
-#[derive(Deserialize)]
+/// Filament extruded for each kind of feature of a print, in millimetres of filament.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct FeatureExtrusion {
+    /// External, internal and overhang perimeters.
+    pub perimeter_mm: f64,
+    /// Sparse, solid, top and bridge infill, gap fill and ironing.
+    pub infill_mm: f64,
+    /// Support material and support interfaces.
+    pub support_mm: f64,
+    pub wipe_tower_mm: f64,
+    /// Skirt, brim, custom G-code and features the slicer does not name.
+    pub other_mm: f64,
+}
+
+/// Print time and filament usage a slicer wrote into the metadata of a G-code file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GcodeMetadata {
+    /// Estimated print time in normal mode, in seconds.
+    pub normal_mode_time: Option<u32>,
+    /// Estimated print time in silent (stealth) mode, in seconds.
+    pub silent_mode_time: Option<u32>,
+    /// Filament used by every extruder, in millimetres.
+    pub filament_used_mm: Vec<f64>,
+    /// Filament used by every extruder, in cm3.
+    pub filament_used_cm3: Vec<f64>,
+    /// Filament used by every extruder, in grams.
+    pub filament_used_g: Vec<f64>,
+    /// Filament cost of every extruder, from the prices in the filament profiles.
+    pub filament_cost: Vec<f64>,
+    /// Filament used by all extruders including the wipe tower, in grams.
+    pub total_filament_used_g: Option<f64>,
+    /// Filament cost of all extruders including the wipe tower.
+    pub total_filament_cost: Option<f64>,
+    /// Filament purged into the wipe tower, in grams.
+    pub wipe_tower_g: Option<f64>,
+    /// Number of layers, as reported by the slicer or counted in the G-code.
+    pub layer_count: Option<u32>,
+    /// Height of the top layer, as reported by the slicer or found in the G-code.
+    pub max_layer_z: Option<f64>,
+    /// Filament extruded per kind of feature, only known for text G-code.
+    pub extrusion: Option<FeatureExtrusion>,
+}
+
+/// Most copies a single order may ask for.
+pub const MAX_COPIES_NBR: u32 = 10_000;
+
+/// Longest print time of one copy that is priced, a year in seconds.
+pub const MAX_PRINT_TIME_S: u32 = 365 * 24 * 3600;
+
+/// Most filament one copy may use, 100 km, far more than any printer holds.
+pub const MAX_FILAMENT_MM: u32 = 100_000_000;
+
+/// Most filament one copy may weigh, in grams.
+pub const MAX_FILAMENT_G: f64 = 1_000_000.0;
+
+/// A print job described by its print time and filament usage, priced without slicing a model.
+#[derive(Clone, Debug, Deserialize)]
+pub struct PriceSimulation {
+    /// Name of a material from the material catalogue.
+    pub material_type: String,
+    pub print_type: PrintType,
+    pub copies_nbr: u32,
+    /// Print time of one copy in seconds.
+    pub time: u32,
+    /// Length of the filament used by one copy; derived from `material_g` if missing.
+    #[serde(default)]
+    pub material_mm: Option<u32>,
+    /// Mass of the filament used by one copy in grams.
+    #[serde(default)]
+    pub material_g: Option<f64>,
+}
+
+/// How print time is billed against the hourly rate tiers of a material.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HourlyBilling {
+    /// The whole print time is billed at the rate of the tier it falls in.
+    #[default]
+    WholeJob,
+    /// Every part of the print time is billed at the rate of the tier it falls in, like tax
+    /// brackets.
+    Marginal,
+}
+
+/// Pricing of one print type, e.g. for the supervision and failure rate of precise prints.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PrintTypeAdjustment {
+    pub print_type: PrintType,
+    /// Factor the material and machine time cost of every copy are multiplied by.
+    #[serde(default = "default_print_type_multiplier")]
+    pub multiplier: f64,
+    /// Amount added to the price of every copy.
+    #[serde(default)]
+    pub surcharge: Money,
+}
+
+/// A configuration value that cannot be used, e.g. a negative price.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ConfigurationFieldError {
+    /// Location of the value, e.g. `materials[2].density_g_cm3`.
+    pub field: String,
+    pub message: String,
+}
+
+impl Display for ConfigurationFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Reasons why an update of the pricing parameters was not applied.
+#[derive(Debug)]
+pub enum PricingUpdateError {
+    /// The updated configuration cannot price orders; nothing was changed.
+    Invalid(Vec<ConfigurationFieldError>),
+    /// The configuration file could not be read or written.
+    Io(std::io::Error),
+}
+
+/// Discount on the price of all copies of orders with at least `from_copies` copies.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct QuantityDiscount {
+    pub from_copies: u32,
+    /// Discount in percent, e.g. 10 for -10%.
+    pub percent: f64,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(try_from = "PrinterConfigurationFile")]
 pub struct PrinterConfiguration {
-    pub material_rate_pla: u32,
-    pub material_rate_pet: u32,
-    pub material_rate_asa: u32,
-    pub hourly_rate_time_threshold: [u32; 3],
-    pub hourly_rate_pla_price: [u32; 3],
-    pub hourly_rate_pet_price: [u32; 3],
-    pub hourly_rate_asa_price: [u32; 3],
+    /// Printable size along X, Y and Z in millimetres.
+    pub build_volume: [u32; 3],
+    /// Materials orders may be printed in.
+    pub materials: Vec<Material>,
+    pub hourly_billing: HourlyBilling,
+    /// Multipliers and surcharges of the print types, print types not listed are not adjusted.
+    pub print_type_adjustments: Vec<PrintTypeAdjustment>,
+    /// Setup fee charged once per order.
+    pub order_fee: Money,
+    /// Lowest total of an order before rounding, smaller orders are raised to it.
+    pub minimum_order_value: Money,
+    /// Discount brackets sorted by `from_copies`, the last one an order reaches applies.
+    pub quantity_discounts: Vec<QuantityDiscount>,
+    /// How the total of a quote is rounded.
+    pub price_rounding: RoundingPolicy,
+    /// Whether the materials were converted from the fixed PLA, PET and ASA rates of an older
+    /// file. They name no filament profiles, so slicers use the filament settings of the print
+    /// profile for them.
+    #[serde(skip)]
+    pub legacy_catalogue: bool,
+}
+
+/// Layout of the configuration file, which may still carry the fixed PLA, PET and ASA rates.
+#[derive(Deserialize)]
+struct PrinterConfigurationFile {
+    #[serde(default = "default_build_volume")]
+    build_volume: [u32; 3],
+    #[serde(default)]
+    materials: Vec<Material>,
+    #[serde(default)]
+    hourly_billing: HourlyBilling,
+    #[serde(flatten)]
+    legacy_material_rates: Option<LegacyMaterialRates>,
+    #[serde(default)]
+    print_type_adjustments: Vec<PrintTypeAdjustment>,
+    #[serde(default = "default_order_fee")]
+    order_fee: Money,
+    #[serde(default)]
+    minimum_order_value: Money,
+    #[serde(default)]
+    quantity_discounts: Vec<QuantityDiscount>,
+    #[serde(default)]
+    price_rounding: RoundingPolicy,
+}
+
+impl TryFrom<PrinterConfigurationFile> for PrinterConfiguration {
+    type Error = String;
+
+    fn try_from(file: PrinterConfigurationFile) -> Result<Self, Self::Error> {
+        let (materials, legacy_catalogue) =
+            match (file.materials.is_empty(), file.legacy_material_rates) {
+                (true, Some(legacy_material_rates)) => {
+                    (legacy_material_rates.into_catalogue()?, true)
+                }
+                _ => (file.materials, false),
+            };
+        validate_print_type_adjustments(&file.print_type_adjustments).map_err(|e| e.to_string())?;
+        validate_build_volume(&file.build_volume).map_err(|e| e.to_string())?;
+        validate_order_pricing(
+            file.order_fee,
+            file.minimum_order_value,
+            &file.quantity_discounts,
+        )
+        .map_err(|errors| {
+            errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join("; ")
+        })?;
+        Ok(PrinterConfiguration {
+            build_volume: file.build_volume,
+            materials,
+            hourly_billing: file.hourly_billing,
+            print_type_adjustments: file.print_type_adjustments,
+            order_fee: file.order_fee,
+            minimum_order_value: file.minimum_order_value,
+            quantity_discounts: file.quantity_discounts,
+            price_rounding: file.price_rounding,
+            legacy_catalogue,
+        })
+    }
+}
+
+/// Largest print type multiplier, far above any real surcharge, so prices stay within range.
+const MAX_PRINT_TYPE_MULTIPLIER: f64 = 100.0;
+
+/// Longest axis of a build volume, well beyond the largest desktop and industrial printers.
+const MAX_BUILD_VOLUME_MM: u32 = 10_000;
+
+fn field_error(field: String, message: String) -> Result<(), ConfigurationFieldError> {
+    Err(ConfigurationFieldError { field, message })
+}
+
+/**
+ * @brief Rejects adjustments that would make a print type free, negative, ambiguous or
+ *        absurdly expensive.
+ *
+ * @param adjustments Print type adjustments of a configuration.
+ * @return Result<(), ConfigurationFieldError> The first invalid field found.
+ */
+pub fn validate_print_type_adjustments(
+    adjustments: &[PrintTypeAdjustment],
+) -> Result<(), ConfigurationFieldError> {
+    for (i, adjustment) in adjustments.iter().enumerate() {
+        let field = |name: &str| format!("print_type_adjustments[{}]{}", i, name);
+        if adjustments[..i]
+            .iter()
+            .any(|other| other.print_type == adjustment.print_type)
+        {
+            return field_error(
+                field(".print_type"),
+                format!(
+                    "Print type {} is adjusted more than once",
+                    adjustment.print_type
+                ),
+            );
+        }
+        if !(adjustment.multiplier > 0.0 && adjustment.multiplier <= MAX_PRINT_TYPE_MULTIPLIER) {
+            return field_error(
+                field(".multiplier"),
+                format!(
+                    "Print type {} has a multiplier that is not a positive number of at most {}",
+                    adjustment.print_type, MAX_PRINT_TYPE_MULTIPLIER
+                ),
+            );
+        }
+        if adjustment.surcharge < Money::ZERO {
+            return field_error(
+                field(".surcharge"),
+                format!(
+                    "Print type {} has a negative surcharge",
+                    adjustment.print_type
+                ),
+            );
+        }
+    }
+    Ok(())
+}
+
+/**
+ * @brief Rejects negative order fees and minimums, and discount brackets that cannot be applied.
+ *
+ * @param order_fee Setup fee of a configuration.
+ * @param minimum_order_value Minimum order value of a configuration.
+ * @param quantity_discounts Quantity discount brackets of a configuration.
+ * @return Result<(), Vec<ConfigurationFieldError>> Every invalid field found, the first
+ *         problem of each discount bracket.
+ */
+pub fn validate_order_pricing(
+    order_fee: Money,
+    minimum_order_value: Money,
+    quantity_discounts: &[QuantityDiscount],
+) -> Result<(), Vec<ConfigurationFieldError>> {
+    let mut errors = Vec::new();
+    if order_fee < Money::ZERO {
+        errors.push(ConfigurationFieldError {
+            field: "order_fee".to_string(),
+            message: "The order fee is negative".to_string(),
+        });
+    }
+    if minimum_order_value < Money::ZERO {
+        errors.push(ConfigurationFieldError {
+            field: "minimum_order_value".to_string(),
+            message: "The minimum order value is negative".to_string(),
+        });
+    }
+    for (i, discount) in quantity_discounts.iter().enumerate() {
+        errors.extend(validate_quantity_discount(quantity_discounts, i, discount).err());
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks one discount bracket against the bracket before it.
+fn validate_quantity_discount(
+    quantity_discounts: &[QuantityDiscount],
+    i: usize,
+    discount: &QuantityDiscount,
+) -> Result<(), ConfigurationFieldError> {
+    let field = |name: &str| format!("quantity_discounts[{}]{}", i, name);
+    if discount.from_copies == 0 {
+        return field_error(
+            field(".from_copies"),
+            "Quantity discounts start from at least 1 copy".to_string(),
+        );
+    }
+    if i > 0 && quantity_discounts[i - 1].from_copies >= discount.from_copies {
+        return field_error(
+            field(".from_copies"),
+            format!(
+                "Quantity discounts are not sorted by from_copies at {} copies",
+                discount.from_copies
+            ),
+        );
+    }
+    if !(0.0..100.0).contains(&discount.percent) {
+        return field_error(
+            field(".percent"),
+            format!(
+                "Quantity discount from {} copies is not between 0 and 100 percent",
+                discount.from_copies
+            ),
+        );
+    }
+    Ok(())
+}
+
+/**
+ * @brief Rejects build volumes no printer has, so model fit checks stay meaningful.
+ *
+ * @param build_volume Printable size along X, Y and Z in millimetres.
+ * @return Result<(), ConfigurationFieldError> The first axis out of range.
+ */
+pub fn validate_build_volume(build_volume: &[u32; 3]) -> Result<(), ConfigurationFieldError> {
+    for (i, size) in build_volume.iter().enumerate() {
+        if !(1..=MAX_BUILD_VOLUME_MM).contains(size) {
+            return field_error(
+                format!("build_volume[{}]", i),
+                format!(
+                    "The build volume must be between 1 and {} mm along each axis",
+                    MAX_BUILD_VOLUME_MM
+                ),
+            );
+        }
+    }
+    Ok(())
+}
+
+/**
+ * @brief Rejects orders for no copies or for more copies than a single order may ask for.
+ *
+ * @param copies_nbr Number of copies ordered.
+ * @return Result<(), String> Why the number is rejected.
+ */
+pub fn validate_copies_nbr(copies_nbr: u32) -> Result<(), String> {
+    if (1..=MAX_COPIES_NBR).contains(&copies_nbr) {
+        Ok(())
+    } else {
+        Err(format!(
+            "copies_nbr must be between 1 and {}",
+            MAX_COPIES_NBR
+        ))
+    }
+}
+
+/// Setup fee of configurations that set none.
+pub fn default_order_fee() -> Money {
+    Money::from_pln(1)
+}
+
+/// Multiplier of print types without an adjustment.
+pub fn default_print_type_multiplier() -> f64 {
+    1.0
+}
+
+/**
+ * @brief Returns the coefficients the geometric estimator uses for print types the
+ *        configuration does not list.
+ *
+ * Thick print types are printed faster, strong ones with more walls and infill.
+ *
+ * @param print_type Print type to estimate.
+ * @return EstimatorCoefficients Built-in coefficients of the print type.
+ */
+pub fn default_estimator_coefficients(print_type: &PrintType) -> EstimatorCoefficients {
+    let (wall_thickness_mm, infill_percent, volumetric_speed_mm3_s) = match print_type {
+        PrintType::ThickStrong => (1.6, 40.0, 12.0),
+        PrintType::ThickSoft => (1.2, 15.0, 12.0),
+        PrintType::PreciseStrong => (1.6, 40.0, 6.0),
+        PrintType::PreciseSoft => (1.2, 15.0, 6.0),
+    };
+    EstimatorCoefficients {
+        print_type: print_type.clone(),
+        wall_thickness_mm,
+        infill_percent,
+        volumetric_speed_mm3_s,
+    }
+}
+
+/// Build volume of the Prusa MK3/MK4 printers, used when the configuration sets none.
+pub fn default_build_volume() -> [u32; 3] {
+    [250, 210, 210]
 }
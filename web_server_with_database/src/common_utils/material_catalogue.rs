@@ -0,0 +1,446 @@
+/* IMPORTS FROM LIBRARIES */
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::f64::consts::PI;
+use std::io;
+use std::path::Path;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::ConfigurationFieldError;
+use crate::common_utils::money::Money;
+
+/* PRIVATE TYPES AND VARIABLES */
+const LEGACY_MATERIAL_DENSITIES: [(&str, f64); 3] = [("PLA", 1.24), ("PET", 1.27), ("ASA", 1.07)];
+
+/* PUBLIC TYPES AND VARIABLES */
+/// Price of a filament, either by weight or by length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaterialPrice {
+    PerKg(Money),
+    PerMetre(Money),
+}
+
+/// Hourly rate charged for print times above `from_hours`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HourlyRateTier {
+    pub from_hours: u32,
+    pub rate: Money,
+}
+
+/// A filament that orders may be printed in.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Material {
+    /// Name clients order the material by, e.g. "PETG" or "PA-CF".
+    pub name: String,
+    pub density_g_cm3: f64,
+    #[serde(default = "default_filament_diameter")]
+    pub filament_diameter_mm: f64,
+    pub price: MaterialPrice,
+    /// Hourly rate tiers sorted by `from_hours`, the first one starting at 0 hours.
+    pub hourly_rates: Vec<HourlyRateTier>,
+    /// Filament profile in the profile directory of the selected slicer, loaded on top of the
+    /// print profile when slicing models in this material.
+    #[serde(default, alias = "prusa_filament_profile")]
+    pub filament_profile: Option<String>,
+}
+
+/// Per-material rates of configuration files written before the material catalogue existed.
+#[derive(Deserialize)]
+pub struct LegacyMaterialRates {
+    pub material_rate_pla: u32,
+    pub material_rate_pet: u32,
+    pub material_rate_asa: u32,
+    pub hourly_rate_time_threshold: Vec<u32>,
+    pub hourly_rate_pla_price: Vec<Money>,
+    pub hourly_rate_pet_price: Vec<Money>,
+    pub hourly_rate_asa_price: Vec<Money>,
+}
+
+/* PRIVATE FUNCTIONS */
+fn invalid_field(field: String, message: String) -> Result<(), ConfigurationFieldError> {
+    Err(ConfigurationFieldError { field, message })
+}
+
+fn validate_hourly_rate_tiers(
+    field: &str,
+    material: &Material,
+) -> Result<(), ConfigurationFieldError> {
+    match material.hourly_rates.first() {
+        None => invalid_field(
+            format!("{}.hourly_rates", field),
+            format!("Material '{}' has no hourly rates", material.name),
+        ),
+        Some(first_tier) if first_tier.from_hours != 0 => invalid_field(
+            format!("{}.hourly_rates[0].from_hours", field),
+            format!(
+                "The first hourly rate of material '{}' must start at 0 hours",
+                material.name
+            ),
+        ),
+        Some(_) => match material
+            .hourly_rates
+            .windows(2)
+            .position(|pair| pair[0].from_hours >= pair[1].from_hours)
+        {
+            Some(i) => invalid_field(
+                format!("{}.hourly_rates[{}].from_hours", field, i + 1),
+                format!(
+                    "Hourly rates of material '{}' are not sorted: {} h is followed by {} h",
+                    material.name,
+                    material.hourly_rates[i].from_hours,
+                    material.hourly_rates[i + 1].from_hours
+                ),
+            ),
+            None => Ok(()),
+        },
+    }
+}
+
+fn validate_material(
+    field: &str,
+    material: &Material,
+    profile_dir: &Path,
+    require_filament_profile: bool,
+) -> Result<(), ConfigurationFieldError> {
+    let name = &material.name;
+    if !(material.density_g_cm3.is_finite() && material.density_g_cm3 > 0.0) {
+        return invalid_field(
+            format!("{}.density_g_cm3", field),
+            format!("Material '{}' has an invalid density", name),
+        );
+    }
+    if !(material.filament_diameter_mm.is_finite() && material.filament_diameter_mm > 0.0) {
+        return invalid_field(
+            format!("{}.filament_diameter_mm", field),
+            format!("Material '{}' has an invalid filament diameter", name),
+        );
+    }
+    validate_hourly_rate_tiers(field, material)?;
+    let (MaterialPrice::PerKg(price) | MaterialPrice::PerMetre(price)) = material.price;
+    if price < Money::ZERO {
+        return invalid_field(
+            format!("{}.price", field),
+            format!("Material '{}' has a negative price", name),
+        );
+    }
+    if let Some(i) = material
+        .hourly_rates
+        .iter()
+        .position(|tier| tier.rate < Money::ZERO)
+    {
+        return invalid_field(
+            format!("{}.hourly_rates[{}].rate", field, i),
+            format!("Material '{}' has a negative price", name),
+        );
+    }
+    if require_filament_profile && material.filament_profile.is_none() {
+        return invalid_field(
+            format!("{}.filament_profile", field),
+            format!(
+                "Material '{}' has no filament profile to slice its orders with",
+                name
+            ),
+        );
+    }
+    if let Some(profile) = &material.filament_profile {
+        let is_plain_file_name = Path::new(profile).file_name() == Some(profile.as_ref());
+        if !is_plain_file_name || !profile_dir.join(profile).is_file() {
+            return invalid_field(
+                format!("{}.filament_profile", field),
+                format!(
+                    "Filament profile '{}' of material '{}' is not a file in {}",
+                    profile,
+                    name,
+                    profile_dir.display()
+                ),
+            );
+        }
+    }
+    Ok(())
+}
+
+/* PUBLIC FUNCTIONS */
+/// Diameter of the filament used by Prusa printers, used when a material sets none.
+pub fn default_filament_diameter() -> f64 {
+    1.75
+}
+
+impl Material {
+    /**
+     * @brief Computes the mass of a length of this filament.
+     *
+     * @param length_mm Length of the filament in millimetres.
+     * @return f64 Mass in grams.
+     */
+    pub fn filament_mass_g(&self, length_mm: u32) -> f64 {
+        let radius_mm = self.filament_diameter_mm / 2.0;
+        let volume_cm3 = f64::from(length_mm) * PI * radius_mm * radius_mm / 1000.0;
+        volume_cm3 * self.density_g_cm3
+    }
+
+    /**
+     * @brief Computes the length of this filament that has a given mass.
+     *
+     * @param mass_g Mass of the filament in grams.
+     * @return Option<u32> Length of the filament in millimetres, rounded to whole millimetres,
+     *         None if the mass is negative, not a number or too large to express in them.
+     */
+    pub fn filament_length_mm(&self, mass_g: f64) -> Option<u32> {
+        let radius_mm = self.filament_diameter_mm / 2.0;
+        let volume_cm3 = mass_g / self.density_g_cm3;
+        let length_mm = (volume_cm3 * 1000.0 / (PI * radius_mm * radius_mm)).round();
+        (0.0..=f64::from(u32::MAX))
+            .contains(&length_mm)
+            .then_some(length_mm as u32)
+    }
+}
+
+impl LegacyMaterialRates {
+    /**
+     * @brief Turns the fixed PLA, PET and ASA rates into an equivalent catalogue.
+     *
+     * @return Result<Vec<Material>, String> Catalogue priced per metre with typical densities,
+     *         or an error if a list of hourly rates does not match the time thresholds.
+     */
+    pub fn into_catalogue(self) -> Result<Vec<Material>, String> {
+        let rates = [
+            (self.material_rate_pla, self.hourly_rate_pla_price),
+            (self.material_rate_pet, self.hourly_rate_pet_price),
+            (self.material_rate_asa, self.hourly_rate_asa_price),
+        ];
+        let thresholds = &self.hourly_rate_time_threshold;
+        LEGACY_MATERIAL_DENSITIES
+            .iter()
+            .zip(rates)
+            .map(
+                |((name, density_g_cm3), (rate_grosz_per_m, hourly_prices))| {
+                    if hourly_prices.len() != thresholds.len() {
+                        return Err(format!(
+                            "{} has {} hourly rates for {} time thresholds",
+                            name,
+                            hourly_prices.len(),
+                            thresholds.len()
+                        ));
+                    }
+                    Ok(Material {
+                        name: name.to_string(),
+                        density_g_cm3: *density_g_cm3,
+                        filament_diameter_mm: default_filament_diameter(),
+                        price: MaterialPrice::PerMetre(Money::from_grosz(i64::from(
+                            rate_grosz_per_m,
+                        ))),
+                        hourly_rates: thresholds
+                            .iter()
+                            .zip(hourly_prices)
+                            .map(|(from_hours, rate)| HourlyRateTier {
+                                from_hours: *from_hours,
+                                rate,
+                            })
+                            .collect(),
+                        filament_profile: None,
+                    })
+                },
+            )
+            .collect()
+    }
+}
+
+/**
+ * @brief Checks that a material catalogue can price orders, naming the offending field.
+ *
+ * @param materials Catalogue to check.
+ * @param profile_dir Directory the filament profiles of the selected slicer are stored in.
+ * @param require_filament_profiles Whether every material must name a filament profile, as
+ *        when the selected slicer slices models.
+ * @return Result<(), ConfigurationFieldError> The first problem found, located in the
+ *         `materials` list of the configuration.
+ */
+pub fn check_material_catalogue(
+    materials: &[Material],
+    profile_dir: &Path,
+    require_filament_profiles: bool,
+) -> Result<(), ConfigurationFieldError> {
+    if materials.is_empty() {
+        return invalid_field(
+            "materials".to_string(),
+            "The material catalogue is empty".to_string(),
+        );
+    }
+    let mut names = HashSet::new();
+    for (i, material) in materials.iter().enumerate() {
+        let field = format!("materials[{}]", i);
+        if material.name.trim().is_empty() {
+            return invalid_field(
+                format!("{}.name", field),
+                "A material in the catalogue has no name".to_string(),
+            );
+        }
+        if !names.insert(material.name.as_str()) {
+            return invalid_field(
+                format!("{}.name", field),
+                format!("Material '{}' is listed more than once", material.name),
+            );
+        }
+        validate_material(&field, material, profile_dir, require_filament_profiles)?;
+    }
+    Ok(())
+}
+
+/**
+ * @brief Checks that a material catalogue can price orders.
+ *
+ * @param materials Catalogue to check.
+ * @param profile_dir Directory the filament profiles of the selected slicer are stored in.
+ * @param require_filament_profiles Whether every material must name a filament profile.
+ * @return io::Result<()> An `InvalidData` error describing the first problem found.
+ */
+pub fn validate_material_catalogue(
+    materials: &[Material],
+    profile_dir: &Path,
+    require_filament_profiles: bool,
+) -> io::Result<()> {
+    check_material_catalogue(materials, profile_dir, require_filament_profiles)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.message))
+}
+
+/**
+ * @brief Looks up a material by the name clients order it by.
+ *
+ * @param materials Catalogue to search.
+ * @param name Name of the material, compared exactly.
+ * @return Option<&Material> The material, if it is catalogued.
+ */
+pub fn find_material<'a>(materials: &'a [Material], name: &str) -> Option<&'a Material> {
+    materials.iter().find(|material| material.name == name)
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_utils::global_types::PrinterConfiguration;
+
+    fn tiers(tiers: &[(u32, i64)]) -> Vec<HourlyRateTier> {
+        tiers
+            .iter()
+            .map(|(from_hours, rate)| HourlyRateTier {
+                from_hours: *from_hours,
+                rate: Money::from_pln(*rate),
+            })
+            .collect()
+    }
+
+    fn petg() -> Material {
+        Material {
+            name: "PETG".to_string(),
+            density_g_cm3: 1.27,
+            filament_diameter_mm: default_filament_diameter(),
+            price: MaterialPrice::PerKg(Money::from_pln(90)),
+            hourly_rates: tiers(&[(0, 35), (10, 30), (100, 25)]),
+            filament_profile: None,
+        }
+    }
+
+    #[test]
+    fn test_catalogue_is_read_from_json_and_validated() {
+        let materials: Vec<Material> = serde_json::from_str(
+            r#"[{"name": "PETG", "density_g_cm3": 1.27, "price": {"per_kg": "90.00"},
+                 "hourly_rates": [{"from_hours": 0, "rate": 35}, {"from_hours": 10, "rate": 30},
+                                  {"from_hours": 100, "rate": 25}]}]"#,
+        )
+        .unwrap();
+        assert_eq!(materials, vec![petg()]);
+        let renamed: Material = serde_json::from_value(serde_json::json!({
+            "name": "PETG", "density_g_cm3": 1.27, "price": {"per_kg": 90},
+            "hourly_rates": [{"from_hours": 0, "rate": 35}],
+            "prusa_filament_profile": "filament_petg.ini"
+        }))
+        .unwrap();
+        assert_eq!(
+            renamed.filament_profile.as_deref(),
+            Some("filament_petg.ini")
+        );
+        assert!(validate_material_catalogue(&materials, Path::new("."), false).is_ok());
+        assert_eq!(find_material(&materials, "PETG"), Some(&petg()));
+        assert_eq!(find_material(&materials, "petg"), None);
+        // One metre of 1.75 mm filament is 2.405 cm3
+        assert!((petg().filament_mass_g(1000) - 3.054).abs() < 1e-3);
+        assert_eq!(
+            petg().filament_length_mm(petg().filament_mass_g(1000)),
+            Some(1000)
+        );
+        assert_eq!(petg().filament_length_mm(1e12), None);
+        assert_eq!(petg().filament_length_mm(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_invalid_catalogues_are_rejected() {
+        let dir = Path::new(".");
+        assert!(validate_material_catalogue(&[], dir, false).is_err());
+        assert!(validate_material_catalogue(&[petg(), petg()], dir, false).is_err());
+        let mut weightless = petg();
+        weightless.density_g_cm3 = 0.0;
+        assert!(validate_material_catalogue(&[weightless], dir, false).is_err());
+        let mut escaping_profile = petg();
+        escaping_profile.filament_profile = Some("../../etc/passwd".to_string());
+        assert!(validate_material_catalogue(&[escaping_profile], dir, false).is_err());
+        let mut missing_profile = petg();
+        missing_profile.filament_profile = Some("missing_profile.ini".to_string());
+        assert!(validate_material_catalogue(&[missing_profile], dir, false).is_err());
+        let mut late_start = petg();
+        late_start.hourly_rates = tiers(&[(1, 35)]);
+        assert!(validate_material_catalogue(&[late_start], dir, false).is_err());
+        let mut unsorted = petg();
+        unsorted.hourly_rates = tiers(&[(0, 35), (100, 25), (10, 30)]);
+        assert!(validate_material_catalogue(&[unsorted], dir, false).is_err());
+        let mut no_rates = petg();
+        no_rates.hourly_rates.clear();
+        assert!(validate_material_catalogue(&[no_rates], dir, false).is_err());
+
+        let mut negative_rate = petg();
+        negative_rate.name = "PETG-CF".to_string();
+        negative_rate.hourly_rates = tiers(&[(0, 35), (10, -30)]);
+        let error = check_material_catalogue(&[petg(), negative_rate], dir, false).unwrap_err();
+        assert_eq!(error.field, "materials[1].hourly_rates[1].rate");
+
+        // Slicers need a filament profile for every material
+        let error = check_material_catalogue(&[petg()], dir, true).unwrap_err();
+        assert_eq!(error.field, "materials[0].filament_profile");
+    }
+
+    #[test]
+    fn test_legacy_configuration_becomes_a_catalogue() {
+        let config: PrinterConfiguration = serde_json::from_str(
+            r#"{"prusa_path": "/usr/bin/prusa-slicer",
+                "material_rate_pla": 60, "material_rate_pet": 80, "material_rate_asa": 100,
+                "hourly_rate_time_threshold": [0, 10, 100, 200],
+                "hourly_rate_pla_price": [30, 25, 20, 18],
+                "hourly_rate_pet_price": [35, 30, 25, 22],
+                "hourly_rate_asa_price": [40, 35, 30, 28]}"#,
+        )
+        .unwrap();
+        let names: Vec<&str> = config.materials.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["PLA", "PET", "ASA"]);
+        let asa = find_material(&config.materials, "ASA").unwrap();
+        assert_eq!(asa.price, MaterialPrice::PerMetre(Money::from_grosz(100)));
+        assert_eq!(
+            asa.hourly_rates,
+            tiers(&[(0, 40), (10, 35), (100, 30), (200, 28)])
+        );
+        assert!(validate_material_catalogue(&config.materials, Path::new("."), false).is_ok());
+
+        let mismatched = serde_json::from_str::<PrinterConfiguration>(
+            r#"{"material_rate_pla": 60, "material_rate_pet": 80, "material_rate_asa": 100,
+                "hourly_rate_time_threshold": [0, 10, 100],
+                "hourly_rate_pla_price": [30, 25, 20],
+                "hourly_rate_pet_price": [35, 30],
+                "hourly_rate_asa_price": [40, 35, 30]}"#,
+        );
+        assert!(mismatched
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("PET has 2 hourly rates for 3 time thresholds"));
+    }
+}
@@ -1,5 +1,11 @@
 // PUBLIC MODULES
+pub mod background_job_pool;
 pub mod global_types;
 pub mod global_traits;
+pub mod material_catalogue;
+pub mod model_storage;
+pub mod money;
+pub mod pricing_parameters;
+pub mod pricing_versions;
 
 // PRIVATE MODULES
@@ -0,0 +1,161 @@
+/* IMPORTS FROM LIBRARIES */
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/* IMPORTS FROM OTHER MODULES */
+
+/* PRIVATE TYPES AND VARIABLES */
+static NEXT_UPLOAD_NBR: AtomicU64 = AtomicU64::new(0);
+
+/* PUBLIC TYPES AND VARIABLES */
+/// Directory, relative to the workspace, where uploaded models are stored.
+pub const RECEIVED_ORDERS_DIR: &str = "data_files/received_orders";
+/// Directory, relative to the workspace, where the sliced G-code is written.
+pub const PROCESSED_ORDERS_DIR: &str = "data_files/processed_orders";
+
+/* PRIVATE FUNCTIONS */
+fn invalid_file_name(file_name: &str, reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("Invalid file name '{}': {}", file_name, reason),
+    )
+}
+
+fn is_valid_model_key(model_key: &str) -> bool {
+    !model_key.is_empty()
+        && !model_key.starts_with('.')
+        && model_key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+}
+
+fn model_key_path(ws_path: &str, dir: &str, model_key: &str, suffix: &str) -> io::Result<PathBuf> {
+    if !is_valid_model_key(model_key) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Invalid model key '{}'", model_key),
+        ));
+    }
+    Ok(Path::new(ws_path)
+        .join(dir)
+        .join(format!("{}{}", model_key, suffix)))
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Validates the file name supplied by a client.
+ *
+ * The name is only kept as order metadata, but names that try to address another directory
+ * are rejected outright so the client gets a clear error instead of a silently renamed file.
+ *
+ * @param file_name File name as sent by the client.
+ * @return io::Result<String> The sanitized file name, or an `InvalidInput` error.
+ */
+pub fn sanitize_client_file_name(file_name: &str) -> io::Result<String> {
+    if file_name.contains(['/', '\\']) || file_name == "." || file_name == ".." {
+        return Err(invalid_file_name(
+            file_name,
+            "path components are not allowed",
+        ));
+    }
+    let sanitized = sanitize_filename::sanitize(file_name);
+    if sanitized.trim().is_empty() {
+        return Err(invalid_file_name(file_name, "the name is empty"));
+    }
+    Ok(sanitized)
+}
+
+/**
+ * @brief Generates a unique, server-controlled key under which an uploaded model is stored.
+ *
+ * The key combines the upload time, the process ID and a per-process counter, so concurrent
+ * uploads of files with the same name never share a path. The lowercase extension of the
+ * original file name is kept, because the slicer detects the model format from it.
+ *
+ * @param file_name Sanitized original file name of the model.
+ * @return String Key of the stored model.
+ */
+pub fn generate_model_key(file_name: &str) -> String {
+    let upload_nbr = NEXT_UPLOAD_NBR.fetch_add(1, Ordering::Relaxed);
+    let extension = Path::new(file_name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .filter(|ext| !ext.is_empty() && ext.chars().all(|c| c.is_ascii_alphanumeric()))
+        .map(|ext| format!(".{}", ext.to_ascii_lowercase()))
+        .unwrap_or_default();
+    format!(
+        "{}-{}-{:04}{}",
+        chrono::Utc::now().format("%Y%m%d%H%M%S%f"),
+        std::process::id(),
+        upload_nbr,
+        extension
+    )
+}
+
+/**
+ * @brief Returns the path of an uploaded model.
+ *
+ * @param ws_path Path to the workspace directory.
+ * @param model_key Key returned by `generate_model_key`.
+ * @return io::Result<PathBuf> Path of the model, or an error if the key is malformed.
+ */
+pub fn received_model_path(ws_path: &str, model_key: &str) -> io::Result<PathBuf> {
+    model_key_path(ws_path, RECEIVED_ORDERS_DIR, model_key, "")
+}
+
+/**
+ * @brief Returns the path of the G-code sliced from an uploaded model.
+ *
+ * @param ws_path Path to the workspace directory.
+ * @param model_key Key returned by `generate_model_key`.
+ * @return io::Result<PathBuf> Path of the G-code, or an error if the key is malformed.
+ */
+pub fn processed_gcode_path(ws_path: &str, model_key: &str) -> io::Result<PathBuf> {
+    model_key_path(ws_path, PROCESSED_ORDERS_DIR, model_key, ".gcode")
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_client_file_name_rejects_traversal() {
+        for name in [
+            "../evil.stl",
+            "..\\evil.stl",
+            "/etc/passwd",
+            "a/b.stl",
+            "..",
+            "",
+        ] {
+            let err = sanitize_client_file_name(name).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "name: {}", name);
+        }
+        assert_eq!(sanitize_client_file_name("part.stl").unwrap(), "part.stl");
+        assert_eq!(sanitize_client_file_name("pa<r>t.stl").unwrap(), "part.stl");
+    }
+
+    #[test]
+    fn test_generate_model_key_is_unique_and_path_safe() {
+        let first = generate_model_key("part.STL");
+        let second = generate_model_key("part.STL");
+        assert_ne!(first, second);
+        assert!(first.ends_with(".stl"));
+        assert_eq!(
+            received_model_path("ws", &first).unwrap(),
+            Path::new("ws/data_files/received_orders").join(&first)
+        );
+        assert_eq!(
+            processed_gcode_path("ws", &first).unwrap(),
+            Path::new("ws/data_files/processed_orders").join(format!("{}.gcode", first))
+        );
+    }
+
+    #[test]
+    fn test_model_paths_reject_malformed_keys() {
+        assert!(received_model_path("ws", "../part.stl").is_err());
+        assert!(processed_gcode_path("ws", "").is_err());
+    }
+}
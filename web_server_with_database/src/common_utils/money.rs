@@ -0,0 +1,355 @@
+/* IMPORTS FROM LIBRARIES */
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/* IMPORTS FROM OTHER MODULES */
+
+/* PRIVATE TYPES AND VARIABLES */
+const GROSZ_PER_PLN: i64 = 100;
+
+/* PUBLIC TYPES AND VARIABLES */
+/// Amount of money in grosz (0.01 PLN).
+///
+/// Amounts are serialized as decimal strings such as `"12.30"`, so no precision is lost in JSON.
+/// Plain JSON numbers are accepted too and read as PLN, which keeps configuration files and
+/// data stored before amounts were exact readable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money(i64);
+
+/// Direction in which quoted prices are rounded to the rounding step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    Up,
+    Down,
+    #[default]
+    Nearest,
+}
+
+/// How the total of a quote is rounded, e.g. up to the next 0.10 PLN.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RoundingPolicy {
+    pub mode: RoundingMode,
+    pub step: Money,
+}
+
+/* PRIVATE FUNCTIONS */
+fn invalid_amount(amount: &str) -> String {
+    format!("Invalid amount of money: '{}'", amount)
+}
+
+/* PUBLIC FUNCTIONS */
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    pub fn from_grosz(grosz: i64) -> Self {
+        Money(grosz)
+    }
+
+    /**
+     * @brief Converts a whole amount in PLN known to be in range, such as a built-in default.
+     *
+     * @param pln Amount in PLN.
+     * @return Money The amount; panics if it cannot be represented, use `checked_from_pln`
+     *         for amounts read from input.
+     */
+    pub const fn from_pln(pln: i64) -> Self {
+        match Money::checked_from_pln(pln) {
+            Some(amount) => amount,
+            None => panic!("Amount of money out of range"),
+        }
+    }
+
+    /**
+     * @brief Converts a whole amount in PLN.
+     *
+     * @param pln Amount in PLN.
+     * @return Option<Money> The amount, or None if it cannot be represented in grosz.
+     */
+    pub const fn checked_from_pln(pln: i64) -> Option<Self> {
+        match pln.checked_mul(GROSZ_PER_PLN) {
+            Some(grosz) => Some(Money(grosz)),
+            None => None,
+        }
+    }
+
+    pub fn grosz(self) -> i64 {
+        self.0
+    }
+
+    /**
+     * @brief Converts an amount in PLN given as a floating point number, e.g. by a slicer.
+     *
+     * @param pln Amount in PLN.
+     * @return Option<Money> The amount rounded to whole grosz, or None if it is not finite or
+     *         cannot be represented in grosz.
+     */
+    pub fn from_pln_f64(pln: f64) -> Option<Self> {
+        let grosz = (pln * GROSZ_PER_PLN as f64).round();
+        // i64::MAX is not exactly representable, the bound is the first value above it
+        (grosz.is_finite() && grosz >= i64::MIN as f64 && grosz < i64::MAX as f64)
+            .then_some(Money(grosz as i64))
+    }
+
+    /**
+     * @brief Converts a fractional amount of grosz, rounding halves away from zero.
+     *
+     * Costs derived from rates, such as grosz per metre times millimetres, are rarely whole
+     * grosz; this is the single place where they are rounded.
+     *
+     * @param numerator Amount in grosz multiplied by `denominator`.
+     * @param denominator Positive divisor of `numerator`.
+     * @return Money The amount rounded to whole grosz.
+     */
+    pub fn from_grosz_fraction(numerator: i64, denominator: i64) -> Self {
+        // Widened, as the magnitude of i64::MIN does not fit; the result is never larger
+        let (numerator, denominator) = (i128::from(numerator), i128::from(denominator));
+        let rounded = (numerator.abs() + denominator / 2) / denominator * numerator.signum();
+        Money(rounded as i64)
+    }
+
+    /**
+     * @brief Multiplies the amount by a configured factor, such as a pricing multiplier.
+     *
+     * The factor is taken with a precision of one millionth, so the result does not depend on
+     * how the factor happens to be represented as a floating point number.
+     *
+     * @param factor Finite factor to multiply by.
+     * @return Option<Money> The product rounded to whole grosz, or None if it is out of range.
+     */
+    pub fn multiplied_by(self, factor: f64) -> Option<Self> {
+        let factor_millionths = (factor * 1_000_000.0).round();
+        if !(factor_millionths.is_finite() && factor_millionths.abs() < i64::MAX as f64) {
+            return None;
+        }
+        self.0
+            .checked_mul(factor_millionths as i64)
+            .map(|product| Money::from_grosz_fraction(product, 1_000_000))
+    }
+
+    pub fn checked_add(self, other: Money) -> Option<Self> {
+        self.0.checked_add(other.0).map(Money)
+    }
+
+    pub fn checked_sub(self, other: Money) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Money)
+    }
+
+    /// Amount of several copies, None if it is out of range.
+    pub fn checked_mul(self, copies: u32) -> Option<Self> {
+        self.0.checked_mul(i64::from(copies)).map(Money)
+    }
+
+    /**
+     * @brief Rounds the amount to a multiple of the policy's step.
+     *
+     * @param policy Rounding step and direction.
+     * @return Option<Money> The rounded amount, unchanged if the step is not positive, or None
+     *         if the multiple of the step it rounds to is out of range.
+     */
+    pub fn round(self, policy: &RoundingPolicy) -> Option<Self> {
+        if policy.step <= Money::ZERO {
+            return Some(self);
+        }
+        let step = policy.step.0;
+        let floor = self.0.div_euclid(step).checked_mul(step)?;
+        let remainder = self.0 - floor;
+        let rounded = match policy.mode {
+            RoundingMode::Down => Some(floor),
+            RoundingMode::Up if remainder > 0 => floor.checked_add(step),
+            RoundingMode::Up => Some(floor),
+            RoundingMode::Nearest if remainder >= step - remainder => floor.checked_add(step),
+            RoundingMode::Nearest => Some(floor),
+        };
+        rounded.map(Money)
+    }
+}
+
+impl Default for RoundingPolicy {
+    /// Rounds to the nearest grosz, so quotes are exactly the sum of their components.
+    fn default() -> Self {
+        RoundingPolicy {
+            mode: RoundingMode::Nearest,
+            step: Money(1),
+        }
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let grosz = self.0.unsigned_abs();
+        write!(
+            f,
+            "{}{}.{:02}",
+            sign,
+            grosz / GROSZ_PER_PLN as u64,
+            grosz % GROSZ_PER_PLN as u64
+        )
+    }
+}
+
+impl FromStr for Money {
+    type Err = String;
+
+    /// Parses amounts in PLN with at most two decimal places, such as `12`, `12.3` or `-0.05`.
+    fn from_str(amount: &str) -> Result<Self, Self::Err> {
+        let trimmed = amount.trim();
+        let (negative, digits) = match trimmed.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, trimmed),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !all_digits(whole) || !all_digits(fraction) || fraction.len() > 2 {
+            return Err(invalid_amount(amount));
+        }
+        let whole: i64 = whole.parse().map_err(|_| invalid_amount(amount))?;
+        let fraction: i64 = format!("{:0<2}", fraction).parse().unwrap_or(0);
+        let grosz = whole
+            .checked_mul(GROSZ_PER_PLN)
+            .and_then(|g| g.checked_add(fraction))
+            .ok_or_else(|| invalid_amount(amount))?;
+        Ok(Money(if negative { -grosz } else { grosz }))
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MoneyVisitor;
+
+        impl de::Visitor<'_> for MoneyVisitor {
+            type Value = Money;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an amount in PLN, as a string like \"12.30\" or a number")
+            }
+
+            fn visit_str<E: de::Error>(self, amount: &str) -> Result<Money, E> {
+                amount.parse().map_err(E::custom)
+            }
+
+            fn visit_i64<E: de::Error>(self, pln: i64) -> Result<Money, E> {
+                Money::checked_from_pln(pln)
+                    .ok_or_else(|| E::custom(invalid_amount(&pln.to_string())))
+            }
+
+            fn visit_u64<E: de::Error>(self, pln: u64) -> Result<Money, E> {
+                i64::try_from(pln)
+                    .ok()
+                    .and_then(Money::checked_from_pln)
+                    .ok_or_else(|| E::custom(invalid_amount(&pln.to_string())))
+            }
+
+            fn visit_f64<E: de::Error>(self, pln: f64) -> Result<Money, E> {
+                Money::from_pln_f64(pln).ok_or_else(|| E::custom(invalid_amount(&pln.to_string())))
+            }
+        }
+
+        deserializer.deserialize_any(MoneyVisitor)
+    }
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_money_is_parsed_and_formatted_exactly() {
+        assert_eq!("12.3".parse::<Money>(), Ok(Money::from_grosz(1230)));
+        assert_eq!("-0.05".parse::<Money>(), Ok(Money::from_grosz(-5)));
+        assert_eq!("7".parse::<Money>(), Ok(Money::from_pln(7)));
+        assert!("1.234".parse::<Money>().is_err());
+        assert!("1,5".parse::<Money>().is_err());
+        assert_eq!(Money::from_grosz(-105).to_string(), "-1.05");
+        assert_eq!(
+            serde_json::to_string(&Money::from_grosz(6220)).unwrap(),
+            "\"62.20\""
+        );
+        // Numbers are PLN, as in configuration files and breakdowns stored as floats
+        assert_eq!(
+            serde_json::from_str::<Money>("30.6").unwrap(),
+            Money::from_grosz(3060)
+        );
+    }
+
+    #[test]
+    fn test_rounding_policies() {
+        let amount = Money::from_grosz(6221);
+        let policy = |mode, step| RoundingPolicy {
+            mode,
+            step: Money::from_grosz(step),
+        };
+        assert_eq!(
+            amount.round(&policy(RoundingMode::Up, 10)).unwrap().grosz(),
+            6230
+        );
+        assert_eq!(
+            amount
+                .round(&policy(RoundingMode::Down, 10))
+                .unwrap()
+                .grosz(),
+            6220
+        );
+        assert_eq!(
+            amount
+                .round(&policy(RoundingMode::Nearest, 10))
+                .unwrap()
+                .grosz(),
+            6220
+        );
+        assert_eq!(
+            amount
+                .round(&policy(RoundingMode::Nearest, 50))
+                .unwrap()
+                .grosz(),
+            6200
+        );
+        assert_eq!(amount.round(&RoundingPolicy::default()), Some(amount));
+        let near_max = Money::from_grosz(i64::MAX - 1);
+        assert_eq!(near_max.round(&policy(RoundingMode::Up, 10)), None);
+        assert_eq!(
+            Money::from_grosz(i64::MIN + 1).round(&policy(RoundingMode::Down, 10)),
+            None
+        );
+        assert_eq!(Money::from_grosz_fraction(600, 1000), Money::from_grosz(1));
+        assert_eq!(Money::from_grosz_fraction(499, 1000), Money::ZERO);
+        assert_eq!(
+            Money::from_grosz_fraction(-1500, 1000),
+            Money::from_grosz(-2)
+        );
+        assert_eq!(
+            Money::from_grosz_fraction(i64::MIN, 1),
+            Money::from_grosz(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_out_of_range_is_reported() {
+        let ten = Money::from_pln(10);
+        assert_eq!(ten.multiplied_by(1.15), Some(Money::from_grosz(1150)));
+        assert_eq!(Money::from_grosz(i64::MAX / 2).multiplied_by(100.0), None);
+        assert_eq!(ten.multiplied_by(f64::INFINITY), None);
+        assert_eq!(ten.checked_add(ten), Some(Money::from_pln(20)));
+        assert_eq!(Money::from_grosz(i64::MAX).checked_add(ten), None);
+        assert_eq!(Money::from_grosz(i64::MIN).checked_sub(ten), None);
+        assert_eq!(ten.checked_mul(3), Some(Money::from_pln(30)));
+        assert_eq!(Money::from_grosz(i64::MAX / 2).checked_mul(3), None);
+        assert_eq!(Money::checked_from_pln(i64::MAX), None);
+        assert_eq!(Money::from_pln_f64(1e30), None);
+
+        // Amounts that do not fit are rejected instead of wrapping around
+        assert!(serde_json::from_str::<Money>("9223372036854775807").is_err());
+        assert!(serde_json::from_str::<Money>("-9223372036854775807").is_err());
+        assert!(serde_json::from_str::<Money>("1e30").is_err());
+        assert!(serde_json::from_str::<Money>("\"92233720368547758.08\"").is_err());
+    }
+}
@@ -0,0 +1,400 @@
+/* IMPORTS FROM LIBRARIES */
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::{
+    default_order_fee, validate_build_volume, validate_order_pricing,
+    validate_print_type_adjustments, ConfigurationFieldError, HourlyBilling, PrintTypeAdjustment,
+    PrinterConfiguration, QuantityDiscount,
+};
+use crate::common_utils::material_catalogue::{check_material_catalogue, Material};
+use crate::common_utils::money::{Money, RoundingPolicy};
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Fields of configuration files written before the material catalogue existed, replaced by
+/// `materials` when the file is rewritten.
+const LEGACY_KEYS: [&str; 7] = [
+    "material_rate_pla",
+    "material_rate_pet",
+    "material_rate_asa",
+    "hourly_rate_time_threshold",
+    "hourly_rate_pla_price",
+    "hourly_rate_pet_price",
+    "hourly_rate_asa_price",
+];
+
+/* PUBLIC TYPES AND VARIABLES */
+/// Fields of the configuration file that may be changed through the pricing API.
+pub const PRICING_KEYS: [&str; 8] = [
+    "build_volume",
+    "materials",
+    "hourly_billing",
+    "print_type_adjustments",
+    "order_fee",
+    "minimum_order_value",
+    "quantity_discounts",
+    "price_rounding",
+];
+
+/* PRIVATE FUNCTIONS */
+/**
+ * @brief Applies a JSON merge patch (RFC 7386): objects are merged, null removes a field and
+ *        any other value, including a list, replaces the current one.
+ *
+ * @param target Value to patch.
+ * @param patch Patch to apply.
+ */
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+/**
+ * @brief Checks that a field of the configuration has the expected type.
+ *
+ * @param config Configuration as JSON.
+ * @param key Name of the field; a missing field takes its default and is not checked.
+ * @param errors Collected errors.
+ * @return Option<T> The typed value, if the field is set and valid.
+ */
+fn check_field<T: DeserializeOwned>(
+    config: &Value,
+    key: &str,
+    errors: &mut Vec<ConfigurationFieldError>,
+) -> Option<T> {
+    let value = config.get(key)?;
+    serde_json::from_value(value.clone())
+        .map_err(|e| {
+            errors.push(ConfigurationFieldError {
+                field: key.to_string(),
+                message: e.to_string(),
+            })
+        })
+        .ok()
+}
+
+/**
+ * @brief Checks that every entry of a list field of the configuration has the expected type.
+ *
+ * @param config Configuration as JSON.
+ * @param key Name of the field; a missing field is an empty list.
+ * @param errors Collected errors, located at the offending entries.
+ * @return Option<Vec<T>> The typed entries, if all of them are valid.
+ */
+fn check_list<T: DeserializeOwned>(
+    config: &Value,
+    key: &str,
+    errors: &mut Vec<ConfigurationFieldError>,
+) -> Option<Vec<T>> {
+    let Some(Value::Array(entries)) = config.get(key) else {
+        return check_field(config, key, errors).or(Some(Vec::new()));
+    };
+    let errors_before = errors.len();
+    let typed: Vec<T> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(i, entry)| {
+            serde_json::from_value(entry.clone())
+                .map_err(|e| {
+                    errors.push(ConfigurationFieldError {
+                        field: format!("{}[{}]", key, i),
+                        message: e.to_string(),
+                    })
+                })
+                .ok()
+        })
+        .collect();
+    (errors.len() == errors_before).then_some(typed)
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Applies a partial update to a pricing configuration and validates the result.
+ *
+ * The update is a JSON merge patch of the configuration as returned by the pricing API, so
+ * lists such as `materials` are replaced as a whole.
+ *
+ * @param current Configuration orders are currently priced with.
+ * @param update Changed fields of the configuration.
+ * @param profile_dir Directory the filament profiles of the selected slicer are stored in.
+ * @param require_filament_profiles Whether every material must name a filament profile.
+ *        Materials converted from the rates of an older file are exempt until they are
+ *        replaced.
+ * @return Result<PrinterConfiguration, Vec<ConfigurationFieldError>> The updated
+ *         configuration, or every problem found in it.
+ */
+pub fn apply_pricing_update(
+    current: &PrinterConfiguration,
+    update: &Value,
+    profile_dir: &Path,
+    require_filament_profiles: bool,
+) -> Result<PrinterConfiguration, Vec<ConfigurationFieldError>> {
+    let Value::Object(fields) = update else {
+        return Err(vec![ConfigurationFieldError {
+            field: String::new(),
+            message: "The update must be a JSON object".to_string(),
+        }]);
+    };
+    let mut errors: Vec<ConfigurationFieldError> = fields
+        .keys()
+        .filter(|key| !PRICING_KEYS.contains(&key.as_str()))
+        .map(|key| ConfigurationFieldError {
+            field: key.clone(),
+            message: "Not a pricing parameter".to_string(),
+        })
+        .collect();
+
+    let legacy_catalogue = current.legacy_catalogue && !fields.contains_key("materials");
+    let mut config =
+        serde_json::to_value(current).expect("Pricing configurations are serializable");
+    merge_patch(&mut config, update);
+
+    let build_volume = check_field::<[u32; 3]>(&config, "build_volume", &mut errors);
+    check_field::<HourlyBilling>(&config, "hourly_billing", &mut errors);
+    check_field::<RoundingPolicy>(&config, "price_rounding", &mut errors);
+    let materials = check_list::<Material>(&config, "materials", &mut errors);
+    let adjustments =
+        check_list::<PrintTypeAdjustment>(&config, "print_type_adjustments", &mut errors);
+    let discounts = check_list::<QuantityDiscount>(&config, "quantity_discounts", &mut errors);
+    let order_fee = check_field::<Money>(&config, "order_fee", &mut errors);
+    let minimum_order_value = check_field::<Money>(&config, "minimum_order_value", &mut errors);
+
+    if let Some(materials) = materials {
+        errors.extend(
+            check_material_catalogue(
+                &materials,
+                profile_dir,
+                require_filament_profiles && !legacy_catalogue,
+            )
+            .err(),
+        );
+    }
+    if let Some(adjustments) = adjustments {
+        errors.extend(validate_print_type_adjustments(&adjustments).err());
+    }
+    if let Some(build_volume) = build_volume {
+        errors.extend(validate_build_volume(&build_volume).err());
+    }
+    // Amounts that are missing or already reported as invalid are checked with defaults, and
+    // so are discounts that did not parse
+    if let Err(pricing_errors) = validate_order_pricing(
+        order_fee.unwrap_or_else(default_order_fee),
+        minimum_order_value.unwrap_or_default(),
+        discounts.as_deref().unwrap_or_default(),
+    ) {
+        errors.extend(pricing_errors);
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    serde_json::from_value(config)
+        .map(|config| PrinterConfiguration {
+            legacy_catalogue,
+            ..config
+        })
+        .map_err(|e| {
+            vec![ConfigurationFieldError {
+                field: String::new(),
+                message: e.to_string(),
+            }]
+        })
+}
+
+/**
+ * @brief Replaces the pricing fields of a configuration file, keeping all other fields.
+ *
+ * Fixed PLA, PET and ASA rates of older files are dropped, as the catalogue written in their
+ * place takes precedence over them. While the catalogue is still the one converted from those
+ * rates, the rates are kept instead, so the file keeps loading without filament profiles.
+ *
+ * @param file Current content of the configuration file.
+ * @param config Configuration to write into the file.
+ * @return Value The new content of the configuration file.
+ */
+pub fn pricing_file_content(file: &Value, config: &PrinterConfiguration) -> Value {
+    let mut content = match file {
+        Value::Object(fields) => fields.clone(),
+        _ => Map::new(),
+    };
+    let replaced_keys: &[&str] = if config.legacy_catalogue {
+        &[]
+    } else {
+        &LEGACY_KEYS
+    };
+    for key in replaced_keys.iter().chain(PRICING_KEYS.iter()) {
+        content.remove(*key);
+    }
+    if let Ok(Value::Object(mut pricing)) = serde_json::to_value(config) {
+        if config.legacy_catalogue {
+            pricing.remove("materials");
+        }
+        content.extend(pricing);
+    }
+    Value::Object(content)
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn current_config() -> PrinterConfiguration {
+        serde_json::from_value(json!({
+            "materials": [{"name": "PLA", "density_g_cm3": 1.24, "price": {"per_metre": 0.6},
+                           "hourly_rates": [{"from_hours": 0, "rate": 30}]}],
+            "order_fee": "2.00"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_partial_update_keeps_the_other_parameters() {
+        let dir = Path::new(".");
+        let updated = apply_pricing_update(
+            &current_config(),
+            &json!({"minimum_order_value": "25.00", "hourly_billing": "marginal"}),
+            dir,
+            false,
+        )
+        .unwrap();
+        assert_eq!(updated.minimum_order_value, Money::from_pln(25));
+        assert_eq!(updated.hourly_billing, HourlyBilling::Marginal);
+        assert_eq!(updated.order_fee, Money::from_pln(2));
+        assert_eq!(updated.materials, current_config().materials);
+
+        // Removing a field restores its default
+        let updated =
+            apply_pricing_update(&current_config(), &json!({"order_fee": null}), dir, false);
+        assert_eq!(updated.unwrap().order_fee, default_order_fee());
+    }
+
+    #[test]
+    fn test_invalid_update_reports_every_field() {
+        let errors = apply_pricing_update(
+            &current_config(),
+            &json!({
+                "prusa_path": "/bin/sh",
+                "order_fee": "-1.00",
+                "materials": [{"name": "PLA", "density_g_cm3": 0, "price": {"per_metre": 0.6},
+                               "hourly_rates": [{"from_hours": 0, "rate": 30}]},
+                              {"name": "PET"}],
+                "quantity_discounts": [{"from_copies": 10, "percent": 120}]
+            }),
+            Path::new("."),
+            false,
+        )
+        .err()
+        .unwrap();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "prusa_path",
+                "materials[1]",
+                "order_fee",
+                "quantity_discounts[0].percent"
+            ]
+        );
+
+        let errors = apply_pricing_update(
+            &current_config(),
+            &json!({"order_fee": "-1.00", "build_volume": [250, 0, 210],
+                    "quantity_discounts": [{"from_copies": 10, "percent": 120},
+                                           {"from_copies": 5, "percent": 10}]}),
+            Path::new("."),
+            false,
+        )
+        .err()
+        .unwrap();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "build_volume[1]",
+                "order_fee",
+                "quantity_discounts[0].percent",
+                "quantity_discounts[1].from_copies"
+            ]
+        );
+
+        let errors = apply_pricing_update(
+            &current_config(),
+            &json!({"quantity_discounts": [{"from_copies": 10, "percent": 120}],
+                    "materials": [{"name": "PLA", "density_g_cm3": 0, "price": {"per_kg": 90},
+                                   "hourly_rates": [{"from_hours": 0, "rate": 30}]}]}),
+            Path::new("."),
+            false,
+        )
+        .err()
+        .unwrap();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "materials[0].density_g_cm3",
+                "quantity_discounts[0].percent"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_file_content_keeps_other_fields_and_drops_legacy_rates() {
+        let file = json!({"prusa_path": "/usr/bin/prusa-slicer", "material_rate_pla": 60,
+                          "order_fee": "1.00"});
+        let content = pricing_file_content(&file, &current_config());
+        assert_eq!(content["prusa_path"], "/usr/bin/prusa-slicer");
+        assert_eq!(content["order_fee"], "2.00");
+        assert!(content.get("material_rate_pla").is_none());
+        assert_eq!(content["materials"][0]["name"], "PLA");
+    }
+
+    #[test]
+    fn test_legacy_catalogue_needs_no_filament_profiles_until_replaced() {
+        let file = json!({"material_rate_pla": 60, "material_rate_pet": 80,
+                          "material_rate_asa": 100, "hourly_rate_time_threshold": [0],
+                          "hourly_rate_pla_price": [30], "hourly_rate_pet_price": [35],
+                          "hourly_rate_asa_price": [40]});
+        let legacy: PrinterConfiguration = serde_json::from_value(file.clone()).unwrap();
+        assert!(legacy.legacy_catalogue);
+
+        let updated =
+            apply_pricing_update(&legacy, &json!({"order_fee": "3.00"}), Path::new("."), true)
+                .unwrap();
+        assert!(updated.legacy_catalogue);
+        // The rewritten file still holds the rates, so it loads the same catalogue again
+        let content = pricing_file_content(&file, &updated);
+        assert_eq!(content["material_rate_pla"], 60);
+        assert!(content.get("materials").is_none());
+        let reloaded: PrinterConfiguration = serde_json::from_value(content).unwrap();
+        assert_eq!(reloaded.materials, legacy.materials);
+        assert_eq!(reloaded.order_fee, Money::from_pln(3));
+
+        let materials = serde_json::to_value(&legacy.materials).unwrap();
+        let errors = apply_pricing_update(
+            &legacy,
+            &json!({ "materials": materials }),
+            Path::new("."),
+            true,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(errors[0].field, "materials[0].filament_profile");
+    }
+}
@@ -0,0 +1,236 @@
+/* IMPORTS FROM LIBRARIES */
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::PrinterConfiguration;
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Fields identifying the entries of configuration lists, so entries are compared by identity
+/// rather than by position.
+const LIST_ENTRY_KEYS: [&str; 3] = ["name", "print_type", "from_copies"];
+
+/* PUBLIC TYPES AND VARIABLES */
+/// A pricing configuration as stored in the database; stored versions are never modified.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PricingVersion {
+    pub id: i64,
+    /// SHA-256 of the canonical JSON of the configuration, as lowercase hex.
+    pub hash: String,
+    /// When the configuration was first loaded.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// The configuration in the layout of the configuration file.
+    pub configuration: Value,
+}
+
+/// A value that differs between two pricing configurations.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ConfigurationChange {
+    /// Location of the value, e.g. `materials[PLA].price.per_metre`.
+    pub path: String,
+    /// The value in the older configuration, missing if it was added.
+    pub old: Option<Value>,
+    /// The value in the newer configuration, missing if it was removed.
+    pub new: Option<Value>,
+}
+
+/* PRIVATE FUNCTIONS */
+/**
+ * @brief Finds the field identifying every entry of a list, if all entries have one.
+ *
+ * @param entries Entries of the list.
+ * @return Option<&str> Name of the identifying field.
+ */
+fn list_entry_key(entries: &[Value]) -> Option<&'static str> {
+    LIST_ENTRY_KEYS
+        .into_iter()
+        .find(|key| !entries.is_empty() && entries.iter().all(|entry| entry.get(key).is_some()))
+}
+
+/**
+ * @brief Gives every entry of a list the label it is reported under in a path.
+ *
+ * @param entries Entries of the list.
+ * @param key Field identifying the entries, or None to label them by position.
+ * @return Vec<(String, &Value)> Label and value of every entry.
+ */
+fn label_list_entries<'a>(entries: &'a [Value], key: Option<&str>) -> Vec<(String, &'a Value)> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let label = match key.and_then(|key| entry.get(key)) {
+                Some(Value::String(id)) => id.clone(),
+                Some(id) => id.to_string(),
+                None => i.to_string(),
+            };
+            (label, entry)
+        })
+        .collect()
+}
+
+/// Looks up a labelled list entry, see `label_list_entries`.
+fn find_list_entry<'a>(entries: &[(String, &'a Value)], label: &str) -> Option<&'a Value> {
+    entries
+        .iter()
+        .find(|(entry_label, _)| entry_label == label)
+        .map(|(_, entry)| *entry)
+}
+
+/**
+ * @brief Compares two JSON values and collects the paths of the leaves that differ.
+ *
+ * @param path Location of the compared values.
+ * @param old Value in the older configuration.
+ * @param new Value in the newer configuration.
+ * @param changes Collected differences.
+ */
+fn diff_values(
+    path: &str,
+    old: Option<&Value>,
+    new: Option<&Value>,
+    changes: &mut Vec<ConfigurationChange>,
+) {
+    match (old, new) {
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                diff_values(&child_path, old.get(key), new.get(key), changes);
+            }
+        }
+        (Some(Value::Array(old)), Some(Value::Array(new))) => {
+            let key = list_entry_key(old).filter(|key| list_entry_key(new) == Some(*key));
+            let old_entries = label_list_entries(old, key);
+            let new_entries = label_list_entries(new, key);
+            let mut labels: Vec<&String> = old_entries.iter().map(|(label, _)| label).collect();
+            for (label, _) in &new_entries {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+            for label in labels {
+                diff_values(
+                    &format!("{}[{}]", path, label),
+                    find_list_entry(&old_entries, label),
+                    find_list_entry(&new_entries, label),
+                    changes,
+                );
+            }
+        }
+        (old, new) if old != new => changes.push(ConfigurationChange {
+            path: path.to_string(),
+            old: old.cloned(),
+            new: new.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Serializes a pricing configuration into the form it is versioned in.
+ *
+ * Object keys are sorted, so the same configuration always gives the same JSON regardless of
+ * how its file was laid out.
+ *
+ * @param configuration Configuration to serialize.
+ * @return (String, String) The canonical JSON and its SHA-256 as lowercase hex.
+ */
+pub fn canonical_configuration(configuration: &PrinterConfiguration) -> (String, String) {
+    let value =
+        serde_json::to_value(configuration).expect("Pricing configurations are serializable");
+    let json = value.to_string();
+    let hash = Sha256::digest(json.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    (json, hash)
+}
+
+/**
+ * @brief Lists the values that differ between two pricing configurations.
+ *
+ * Materials, print type adjustments and quantity discounts are matched by their name, print
+ * type and number of copies, so reordering them is not reported as a change.
+ *
+ * @param old The older configuration.
+ * @param new The newer configuration.
+ * @return Vec<ConfigurationChange> Every added, removed or modified value.
+ */
+pub fn diff_configurations(old: &Value, new: &Value) -> Vec<ConfigurationChange> {
+    let mut changes = Vec::new();
+    diff_values("", Some(old), Some(new), &mut changes);
+    changes
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_equal_configurations_have_the_same_hash() {
+        let config = |json: &str| serde_json::from_str::<PrinterConfiguration>(json).unwrap();
+        let material = r#"{"name": "PLA", "density_g_cm3": 1.24, "price": {"per_metre": 0.6},
+                           "hourly_rates": [{"from_hours": 0, "rate": 30}]}"#;
+        let (json, hash) =
+            canonical_configuration(&config(&format!(r#"{{"materials": [{}]}}"#, material)));
+        let (reordered_json, reordered_hash) = canonical_configuration(&config(&format!(
+            r#"{{"order_fee": "1.00", "materials": [{}]}}"#,
+            material
+        )));
+        assert_eq!(json, reordered_json);
+        assert_eq!(hash, reordered_hash);
+        assert_eq!(hash.len(), 64);
+
+        let (_, other_hash) = canonical_configuration(&config(
+            &format!(r#"{{"materials": [{}]}}"#, material).replace("30", "31"),
+        ));
+        assert_ne!(hash, other_hash);
+    }
+
+    #[test]
+    fn test_diff_matches_materials_by_name() {
+        let old = json!({
+            "order_fee": "1.00",
+            "materials": [
+                {"name": "PLA", "price": {"per_metre": "0.60"}},
+                {"name": "PET", "price": {"per_metre": "0.80"}}
+            ]
+        });
+        let new = json!({
+            "order_fee": "1.00",
+            "materials": [
+                {"name": "PET", "price": {"per_metre": "0.80"}},
+                {"name": "PLA", "price": {"per_metre": "0.65"}},
+                {"name": "ASA", "price": {"per_metre": "1.00"}}
+            ]
+        });
+        let changes = diff_configurations(&old, &new);
+        assert_eq!(
+            changes,
+            vec![
+                ConfigurationChange {
+                    path: "materials[PLA].price.per_metre".to_string(),
+                    old: Some(json!("0.60")),
+                    new: Some(json!("0.65")),
+                },
+                ConfigurationChange {
+                    path: "materials[ASA]".to_string(),
+                    old: None,
+                    new: Some(json!({"name": "ASA", "price": {"per_metre": "1.00"}})),
+                },
+            ]
+        );
+        assert!(diff_configurations(&old, &old).is_empty());
+    }
+}
@@ -6,6 +6,7 @@ use std::sync::Mutex;
 /* IMPORTS FROM OTHER MODULES */
 use crate::common_utils::global_traits::DatabaseInterfaceImpl;
 use crate::common_utils::global_types::EvaluationResult;
+use crate::common_utils::pricing_versions::PricingVersion;
 use crate::database_handler::database_sqlite_impl::DatabaseSQLiteImpl;
 
 /* PRIVATE TYPES AND VARIABLES */
@@ -53,42 +54,96 @@ pub fn initialize_db(db_name: &str) {
  */
 pub fn read_orders_from_db() -> Result<Vec<EvaluationResult>> {
     let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
-    return database_handler_impl.read_orders_from_db();
+    database_handler_impl.read_orders_from_db()
 }
 
 pub fn read_completed_orders_from_db() -> Result<Vec<EvaluationResult>> {
     let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
-    return database_handler_impl.read_completed_orders_from_db();
+    database_handler_impl.read_completed_orders_from_db()
+}
+
+/**
+ * @brief Reads one order, new or completed.
+ *
+ * @param order_id ID of the order.
+ * @return Result<Option<EvaluationResult>> The order, or None if no order has the ID.
+ */
+pub fn read_order_from_db(order_id: i64) -> Result<Option<EvaluationResult>> {
+    let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
+    database_handler_impl.read_order_from_db(order_id)
 }
 
 /**
  * @brief Adds an evaluation result to the database.
  *
  * This function stores the evaluation result of an order in the database.
- * Currently, it is a placeholder.
  *
- * @param _slicer_evaluation_result Evaluation result to be added.
+ * @param slicer_evaluation_result Evaluation result to be added.
+ * @return Result<i64> The ID assigned to the stored order.
+ */
+pub fn add_evaluation_to_db(slicer_evaluation_result: &EvaluationResult) -> Result<i64> {
+    let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
+    database_handler_impl.add_evaluation_to_db(slicer_evaluation_result)
+}
+
+/**
+ * @brief Stores a pricing configuration as an immutable version.
+ *
+ * @param hash Hash of the canonical JSON of the configuration.
+ * @param configuration Canonical JSON of the configuration.
+ * @return Result<i64> ID of the version, the existing one if the configuration was stored before.
  */
-pub fn add_evaluation_to_db(slicer_evaluation_result: &EvaluationResult) -> Result<()> {
+pub fn add_pricing_version_to_db(hash: &str, configuration: &str) -> Result<i64> {
     let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
-    return database_handler_impl.add_evaluation_to_db(slicer_evaluation_result);
+    database_handler_impl.add_pricing_version_to_db(hash, configuration)
 }
 
-pub fn modify_new_order_in_database(datetime: &str, new_status: &str) -> Result<()> {
+/**
+ * @brief Reads the stored pricing configuration versions.
+ *
+ * @return Result<Vec<PricingVersion>> All versions, oldest first.
+ */
+pub fn read_pricing_versions_from_db() -> Result<Vec<PricingVersion>> {
+    let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
+    database_handler_impl.read_pricing_versions_from_db(None)
+}
+
+/**
+ * @brief Reads one stored pricing configuration version.
+ *
+ * @param version_id ID of the version.
+ * @return Result<Option<PricingVersion>> The version, or None if no version has the ID.
+ */
+pub fn read_pricing_version_from_db(version_id: i64) -> Result<Option<PricingVersion>> {
+    let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
+    Ok(database_handler_impl
+        .read_pricing_versions_from_db(Some(version_id))?
+        .pop())
+}
+
+/**
+ * @brief Changes the status of an order stored in the `Orders` table.
+ *
+ * @param order_id ID of the order, as returned by `add_evaluation_to_db`.
+ * @param new_status New status of the order.
+ * @return Result<()> An error of kind `NotFound` if no order has the given ID.
+ */
+pub fn modify_new_order_in_database(order_id: i64, new_status: &str) -> Result<()> {
     let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
-    return database_handler_impl.modify_order_in_database("Orders", datetime, new_status);
+    database_handler_impl.modify_order_in_database("Orders", order_id, new_status)
 }
 
-pub fn modify_completed_order_in_database(datetime: &str, new_status: &str) -> Result<()> {
+pub fn modify_completed_order_in_database(order_id: i64, new_status: &str) -> Result<()> {
     let database_handler_impl = DB_HANDLER_STATE.db_impl.lock().unwrap();
-    return database_handler_impl.modify_order_in_database("CompletedOrders", datetime, new_status);
+    database_handler_impl.modify_order_in_database("CompletedOrders", order_id, new_status)
 }
 
 /* TESTS */
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common_utils::global_types::{PrintMaterialType, PrintType, StatusType};
+    use crate::common_utils::global_types::{PrintType, StatusType};
+    use crate::common_utils::money::Money;
     use crate::database_handler::database_mock::DatabaseMockImpl;
 
     /// Helper function to reset the global state
@@ -107,18 +162,23 @@ mod tests {
     fn test_add_evaluation_to_db() {
         reset_state_and_setup_mocked_interface();
         let evaluation = EvaluationResult {
+            id: None,
             date: chrono::Utc::now(),
             name: "John Doe".to_string(),
             email: "john.doe@example.com".to_string(),
             copies_nbr: 1,
             file_name: "file.stl".to_string(),
-            price: 100.0,
-            material_type: PrintMaterialType::PLA,
+            price: Money::from_pln(100),
+            material_type: "PLA".to_string(),
             status: StatusType::New,
             print_type: PrintType::ThickStrong,
+            model_key: "model.stl".to_string(),
+            model_statistics: None,
+            price_breakdown: None,
+            print_parameters: None,
+            pricing_version: None,
         };
         let result = add_evaluation_to_db(&evaluation);
-        assert!(result.is_ok());
-        // No assertion as the mock does not return a value, just ensure no panic
+        assert_eq!(result.unwrap(), 1);
     }
 }
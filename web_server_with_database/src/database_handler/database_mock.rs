@@ -4,6 +4,7 @@ use std::io;
 /* IMPORTS FROM OTHER MODULES */
 use crate::common_utils::global_traits::DatabaseInterfaceImpl;
 use crate::common_utils::global_types::EvaluationResult;
+use crate::common_utils::pricing_versions::PricingVersion;
 
 /* PRIVATE TYPES AND VARIABLES */
 /* PUBLIC TYPES AND VARIABLES */
@@ -14,22 +15,33 @@ pub struct DatabaseMockImpl {}
 /* PUBLIC FUNCTIONS */
 impl DatabaseInterfaceImpl for DatabaseMockImpl {
     fn initialize_db(&self, _db_name: &str) -> io::Result<()> {
-        return Ok(());
+        Ok(())
     }
 
     fn read_orders_from_db(&self) -> io::Result<Vec<EvaluationResult>> {
         let orders = Vec::new();
-        return Ok(orders);
+        Ok(orders)
     }
 
-    fn add_evaluation_to_db(&self, _eval_result: &EvaluationResult) -> io::Result<()> {
-        Ok(())
+    fn add_evaluation_to_db(&self, _eval_result: &EvaluationResult) -> io::Result<i64> {
+        Ok(1)
+    }
+
+    fn add_pricing_version_to_db(&self, _hash: &str, _configuration: &str) -> io::Result<i64> {
+        Ok(1)
+    }
+
+    fn read_pricing_versions_from_db(
+        &self,
+        _version_id: Option<i64>,
+    ) -> io::Result<Vec<PricingVersion>> {
+        Ok(Vec::new())
     }
 
     fn modify_order_in_database(
         &self,
         _table_name: &str,
-        _order_id: &str,
+        _order_id: i64,
         _new_status: &str,
     ) -> io::Result<()> {
         Ok(())
@@ -37,6 +49,10 @@ impl DatabaseInterfaceImpl for DatabaseMockImpl {
 
     fn read_completed_orders_from_db(&self) -> io::Result<Vec<EvaluationResult>> {
         let orders = Vec::new();
-        return Ok(orders);
+        Ok(orders)
+    }
+
+    fn read_order_from_db(&self, _order_id: i64) -> io::Result<Option<EvaluationResult>> {
+        Ok(None)
     }
 }
@@ -1,41 +1,266 @@
 /* IMPORTS FROM LIBRARIES */
 use rusqlite::Connection;
+use serde::{de::DeserializeOwned, Serialize};
 use std::io;
 use std::sync::{Arc, Mutex};
 
 /* IMPORTS FROM OTHER MODULES */
 use crate::common_utils::global_traits::DatabaseInterfaceImpl;
 use crate::common_utils::global_types::{EvaluationResult, StatusType};
+use crate::common_utils::money::Money;
+use crate::common_utils::pricing_versions::PricingVersion;
 use crate::database_handler::database_type_conversions::{
-    chrono_to_datetime, datetime_to_chrono, str_to_print_material_type, str_to_print_type,
-    str_to_status_type,
+    chrono_to_datetime, datetime_to_chrono, str_to_print_type, str_to_status_type,
 };
 
 /* PRIVATE TYPES AND VARIABLES */
+/// Columns shared by the `Orders` and `CompletedOrders` tables, in storage order.
+const ORDER_COLUMNS: &str =
+    "id, date, name, email, copies_nbr, file_name, price_grosz, material_type, print_type, status, model_key, model_statistics, price_breakdown, print_parameters, pricing_version";
+
 /* PUBLIC TYPES AND VARIABLES */
 pub struct DatabaseSQLiteImpl {
     pub db_conn: Arc<Mutex<Option<Connection>>>,
 }
 
 /* PRIVATE FUNCTIONS */
+/**
+ * @brief Creates an orders table with a surrogate integer primary key.
+ *
+ * Only the `Orders` table allocates new IDs (`AUTOINCREMENT` guarantees that IDs are never
+ * reused). `CompletedOrders` keeps the ID the order received when it was first stored, so an
+ * order can be moved between the tables without changing its identity.
+ *
+ * @param conn Database connection.
+ * @param table_name Name of the table to create.
+ * @param allocates_ids Whether the table hands out new IDs.
+ */
+fn create_orders_table(conn: &Connection, table_name: &str, allocates_ids: bool) -> io::Result<()> {
+    let id_column = if allocates_ids {
+        "id integer primary key autoincrement"
+    } else {
+        "id integer primary key"
+    };
+    let sql = format!(
+        "CREATE TABLE IF NOT EXISTS {} (
+            {},
+            date datetime not null,
+            name text not null,
+            email text not null,
+            copies_nbr integer not null,
+            file_name text not null,
+            price_grosz integer not null,
+            material_type text not null,
+            print_type text not null,
+            status text not null,
+            model_key text not null default '',
+            model_statistics text,
+            price_breakdown text,
+            print_parameters text,
+            pricing_version integer
+        )",
+        table_name, id_column
+    );
+    conn.execute(&sql, [])
+        .map_err(|e| io::Error::other(format!("Failed to create {} table: {}", table_name, e)))?;
+    Ok(())
+}
+
+/**
+ * @brief Creates the table of pricing configuration versions.
+ *
+ * Every configuration the server loaded is stored once, identified by the hash of its canonical
+ * JSON. Rows are only ever inserted, so orders can always be traced back to their rates.
+ *
+ * @param conn Database connection.
+ */
+fn create_pricing_versions_table(conn: &Connection) -> io::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS PricingVersions (
+            id integer primary key autoincrement,
+            hash text not null unique,
+            created_at datetime not null,
+            configuration text not null
+        )",
+        [],
+    )
+    .map_err(|e| io::Error::other(format!("Failed to create PricingVersions table: {}", e)))?;
+    Ok(())
+}
+
+fn table_has_column(
+    conn: &Connection,
+    table_name: &str,
+    column_name: &str,
+) -> rusqlite::Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table_name))?;
+    let column_names = stmt.query_map([], |row| row.get::<_, String>(1))?;
+    for existing_column_name in column_names {
+        if existing_column_name? == column_name {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/**
+ * @brief Adds a column introduced after the table was first created.
+ *
+ * @param conn Database connection.
+ * @param table_name Name of the table to extend.
+ * @param column_name Name of the column.
+ * @param column_definition SQL type and constraints of the column; must provide a default.
+ */
+fn add_missing_column(
+    conn: &Connection,
+    table_name: &str,
+    column_name: &str,
+    column_definition: &str,
+) -> rusqlite::Result<()> {
+    if !table_has_column(conn, table_name, column_name)? {
+        conn.execute_batch(&format!(
+            "ALTER TABLE {} ADD COLUMN {} {}",
+            table_name, column_name, column_definition
+        ))?;
+    }
+    Ok(())
+}
+
+/**
+ * @brief Replaces the `price` column holding PLN as REAL with `price_grosz` holding grosz.
+ *
+ * @param conn Database connection.
+ * @param table_name Name of the table to convert.
+ */
+fn convert_real_prices_to_grosz(conn: &Connection, table_name: &str) -> rusqlite::Result<()> {
+    if !table_has_column(conn, table_name, "price")? {
+        return Ok(());
+    }
+    conn.execute_batch(&format!(
+        "ALTER TABLE {0} ADD COLUMN price_grosz integer not null default 0;
+         UPDATE {0} SET price_grosz = CAST(ROUND(price * 100) AS INTEGER);
+         ALTER TABLE {0} DROP COLUMN price;",
+        table_name
+    ))
+}
+
+/**
+ * @brief Migrates databases created by older versions of the application.
+ *
+ * Tables created before orders had an `id` column are rebuilt with the new layout. `Orders`
+ * rows get fresh IDs in date order, `CompletedOrders` rows continue numbering after them, and
+ * the `Orders` ID sequence is bumped past every assigned ID so that new orders never collide
+ * with archived ones. Columns added later are appended with their default values, and prices
+ * stored as REAL are converted to whole grosz. All changes are applied in one transaction.
+ */
+fn migrate_legacy_orders_tables(conn: &mut Connection) -> io::Result<()> {
+    let sql_err = |e: rusqlite::Error| io::Error::other(format!("Migration failed: {}", e));
+    let orders_needs_migration = !table_has_column(conn, "Orders", "id").map_err(sql_err)?;
+    let completed_needs_migration =
+        !table_has_column(conn, "CompletedOrders", "id").map_err(sql_err)?;
+    let tx = conn.transaction().map_err(sql_err)?;
+    if !orders_needs_migration && !completed_needs_migration {
+        upgrade_tables_to_current_layout(&tx).map_err(sql_err)?;
+        return tx.commit().map_err(sql_err);
+    }
+
+    let legacy_columns =
+        "date, name, email, copies_nbr, file_name, price_grosz, material_type, print_type, status";
+    let legacy_values = "date, name, email, copies_nbr, file_name, CAST(ROUND(price * 100) AS INTEGER), material_type, print_type, status";
+    if orders_needs_migration {
+        tx.execute_batch("ALTER TABLE Orders RENAME TO Orders_legacy")
+            .map_err(sql_err)?;
+        create_orders_table(&tx, "Orders", true)?;
+        tx.execute_batch(&format!(
+            "INSERT INTO Orders ({0}) SELECT {1} FROM Orders_legacy ORDER BY date;
+             DROP TABLE Orders_legacy;",
+            legacy_columns, legacy_values
+        ))
+        .map_err(sql_err)?;
+    }
+    if completed_needs_migration {
+        tx.execute_batch("ALTER TABLE CompletedOrders RENAME TO CompletedOrders_legacy")
+            .map_err(sql_err)?;
+        create_orders_table(&tx, "CompletedOrders", false)?;
+        tx.execute_batch(&format!(
+            "INSERT INTO CompletedOrders (id, {0})
+                SELECT (SELECT IFNULL(MAX(id), 0) FROM Orders) + ROW_NUMBER() OVER (ORDER BY date), {1}
+                FROM CompletedOrders_legacy;
+             DROP TABLE CompletedOrders_legacy;",
+            legacy_columns, legacy_values
+        ))
+        .map_err(sql_err)?;
+    }
+    tx.execute_batch(
+        "DELETE FROM sqlite_sequence WHERE name = 'Orders';
+         INSERT INTO sqlite_sequence (name, seq) SELECT 'Orders', max_id FROM (
+            SELECT MAX(id) AS max_id FROM (
+                SELECT id FROM Orders UNION ALL SELECT id FROM CompletedOrders
+            )
+         ) WHERE max_id IS NOT NULL;",
+    )
+    .map_err(sql_err)?;
+    upgrade_tables_to_current_layout(&tx).map_err(sql_err)?;
+    tx.commit().map_err(sql_err)
+}
+
+fn upgrade_tables_to_current_layout(conn: &Connection) -> rusqlite::Result<()> {
+    for table_name in ["Orders", "CompletedOrders"] {
+        convert_real_prices_to_grosz(conn, table_name)?;
+        add_missing_column(conn, table_name, "model_key", "text not null default ''")?;
+        add_missing_column(conn, table_name, "model_statistics", "text")?;
+        add_missing_column(conn, table_name, "price_breakdown", "text")?;
+        add_missing_column(conn, table_name, "print_parameters", "text")?;
+        add_missing_column(conn, table_name, "pricing_version", "integer")?;
+    }
+    Ok(())
+}
+
+/// Reads a column holding an optional value stored as JSON.
+fn read_json_column<T: DeserializeOwned>(
+    row: &rusqlite::Row,
+    index: usize,
+) -> rusqlite::Result<Option<T>> {
+    let json: Option<String> = row.get(index)?;
+    json.map(|json| serde_json::from_str(&json))
+        .transpose()
+        .map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(
+                index,
+                rusqlite::types::Type::Text,
+                Box::new(e),
+            )
+        })
+}
+
+/// Serializes an optional value into the JSON stored in its column.
+fn to_json_column<T: Serialize>(value: &Option<T>) -> io::Result<Option<String>> {
+    value
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(io::Error::other)
+}
+
 fn read_orders_from_table(
     conn: &Connection,
     table_name: &str,
+    order_id: Option<i64>,
 ) -> io::Result<Vec<EvaluationResult>> {
-    let query = format!("SELECT date, name, email, copies_nbr, file_name, price, material_type, print_type, status FROM {} ORDER BY date DESC", table_name);
-    let mut stmt = conn.prepare(&query).map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!("Failed to prepare statement: {}", e),
-        )
-    })?;
+    let query = format!(
+        "SELECT {} FROM {} WHERE ?1 IS NULL OR id = ?1 ORDER BY date DESC, id DESC",
+        ORDER_COLUMNS, table_name
+    );
+    let mut stmt = conn
+        .prepare(&query)
+        .map_err(|e| io::Error::other(format!("Failed to prepare statement: {}", e)))?;
     let order_iter = stmt
-        .query_map([], |row| {
-            let date_str: String = row.get(0)?;
+        .query_map([order_id], |row| {
+            let date_str: String = row.get(1)?;
             let date = datetime_to_chrono(&date_str);
             if date.is_err() {
                 return Err(rusqlite::Error::FromSqlConversionFailure(
-                    0,
+                    1,
                     rusqlite::types::Type::Text,
                     Box::new(std::io::Error::new(
                         std::io::ErrorKind::InvalidData,
@@ -43,19 +268,7 @@ fn read_orders_from_table(
                     )),
                 ));
             };
-            let material_type_str: String = row.get(6)?;
-            let material_type = str_to_print_material_type(&material_type_str);
-            if material_type.is_err() {
-                return Err(rusqlite::Error::FromSqlConversionFailure(
-                    6,
-                    rusqlite::types::Type::Text,
-                    Box::new(std::io::Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        "Unknown material type",
-                    )),
-                ));
-            };
-            let print_type_str: String = row.get(7)?;
+            let print_type_str: String = row.get(8)?;
             let print_type = str_to_print_type(&print_type_str);
             if print_type.is_err() {
                 return Err(rusqlite::Error::FromSqlConversionFailure(
@@ -67,11 +280,11 @@ fn read_orders_from_table(
                     )),
                 ));
             };
-            let status_str: String = row.get(8)?;
+            let status_str: String = row.get(9)?;
             let status = str_to_status_type(&status_str);
             if status.is_err() {
                 return Err(rusqlite::Error::FromSqlConversionFailure(
-                    7,
+                    9,
                     rusqlite::types::Type::Text,
                     Box::new(std::io::Error::new(
                         std::io::ErrorKind::InvalidData,
@@ -80,80 +293,135 @@ fn read_orders_from_table(
                 ));
             };
             Ok(EvaluationResult {
+                id: Some(row.get(0)?),
                 date: date.unwrap(),
-                name: row.get(1)?,
-                email: row.get(2)?,
-                copies_nbr: row.get(3)?,
-                file_name: row.get(4)?,
-                price: row.get(5)?,
-                material_type: material_type.unwrap(),
+                name: row.get(2)?,
+                email: row.get(3)?,
+                copies_nbr: row.get(4)?,
+                file_name: row.get(5)?,
+                price: Money::from_grosz(row.get(6)?),
+                // Materials removed from the catalogue since stay readable
+                material_type: row.get(7)?,
                 print_type: print_type.unwrap(),
                 status: status.unwrap(),
+                model_key: row.get(10)?,
+                model_statistics: read_json_column(row, 11)?,
+                price_breakdown: read_json_column(row, 12)?,
+                print_parameters: read_json_column(row, 13)?,
+                pricing_version: row.get(14)?,
             })
         })
-        .map_err(|e| {
-            io::Error::new(io::ErrorKind::Other, format!("Failed to query rows: {}", e))
-        })?;
+        .map_err(|e| io::Error::other(format!("Failed to query rows: {}", e)))?;
 
     let mut orders = Vec::new();
     for order in order_iter {
-        orders.push(order.map_err(|e| {
-            io::Error::new(io::ErrorKind::Other, format!("Failed to map row: {}", e))
-        })?);
+        orders.push(order.map_err(|e| io::Error::other(format!("Failed to map row: {}", e)))?);
     }
     Ok(orders)
 }
 
-fn write_evaluation_to_db(
-    db_conn: &Connection,
-    date: &str,
-    name: &str,
-    email: &str,
-    copies_nbr: u32,
-    file_name: &str,
-    price: f64,
-    material_type: &str,
-    print_type: &str,
-    status: &str,
-) -> io::Result<()> {
+fn write_evaluation_to_db(db_conn: &Connection, eval_result: &EvaluationResult) -> io::Result<i64> {
     let params = rusqlite::params![
-        date,
-        name,
-        email,
-        copies_nbr,
-        file_name,
-        price,
-        material_type,
-        print_type,
-        status
+        chrono_to_datetime(&eval_result.date),
+        eval_result.name,
+        eval_result.email,
+        eval_result.copies_nbr,
+        eval_result.file_name,
+        eval_result.price.grosz(),
+        eval_result.material_type.clone(),
+        eval_result.print_type.to_string(),
+        StatusType::New.to_string(),
+        eval_result.model_key,
+        to_json_column(&eval_result.model_statistics)?,
+        to_json_column(&eval_result.price_breakdown)?,
+        to_json_column(&eval_result.print_parameters)?,
+        eval_result.pricing_version
     ];
-    let sql = "INSERT INTO Orders (date, name, email, copies_nbr, file_name, price, material_type, print_type, status) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)";
+    let sql = "INSERT INTO Orders (date, name, email, copies_nbr, file_name, price_grosz, material_type, print_type, status, model_key, model_statistics, price_breakdown, print_parameters, pricing_version) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)";
     match db_conn.execute(sql, params) {
-        Ok(_) => Ok(()),
-        Err(_) => Err(io::Error::new(
-            io::ErrorKind::Other,
-            "Failed to write to database",
-        )),
+        Ok(_) => Ok(db_conn.last_insert_rowid()),
+        Err(_) => Err(io::Error::other("Failed to write to database")),
     }
 }
 
+/**
+ * @brief Stores a pricing configuration, unless the same configuration is already stored.
+ *
+ * @param conn Database connection.
+ * @param hash Hash of the canonical JSON of the configuration.
+ * @param configuration Canonical JSON of the configuration.
+ * @return io::Result<i64> ID of the stored version.
+ */
+fn write_pricing_version_to_db(
+    conn: &Connection,
+    hash: &str,
+    configuration: &str,
+) -> io::Result<i64> {
+    let sql_err =
+        |e: rusqlite::Error| io::Error::other(format!("Failed to store pricing version: {}", e));
+    conn.execute(
+        "INSERT OR IGNORE INTO PricingVersions (hash, created_at, configuration) VALUES (?1, ?2, ?3)",
+        rusqlite::params![hash, chrono_to_datetime(&chrono::Utc::now()), configuration],
+    )
+    .map_err(sql_err)?;
+    conn.query_row(
+        "SELECT id FROM PricingVersions WHERE hash = ?1",
+        [hash],
+        |row| row.get(0),
+    )
+    .map_err(sql_err)
+}
+
+fn read_pricing_versions_from_table(
+    conn: &Connection,
+    version_id: Option<i64>,
+) -> io::Result<Vec<PricingVersion>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, hash, created_at, configuration FROM PricingVersions
+             WHERE ?1 IS NULL OR id = ?1 ORDER BY id",
+        )
+        .map_err(|e| io::Error::other(format!("Failed to prepare statement: {}", e)))?;
+    let version_iter = stmt
+        .query_map([version_id], |row| {
+            let created_at: String = row.get(2)?;
+            let created_at = datetime_to_chrono(&created_at).map_err(|e| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    2,
+                    rusqlite::types::Type::Text,
+                    Box::new(e),
+                )
+            })?;
+            Ok(PricingVersion {
+                id: row.get(0)?,
+                hash: row.get(1)?,
+                created_at,
+                configuration: read_json_column(row, 3)?.unwrap_or_default(),
+            })
+        })
+        .map_err(|e| io::Error::other(format!("Failed to query rows: {}", e)))?;
+    version_iter
+        .map(|version| version.map_err(|e| io::Error::other(format!("Failed to map row: {}", e))))
+        .collect()
+}
+
 fn update_order_status_in_db(
     conn: &Connection,
     table_name: &str,
-    datetime: &str,
+    order_id: i64,
     new_status: &str,
 ) -> io::Result<()> {
-    // Handle case where datetime ends with ' UTC'
-    let datetime: &str = &datetime[0..19];
-
-    let sql = format!("UPDATE {} SET status = ? WHERE date = ?", table_name);
-    let params = [new_status, datetime];
-    match conn.execute(&sql, params) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(io::Error::new(
-            io::ErrorKind::Other,
-            format!("Failed to update order status: {}", e),
+    let sql = format!("UPDATE {} SET status = ?1 WHERE id = ?2", table_name);
+    match conn.execute(&sql, rusqlite::params![new_status, order_id]) {
+        Ok(0) => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Order {} not found in {}", order_id, table_name),
         )),
+        Ok(_) => Ok(()),
+        Err(e) => Err(io::Error::other(format!(
+            "Failed to update order status: {}",
+            e
+        ))),
     }
 }
 
@@ -174,41 +442,24 @@ fn move_orders_between_tables(db_conn: &Mutex<Option<Connection>>) {
         }
     };
     // Create archive table if it doesn't exist
-    conn.execute(
-        "CREATE TABLE IF NOT EXISTS CompletedOrders (
-            date datetime not null,
-            name text not null,
-            email text not null,
-            copies_nbr integer not null,
-            file_name text not null,
-            price REAL not null,
-            material_type text not null,
-            print_type text not null,
-            status text not null
-        )",
-        [],
-    )
-    .map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!("Failed to create archive table: {}", e),
-        )
-    })
-    .unwrap();
+    create_orders_table(conn, "CompletedOrders", false).unwrap();
 
     // Move completed or canceled orders from Orders to CompletedOrders
     conn.execute(
-        "INSERT INTO CompletedOrders SELECT * FROM Orders WHERE status = ?1 OR status = ?2",
+        &format!(
+            "INSERT INTO CompletedOrders ({0}) SELECT {0} FROM Orders WHERE status = ?1 OR status = ?2",
+            ORDER_COLUMNS
+        ),
         [
             StatusType::Canceled.to_string().as_str(),
             StatusType::Completed.to_string().as_str(),
         ],
     )
     .map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!("Failed to move completed/canceled orders to archive: {}", e),
-        )
+        io::Error::other(format!(
+            "Failed to move completed/canceled orders to archive: {}",
+            e
+        ))
     })
     .unwrap();
 
@@ -221,29 +472,29 @@ fn move_orders_between_tables(db_conn: &Mutex<Option<Connection>>) {
         ],
     )
     .map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!("Failed to delete moved orders from Orders table: {}", e),
-        )
+        io::Error::other(format!(
+            "Failed to delete moved orders from Orders table: {}",
+            e
+        ))
     })
     .unwrap();
 
     // Move new or in-progress orders from CompletedOrders to Orders
     conn.execute(
-        "INSERT INTO Orders SELECT * FROM CompletedOrders WHERE status = ?1 OR status = ?2",
+        &format!(
+            "INSERT INTO Orders ({0}) SELECT {0} FROM CompletedOrders WHERE status = ?1 OR status = ?2",
+            ORDER_COLUMNS
+        ),
         [
             StatusType::New.to_string().as_str(),
             StatusType::InProgress.to_string().as_str(),
         ],
     )
     .map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!(
-                "Failed to move new/in-progress orders to Orders table: {}",
-                e
-            ),
-        )
+        io::Error::other(format!(
+            "Failed to move new/in-progress orders to Orders table: {}",
+            e
+        ))
     })
     .unwrap();
 
@@ -256,13 +507,10 @@ fn move_orders_between_tables(db_conn: &Mutex<Option<Connection>>) {
         ],
     )
     .map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!(
-                "Failed to delete moved orders from CompletedOrders table: {}",
-                e
-            ),
-        )
+        io::Error::other(format!(
+            "Failed to delete moved orders from CompletedOrders table: {}",
+            e
+        ))
     })
     .unwrap();
 }
@@ -270,41 +518,15 @@ fn move_orders_between_tables(db_conn: &Mutex<Option<Connection>>) {
 /* PUBLIC FUNCTIONS */
 impl DatabaseInterfaceImpl for DatabaseSQLiteImpl {
     fn initialize_db(&self, db_name: &str) -> io::Result<()> {
-        let conn = Connection::open(db_name).expect("Failed to open database");
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS Orders (
-            date datetime not null,
-            name text not null,
-            email text not null,
-            copies_nbr integer not null,
-            file_name text not null,
-            price REAL not null,
-            material_type text not null,
-            print_type text not null,
-            status text not null
-            )",
-            [],
-        )
-        .expect("Failed to create Orders table");
-
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS CompletedOrders (
-            date datetime not null,
-            name text not null,
-            email text not null,
-            copies_nbr integer not null,
-            file_name text not null,
-            price REAL not null,
-            material_type text not null,
-            print_type text not null,
-            status text not null
-            )",
-            [],
-        )
-        .expect("Failed to create CompletedOrders table");
+        let mut conn = Connection::open(db_name).expect("Failed to open database");
+        create_orders_table(&conn, "Orders", true).expect("Failed to create Orders table");
+        create_orders_table(&conn, "CompletedOrders", false)
+            .expect("Failed to create CompletedOrders table");
+        create_pricing_versions_table(&conn)?;
+        migrate_legacy_orders_tables(&mut conn)?;
         let mut db_conn = self.db_conn.lock().unwrap();
         *db_conn = Some(conn);
-        return Ok(());
+        Ok(())
     }
 
     fn read_orders_from_db(&self) -> io::Result<Vec<EvaluationResult>> {
@@ -316,7 +538,7 @@ impl DatabaseInterfaceImpl for DatabaseSQLiteImpl {
             )
         })?;
 
-        read_orders_from_table(conn, "Orders")
+        read_orders_from_table(conn, "Orders", None)
     }
 
     fn read_completed_orders_from_db(&self) -> io::Result<Vec<EvaluationResult>> {
@@ -328,10 +550,10 @@ impl DatabaseInterfaceImpl for DatabaseSQLiteImpl {
             )
         })?;
 
-        read_orders_from_table(conn, "CompletedOrders")
+        read_orders_from_table(conn, "CompletedOrders", None)
     }
 
-    fn add_evaluation_to_db(&self, eval_result: &EvaluationResult) -> io::Result<()> {
+    fn read_order_from_db(&self, order_id: i64) -> io::Result<Option<EvaluationResult>> {
         let db_conn = self.db_conn.lock().unwrap();
         let conn = db_conn.as_ref().ok_or_else(|| {
             io::Error::new(
@@ -339,24 +561,55 @@ impl DatabaseInterfaceImpl for DatabaseSQLiteImpl {
                 "Database connection is not initialized",
             )
         })?;
-        return write_evaluation_to_db(
-            conn,
-            chrono_to_datetime(&eval_result.date).as_str(),
-            eval_result.name.as_str(),
-            eval_result.email.as_str(),
-            eval_result.copies_nbr,
-            eval_result.file_name.as_str(),
-            eval_result.price,
-            eval_result.material_type.to_string().as_str(),
-            eval_result.print_type.to_string().as_str(),
-            StatusType::New.to_string().as_str(),
-        );
+        // IDs are shared by both tables, as completed orders keep the ID they were given
+        for table_name in ["Orders", "CompletedOrders"] {
+            if let Some(order) = read_orders_from_table(conn, table_name, Some(order_id))?.pop() {
+                return Ok(Some(order));
+            }
+        }
+        Ok(None)
+    }
+
+    fn add_evaluation_to_db(&self, eval_result: &EvaluationResult) -> io::Result<i64> {
+        let db_conn = self.db_conn.lock().unwrap();
+        let conn = db_conn.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotConnected,
+                "Database connection is not initialized",
+            )
+        })?;
+        write_evaluation_to_db(conn, eval_result)
+    }
+
+    fn add_pricing_version_to_db(&self, hash: &str, configuration: &str) -> io::Result<i64> {
+        let db_conn = self.db_conn.lock().unwrap();
+        let conn = db_conn.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotConnected,
+                "Database connection is not initialized",
+            )
+        })?;
+        write_pricing_version_to_db(conn, hash, configuration)
+    }
+
+    fn read_pricing_versions_from_db(
+        &self,
+        version_id: Option<i64>,
+    ) -> io::Result<Vec<PricingVersion>> {
+        let db_conn = self.db_conn.lock().unwrap();
+        let conn = db_conn.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotConnected,
+                "Database connection is not initialized",
+            )
+        })?;
+        read_pricing_versions_from_table(conn, version_id)
     }
 
     fn modify_order_in_database(
         &self,
         table_name: &str,
-        datetime: &str,
+        order_id: i64,
         new_status: &str,
     ) -> io::Result<()> {
         let db_conn = self.db_conn.lock().unwrap();
@@ -366,7 +619,7 @@ impl DatabaseInterfaceImpl for DatabaseSQLiteImpl {
                 "Database connection is not initialized",
             )
         })?;
-        let update_result = update_order_status_in_db(conn, table_name, datetime, new_status);
+        let update_result = update_order_status_in_db(conn, table_name, order_id, new_status);
         if update_result.is_ok() {
             // Clone the Arc<Mutex> to pass it to the new thread
             let db_conn_clone = self.db_conn.clone();
@@ -375,6 +628,220 @@ impl DatabaseInterfaceImpl for DatabaseSQLiteImpl {
                 move_orders_between_tables(&db_conn_clone);
             });
         }
-        return update_result;
+        update_result
+    }
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_utils::global_types::{
+        EvaluatedPrintingParameters, FeatureExtrusion, HourlyBilling, ModelStatistics,
+        PriceBreakdown, PrintType,
+    };
+    use crate::common_utils::material_catalogue::MaterialPrice;
+
+    fn open_in_memory_db() -> DatabaseSQLiteImpl {
+        let db = DatabaseSQLiteImpl {
+            db_conn: Arc::new(Mutex::new(None)),
+        };
+        db.initialize_db(":memory:").unwrap();
+        db
+    }
+
+    fn sample_evaluation(date: chrono::DateTime<chrono::Utc>) -> EvaluationResult {
+        EvaluationResult {
+            id: None,
+            date,
+            name: "John Doe".to_string(),
+            email: "john.doe@example.com".to_string(),
+            copies_nbr: 1,
+            file_name: "file.stl".to_string(),
+            price: Money::from_pln(10),
+            material_type: "PLA".to_string(),
+            print_type: PrintType::ThickStrong,
+            status: StatusType::New,
+            model_key: "model.stl".to_string(),
+            model_statistics: Some(ModelStatistics {
+                triangle_count: 12,
+                bounding_box_min: [0.0; 3],
+                bounding_box_max: [10.0; 3],
+                volume: 1000.0,
+                surface_area: 600.0,
+                is_watertight: true,
+            }),
+            price_breakdown: Some(PriceBreakdown {
+                material_cost: Money::from_grosz(60),
+                material_price: Some(MaterialPrice::PerMetre(Money::from_grosz(60))),
+                material_mass_g: Some(3.0),
+                slicer_filament_cost: None,
+                machine_time_cost: Money::from_grosz(840),
+                hourly_billing: HourlyBilling::WholeJob,
+                hourly_tier: 0,
+                hourly_tier_from_hours: 0,
+                hourly_rate: Money::from_pln(30),
+                hourly_bands: Vec::new(),
+                print_type_multiplier: 1.0,
+                print_type_surcharge: Money::ZERO,
+                print_type_adjustment: Money::ZERO,
+                order_fee: Money::from_pln(1),
+                per_copy_subtotal: Money::from_pln(9),
+                copies: 1,
+                quantity_discount_percent: 0.0,
+                quantity_discount: Money::ZERO,
+                minimum_order_adjustment: Money::ZERO,
+                rounding_adjustment: Money::ZERO,
+                total: Money::from_pln(10),
+            }),
+            print_parameters: Some(EvaluatedPrintingParameters {
+                time: 1000,
+                material_mm: 1000,
+                material_g: Some(3.0),
+                silent_mode_time: Some(1100),
+                layer_count: Some(42),
+                max_z_mm: Some(8.4),
+                extrusion: Some(FeatureExtrusion {
+                    perimeter_mm: 600.0,
+                    infill_mm: 300.0,
+                    support_mm: 100.0,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            pricing_version: None,
+        }
+    }
+
+    #[test]
+    fn test_orders_submitted_in_the_same_second_get_distinct_ids() {
+        let db = open_in_memory_db();
+        let date = chrono::Utc::now();
+        let first_id = db.add_evaluation_to_db(&sample_evaluation(date)).unwrap();
+        let second_id = db.add_evaluation_to_db(&sample_evaluation(date)).unwrap();
+        assert_ne!(first_id, second_id);
+
+        db.modify_order_in_database("Orders", second_id, "InProgress")
+            .unwrap();
+        let orders = db.read_orders_from_db().unwrap();
+        let first = orders.iter().find(|o| o.id == Some(first_id)).unwrap();
+        let second = orders.iter().find(|o| o.id == Some(second_id)).unwrap();
+        assert_eq!(first.status.to_string(), "New");
+        assert_eq!(second.status.to_string(), "InProgress");
+    }
+
+    #[test]
+    fn test_model_statistics_and_price_breakdown_are_stored_with_the_order() {
+        let db = open_in_memory_db();
+        let order_id = db
+            .add_evaluation_to_db(&sample_evaluation(chrono::Utc::now()))
+            .unwrap();
+        let orders = db.read_orders_from_db().unwrap();
+        let order = orders.iter().find(|o| o.id == Some(order_id)).unwrap();
+        let expected = sample_evaluation(order.date);
+        assert_eq!(order.model_statistics, expected.model_statistics);
+        assert_eq!(order.price_breakdown, expected.price_breakdown);
+        assert_eq!(order.print_parameters, expected.print_parameters);
+    }
+
+    #[test]
+    fn test_pricing_versions_are_stored_once_and_referenced_by_orders() {
+        let db = open_in_memory_db();
+        let first = db
+            .add_pricing_version_to_db("aa", r#"{"order_fee":"1.00"}"#)
+            .unwrap();
+        let second = db
+            .add_pricing_version_to_db("bb", r#"{"order_fee":"2.00"}"#)
+            .unwrap();
+        assert_ne!(first, second);
+        assert_eq!(
+            db.add_pricing_version_to_db("aa", r#"{"order_fee":"1.00"}"#)
+                .unwrap(),
+            first
+        );
+        let versions = db.read_pricing_versions_from_db(None).unwrap();
+        assert_eq!(versions.len(), 2);
+        let stored = db.read_pricing_versions_from_db(Some(second)).unwrap();
+        assert_eq!(stored[0].hash, "bb");
+        assert_eq!(stored[0].configuration["order_fee"], "2.00");
+
+        let order_id = db
+            .add_evaluation_to_db(&EvaluationResult {
+                pricing_version: Some(second),
+                ..sample_evaluation(chrono::Utc::now())
+            })
+            .unwrap();
+        let order = db.read_order_from_db(order_id).unwrap().unwrap();
+        assert_eq!(order.id, Some(order_id));
+        assert_eq!(order.pricing_version, Some(second));
+        assert!(db.read_order_from_db(order_id + 1).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_modify_unknown_order_returns_not_found() {
+        let db = open_in_memory_db();
+        let err = db
+            .modify_order_in_database("Orders", 42, "Completed")
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_real_prices_are_migrated_to_grosz() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        for (table_name, allocates_ids) in [("Orders", true), ("CompletedOrders", false)] {
+            create_orders_table(&conn, table_name, allocates_ids).unwrap();
+            conn.execute_batch(&format!(
+                "ALTER TABLE {0} RENAME COLUMN price_grosz TO price;
+                 INSERT INTO {0} (id, date, name, email, copies_nbr, file_name, price, material_type, print_type, status)
+                    VALUES (1, '2024-01-01 10:00:00', 'a', 'a@x', 1, 'a.stl', 62.2, 'PLA', 'ThickStrong', 'New');",
+                table_name
+            ))
+            .unwrap();
+        }
+        migrate_legacy_orders_tables(&mut conn).unwrap();
+
+        for table_name in ["Orders", "CompletedOrders"] {
+            assert!(!table_has_column(&conn, table_name, "price").unwrap());
+            let orders = read_orders_from_table(&conn, table_name, None).unwrap();
+            assert_eq!(orders[0].price, Money::from_grosz(6220));
+        }
+    }
+
+    #[test]
+    fn test_legacy_tables_are_migrated_with_unique_ids() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        for table_name in ["Orders", "CompletedOrders"] {
+            conn.execute_batch(&format!(
+                "CREATE TABLE {0} (date datetime not null, name text not null, email text not null,
+                    copies_nbr integer not null, file_name text not null, price REAL not null,
+                    material_type text not null, print_type text not null, status text not null);
+                 INSERT INTO {0} VALUES ('2024-01-01 10:00:00', 'a', 'a@x', 1, 'a.stl', 1.0, 'PLA', 'ThickStrong', 'New');
+                 INSERT INTO {0} VALUES ('2024-01-01 10:00:00', 'b', 'b@x', 1, 'b.stl', 19.99, 'PLA', 'ThickStrong', 'New');",
+                table_name
+            ))
+            .unwrap();
+        }
+        migrate_legacy_orders_tables(&mut conn).unwrap();
+
+        let mut ids: Vec<i64> = Vec::new();
+        for table_name in ["Orders", "CompletedOrders"] {
+            ids.extend(
+                read_orders_from_table(&conn, table_name, None)
+                    .unwrap()
+                    .iter()
+                    .map(|o| o.id.unwrap()),
+            );
+        }
+        ids.sort();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+        let orders = read_orders_from_table(&conn, "Orders", None).unwrap();
+        assert!(orders.iter().all(|o| o.model_key.is_empty()));
+        let mut prices: Vec<i64> = orders.iter().map(|o| o.price.grosz()).collect();
+        prices.sort();
+        assert_eq!(prices, vec![100, 1999]);
+
+        let new_id = write_evaluation_to_db(&conn, &sample_evaluation(chrono::Utc::now())).unwrap();
+        assert_eq!(new_id, 5);
     }
 }
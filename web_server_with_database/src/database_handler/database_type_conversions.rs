@@ -3,22 +3,13 @@ use chrono::Utc;
 
 /* IMPORTS FROM LIBRARIES */
 /* IMPORTS FROM OTHER MODULES */
-use crate::common_utils::global_types::{PrintMaterialType, PrintType, StatusType};
+use crate::common_utils::global_types::{PrintType, StatusType};
 
 /* PRIVATE TYPES AND VARIABLES */
 /* PUBLIC TYPES AND VARIABLES */
 /* PRIVATE FUNCTIONS */
 /* PUBLIC FUNCTIONS */
 
-pub fn str_to_print_material_type(material: &str) -> Result<PrintMaterialType, &'static str> {
-    match material {
-        "PLA" => Ok(PrintMaterialType::PLA),
-        "PET" => Ok(PrintMaterialType::PET),
-        "ASA" => Ok(PrintMaterialType::ASA),
-        _ => Err("Unknown material type"),
-    }
-}
-
 pub fn str_to_status_type(status: &str) -> Result<StatusType, &'static str> {
     match status {
         "New" => Ok(StatusType::New),
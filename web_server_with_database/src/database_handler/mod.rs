@@ -3,6 +3,7 @@ pub mod database_handler;
 pub use database_handler::*;
 
 // PRIVATE MODULES
+#[cfg(test)]
 mod database_mock;
 mod database_sqlite_impl;
 mod database_type_conversions;
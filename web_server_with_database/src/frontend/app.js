@@ -3,7 +3,13 @@ import createDragAndDropArea from "./drag_and_drop_area.js";
 
 
 var ERROR_CODE_FAILED_PROCESSING_SUBMITTED_FORM = 1006;
+var PROTOCOL_VERSION = 1;
 var EVALUATION_RESULT_TYPE = "evaluation_result";
+var ERROR_TYPE = "error";
+var RECONNECT_DELAY_MS = 2000;
+
+// Upload in progress, kept to resume it when the connection drops
+var currentUpload = null;
 
 /**
  * Function to create the form and add event listeners.
@@ -19,11 +25,7 @@ function createForm() {
             <label for="copies_nbr">Number of copies:</label>
             <input type="number" id="copies_nbr" name="copies_nbr" required><br>
             <label for="material_type">Material type:</label>
-            <select id="material_type" name="material_type" required>
-                <option value="PLA">PLA</option>
-                <option value="PET">PET</option>
-                <option value="ASA">ASA</option>
-            </select><br>
+            <select id="material_type" name="material_type" required></select><br>
             <label for="print_type">Print type:</label>
             <select id="print_type" name="print_type" required>
                 <option value="ThickStrong">Thick Strong</option>
@@ -34,9 +36,12 @@ function createForm() {
             <label for="file">Choose file to upload:</label>
             <div id="drag-drop-placeholder"></div>
             <button type="submit" id="upload-button">Evaluate Price</button>
+            <p id="evaluation-progress"></p>
         </form>
     `;
 
+    loadMaterials(document.getElementById("material_type"));
+
     // Insert drag & drop area
     const placeholder = document.getElementById("drag-drop-placeholder");
     const fileInput = createDragAndDropArea(placeholder);
@@ -77,16 +82,194 @@ function createForm() {
             arrayBufferChunks.push(await chunk.arrayBuffer());
         }
 
+        currentUpload = { token: null, chunks: arrayBufferChunks };
+
         // Send metadata first
         ws.send(JSON.stringify(metadata));
 
         // Then send binary chunks
-        for (const chunk of arrayBufferChunks) {
-            ws.send(chunk);
-        }
+        sendChunks(0);
     });
 }
 
+/**
+ * Fills the material selection with the catalogue of the server.
+ * @param {HTMLSelectElement} materialSelect - Selection to fill.
+ */
+async function loadMaterials(materialSelect) {
+    try {
+        const response = await fetch("/api/materials");
+        if (!response.ok) {
+            throw new Error(`HTTP error! status: ${response.status}`);
+        }
+        for (const material of await response.json()) {
+            const option = document.createElement("option");
+            option.value = material.name;
+            option.textContent = material.name;
+            materialSelect.appendChild(option);
+        }
+    } catch (error) {
+        console.error("Error fetching materials:", error);
+    }
+}
+
+/**
+ * Describes the catalogue price a material cost was calculated with.
+ * @param {object|null} materialPrice - `{per_kg: amount}` or `{per_metre: amount}`.
+ * @returns {string} The price with its unit, empty if unknown.
+ */
+function describeMaterialPrice(materialPrice) {
+    if (!materialPrice) {
+        return "";
+    }
+    if (materialPrice.per_kg !== undefined) {
+        return ` (${materialPrice.per_kg} PLN/kg)`;
+    }
+    return ` (${materialPrice.per_metre} PLN/m)`;
+}
+
+/**
+ * Sends the chunks of the current upload, starting from the given one.
+ * @param {number} firstChunk - Index of the first chunk to send.
+ */
+function sendChunks(firstChunk) {
+    for (const chunk of currentUpload.chunks.slice(firstChunk)) {
+        ws.send(chunk);
+    }
+}
+
+/**
+ * Shows the progress of the current evaluation below the form.
+ * @param {string} text - Progress description, empty to clear it.
+ */
+function showProgress(text) {
+    const progress = document.getElementById("evaluation-progress");
+    if (progress) {
+        progress.textContent = text;
+    }
+}
+
+/**
+ * Dispatches a message received from the evaluation WebSocket.
+ * @param {object} message - Message envelope with `version`, `type` and optional `data`.
+ */
+function handleServerMessage(message) {
+    const data = message.data;
+    switch (message.type) {
+        case "upload_token":
+            if (currentUpload) {
+                currentUpload.token = data.token;
+            }
+            break;
+        case "upload_resumed":
+            showProgress(`Resuming upload: ${data.received} of ${data.total} chunks already stored`);
+            if (currentUpload) {
+                sendChunks(data.received);
+            }
+            break;
+        case "chunk_ack":
+            showProgress(`Uploading: ${data.received} of ${data.total} chunks sent`);
+            break;
+        case "upload_complete":
+            currentUpload = null;
+            showProgress(`Upload of ${data.file_name} complete`);
+            break;
+        case "queued":
+            showProgress(data.position === 0
+                ? `${data.file_name} is next in line for slicing`
+                : `${data.file_name} is waiting for slicing, ${data.position} order(s) ahead`);
+            break;
+        case "slicing_started":
+            showProgress(`Slicing ${data.file_name}...`);
+            break;
+        case "slicing_finished":
+            showProgress(`Slicing of ${data.file_name} finished`);
+            break;
+        case EVALUATION_RESULT_TYPE:
+            showProgress("");
+            showEvaluationResultPopup(data);
+            break;
+        case "model_does_not_fit":
+            showProgress("");
+            alert(data.message);
+            break;
+        case ERROR_TYPE:
+            currentUpload = null;
+            showProgress("");
+            alert(`The order could not be evaluated: ${data.message}`);
+            break;
+        default:
+            console.warn("Unknown message type: ", message.type);
+    }
+}
+
+/**
+ * Describes how the print time of a quote was billed.
+ * @param {object} breakdown - Price breakdown of the order.
+ * @returns {string} The applied rate, or the rate of every band for marginal billing.
+ */
+function describeHourlyBilling(breakdown) {
+    if (breakdown.hourly_bands && breakdown.hourly_bands.length) {
+        const bands = breakdown.hourly_bands.map(band =>
+            `${(band.seconds / 3600).toFixed(2)} h from ${band.from_hours} h at ${band.rate} PLN/h`);
+        return `(${bands.join(", ")})`;
+    }
+    return `(${breakdown.hourly_rate} PLN/h, tier from ${breakdown.hourly_tier_from_hours} h)`;
+}
+
+/**
+ * Describes the multiplier and surcharge the print type added to a copy.
+ * @param {object} breakdown - Price breakdown of the order.
+ * @returns {string} HTML list item, empty if the print type is not adjusted.
+ */
+function describePrintTypeAdjustment(breakdown) {
+    if (!breakdown.print_type_adjustment || Number(breakdown.print_type_adjustment) === 0) {
+        return "";
+    }
+    return `<li>Print type per copy: ${breakdown.print_type_adjustment} PLN
+                (× ${breakdown.print_type_multiplier} + ${breakdown.print_type_surcharge} PLN)</li>`;
+}
+
+/**
+ * Describes the quantity discount and the minimum order value applied to a quote.
+ * @param {object} breakdown - Price breakdown of the order.
+ * @returns {string} HTML list items, empty if neither applies.
+ */
+function describeOrderAdjustments(breakdown) {
+    let items = "";
+    if (breakdown.quantity_discount && Number(breakdown.quantity_discount) !== 0) {
+        items += `<li>Quantity discount (${breakdown.quantity_discount_percent}%): -${breakdown.quantity_discount} PLN</li>`;
+    }
+    if (breakdown.minimum_order_adjustment && Number(breakdown.minimum_order_adjustment) !== 0) {
+        items += `<li>Raised to the minimum order value: ${breakdown.minimum_order_adjustment} PLN</li>`;
+    }
+    return items;
+}
+
+/**
+ * Lists the components of a quote.
+ * @param {object|null} breakdown - Price breakdown of the order, missing for old orders.
+ * @returns {string} HTML list of the price components.
+ */
+function describePriceBreakdown(breakdown) {
+    if (!breakdown) {
+        return "";
+    }
+    return `
+        <ul>
+            <li>Material per copy: ${breakdown.material_cost} PLN${describeMaterialPrice(breakdown.material_price)}</li>
+            <li>Machine time per copy: ${breakdown.machine_time_cost} PLN
+                ${describeHourlyBilling(breakdown)}</li>
+            ${describePrintTypeAdjustment(breakdown)}
+            <li>Per copy: ${breakdown.per_copy_subtotal} PLN × ${breakdown.copies}</li>
+            ${describeOrderAdjustments(breakdown)}
+            <li>Order fee: ${breakdown.order_fee} PLN</li>
+            <li>Rounding: ${breakdown.rounding_adjustment} PLN</li>
+            <li>Total: ${breakdown.total} PLN</li>
+        </ul>
+    `;
+}
+
 function showEvaluationResultPopup(data) {
     // Create a popup window with the evaluation result details
     const popup = document.createElement("div");
@@ -109,6 +292,8 @@ function showEvaluationResultPopup(data) {
                     <p><strong>Print Type:</strong> ${data.print_type}</p>
                     <h2>Estimated Price</h2>
                     <p><strong>Printing price (without delivery):</strong> ${data.price}</p>
+                    ${describePriceBreakdown(data.price_breakdown)}
+                    ${data.estimated ? `<p><em>${data.message}</em></p>` : ""}
                     <button id="close-eval-popup">Close</button>
                 `;
     document.body.appendChild(popup);
@@ -136,17 +321,30 @@ document.addEventListener("DOMContentLoaded", function () {
             document.getElementById("form-container").innerText = error.message;
         });
 
-    // WebSocket setup
+    connectWebSocket();
+
+    // Create the form regardless of the API call status
+    createForm();
+
+    // Create the orders table
+    createRetrievedOrdersTable();
+});
+
+/**
+ * Opens the evaluation WebSocket, resuming the current upload if it was interrupted.
+ */
+function connectWebSocket() {
     window.ws = new WebSocket("ws://127.0.0.1:8080/api/websocket_evaluation");
     window.ws.onopen = function () {
         console.log("WebSocket connection established.");
+        if (currentUpload && currentUpload.token) {
+            window.ws.send(JSON.stringify({ resume_token: currentUpload.token }));
+        }
     };
     window.ws.onmessage = function (event) {
+        let message;
         try {
-            let data = JSON.parse(event.data);
-            if (data.type === EVALUATION_RESULT_TYPE) {
-                showEvaluationResultPopup(data);
-            }
+            message = JSON.parse(event.data);
         } catch (e) {
             alert("Error parsing server response. Please check the console for details.");
             return;
@@ -154,17 +352,19 @@ document.addEventListener("DOMContentLoaded", function () {
         finally {
             console.log("Received message: ", event.data);
         }
+        if (message.version !== PROTOCOL_VERSION) {
+            console.error("Unsupported protocol version: ", message.version);
+            return;
+        }
+        handleServerMessage(message);
     };
     window.ws.onclose = function (event) {
         console.log(event);
-        if (event.code === ERROR_CODE_FAILED_PROCESSING_SUBMITTED_FORM) {
+        if (currentUpload && currentUpload.token) {
+            showProgress("Connection lost, reconnecting to resume the upload...");
+            setTimeout(connectWebSocket, RECONNECT_DELAY_MS);
+        } else if (event.code === ERROR_CODE_FAILED_PROCESSING_SUBMITTED_FORM) {
             alert("Error with processing the request. Websocket connection closed unexpectedly.");
         }
     };
-
-    // Create the form regardless of the API call status
-    createForm();
-
-    // Create the orders table
-    createRetrievedOrdersTable();
-});
+}
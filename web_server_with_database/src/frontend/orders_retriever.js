@@ -4,11 +4,14 @@ export default function createRetrievedOrdersTable() {
         <table id="orders-table">
             <thead>
                 <tr>
+                    <th>ID</th>
                     <th>Date</th>
                     <th>Name</th>
                     <th>Email</th>
                     <th>Copies</th>
                     <th>File Name</th>
+                    <th>Model</th>
+                    <th>Print</th>
                     <th>Price</th>
                     <th>Material Type</th>
                     <th>Print Type</th>
@@ -56,8 +59,11 @@ function populateOrdersTable(orders, table_body, modify_api_url) {
 
     orders.forEach(order => {
         const row = document.createElement("tr");
-        // Set row ID to order.date
-        row.id = `order-${order.date}`;
+        row.id = `order-${order.id}`;
+
+        const idTd = document.createElement("td");
+        idTd.textContent = order.id;
+        row.appendChild(idTd);
 
         const dateTd = document.createElement("td");
         dateTd.textContent = order.date;
@@ -79,8 +85,24 @@ function populateOrdersTable(orders, table_body, modify_api_url) {
         fileNameTd.textContent = order.file_name;
         row.appendChild(fileNameTd);
 
+        const modelTd = document.createElement("td");
+        modelTd.textContent = describeModel(order.model_statistics);
+        row.appendChild(modelTd);
+
+        const printTd = document.createElement("td");
+        printTd.textContent = describePrint(order.print_parameters);
+        printTd.title = describeExtrusion(order.print_parameters);
+        row.appendChild(printTd);
+
         const priceTd = document.createElement("td");
-        priceTd.textContent = order.price.toFixed(2) + " PLN";
+        priceTd.textContent = order.price + " PLN";
+        if (order.price_breakdown) {
+            const b = order.price_breakdown;
+            priceTd.title = `Material ${b.material_cost} + machine time ${b.machine_time_cost} `
+                + `(${b.hourly_rate} PLN/h from ${b.hourly_tier_from_hours} h) per copy, `
+                + `× ${b.copies} - discount ${b.quantity_discount || "0.00"} + order fee ${b.order_fee} `
+                + `+ minimum ${b.minimum_order_adjustment || "0.00"} + rounding ${b.rounding_adjustment} PLN`;
+        }
         row.appendChild(priceTd);
 
         const materialTd = document.createElement("td");
@@ -99,6 +121,78 @@ function populateOrdersTable(orders, table_body, modify_api_url) {
     });
 }
 
+/**
+ * Summarizes the geometry of an order's model for the orders table.
+ * @param {object|null} stats - Model statistics stored with the order, missing for old orders.
+ * @returns {string} Size, volume and triangle count of the model.
+ */
+function describeModel(stats) {
+    if (!stats) {
+        return "-";
+    }
+    const size = stats.bounding_box_max
+        .map((max, axis) => (max - stats.bounding_box_min[axis]).toFixed(1))
+        .join(" x ");
+    const volumeCm3 = (stats.volume / 1000).toFixed(1);
+    const watertight = stats.is_watertight ? "" : ", not watertight";
+    return `${size} mm, ${volumeCm3} cm³, ${stats.triangle_count} triangles${watertight}`;
+}
+
+/**
+ * Formats a duration in seconds as hours and minutes.
+ * @param {number} seconds - Duration in seconds.
+ * @returns {string} The duration, e.g. "26h 05m".
+ */
+function formatDuration(seconds) {
+    const minutes = Math.round(seconds / 60);
+    return `${Math.floor(minutes / 60)}h ${String(minutes % 60).padStart(2, "0")}m`;
+}
+
+/**
+ * Summarizes the slicing results of an order's model for the orders table.
+ * @param {object|null} params - Printing parameters stored with the order, missing for old orders.
+ * @returns {string} Print times, layers, height and filament use of one copy, and whether they
+ *          were estimated without slicing.
+ */
+function describePrint(params) {
+    if (!params) {
+        return "-";
+    }
+    const parts = [formatDuration(params.time)];
+    if (params.silent_mode_time) {
+        parts[0] += ` (silent ${formatDuration(params.silent_mode_time)})`;
+    }
+    if (params.layer_count) {
+        parts.push(`${params.layer_count} layers`);
+    }
+    if (params.max_z_mm) {
+        parts.push(`${params.max_z_mm.toFixed(2)} mm high`);
+    }
+    parts.push(params.material_g ? `${params.material_g.toFixed(2)} g` : `${params.material_mm} mm`);
+    if (params.wipe_tower_g) {
+        parts.push(`wipe tower ${params.wipe_tower_g.toFixed(2)} g`);
+    }
+    if (params.estimated) {
+        parts.push("estimated without slicing");
+    }
+    return parts.join(", ");
+}
+
+/**
+ * Lists the filament extruded per feature, shown as the tooltip of the print summary.
+ * @param {object|null} params - Printing parameters stored with the order.
+ * @returns {string} Filament length per feature, empty if the G-code was not analysed.
+ */
+function describeExtrusion(params) {
+    const e = params && params.extrusion;
+    if (!e) {
+        return "";
+    }
+    return `Perimeters ${e.perimeter_mm.toFixed(0)} mm, infill ${e.infill_mm.toFixed(0)} mm, `
+        + `support ${e.support_mm.toFixed(0)} mm, wipe tower ${e.wipe_tower_mm.toFixed(0)} mm, `
+        + `other ${e.other_mm.toFixed(0)} mm of filament`;
+}
+
 function createStatusDropdown(parent, order, modify_api_url) {
     const statusSelect = document.createElement("select");
     statusSelect.className = "status-select";
@@ -122,7 +216,7 @@ function createStatusDropdown(parent, order, modify_api_url) {
                 headers: {
                     "Content-Type": "application/json"
                 },
-                body: JSON.stringify({ datetime: order.date, new_status: this.value })
+                body: JSON.stringify({ id: order.id, new_status: this.value })
             });
 
             if (!response.ok) {
@@ -0,0 +1,241 @@
+/* IMPORTS FROM LIBRARIES */
+use flate2::read::ZlibDecoder;
+use std::io::{self, Read, Seek, SeekFrom};
+
+/* IMPORTS FROM OTHER MODULES */
+
+/* PRIVATE TYPES AND VARIABLES */
+const FILE_VERSION: u32 = 1;
+
+const CHECKSUM_NONE: u16 = 0;
+const CHECKSUM_CRC32: u16 = 1;
+
+const BLOCK_GCODE: u16 = 1;
+const BLOCK_PRINTER_METADATA: u16 = 3;
+const BLOCK_PRINT_METADATA: u16 = 4;
+const BLOCK_THUMBNAIL: u16 = 5;
+
+const COMPRESSION_NONE: u16 = 0;
+const COMPRESSION_DEFLATE: u16 = 1;
+
+const ENCODING_INI: u16 = 0;
+
+/// Header of a block: type, compression and size of the data before and after compression.
+struct BlockHeader {
+    block_type: u16,
+    compression: u16,
+    uncompressed_size: u32,
+    compressed_size: u32,
+    /// The header as stored in the file, for the checksum.
+    bytes: Vec<u8>,
+}
+
+/* PUBLIC TYPES AND VARIABLES */
+/// First bytes of every binary G-code file.
+pub const MAGIC: &[u8; 4] = b"GCDE";
+
+/* PRIVATE FUNCTIONS */
+fn invalid_gcode(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_bytes<F: Read>(file: &mut F, size: usize) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![0; size];
+    file.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn le_u16(bytes: &[u8]) -> u16 {
+    u16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+fn le_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+/**
+ * @brief Reads the header of the next block.
+ *
+ * @param file Binary G-code file, positioned at the start of a block.
+ * @return io::Result<Option<BlockHeader>> The header, or None at the end of the file.
+ */
+fn read_block_header<F: Read>(file: &mut F) -> io::Result<Option<BlockHeader>> {
+    let mut bytes = vec![0; 8];
+    match file.read_exact(&mut bytes) {
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        result => result?,
+    }
+    let compression = le_u16(&bytes[2..]);
+    let uncompressed_size = le_u32(&bytes[4..]);
+    let compressed_size = if compression == COMPRESSION_NONE {
+        uncompressed_size
+    } else {
+        bytes.extend(read_bytes(file, 4)?);
+        le_u32(&bytes[8..])
+    };
+    Ok(Some(BlockHeader {
+        block_type: le_u16(&bytes),
+        compression,
+        uncompressed_size,
+        compressed_size,
+        bytes,
+    }))
+}
+
+/**
+ * @brief Decodes the data of a metadata block into its entries.
+ *
+ * @param header Header of the block.
+ * @param parameters Parameters of the block, holding the encoding of its data.
+ * @param data Data of the block as stored in the file.
+ * @return io::Result<Vec<(String, String)>> The "key=value" entries of the block.
+ */
+fn decode_metadata(
+    header: &BlockHeader,
+    parameters: &[u8],
+    data: Vec<u8>,
+) -> io::Result<Vec<(String, String)>> {
+    if le_u16(parameters) != ENCODING_INI {
+        return Err(invalid_gcode(format!(
+            "Unsupported metadata encoding {}",
+            le_u16(parameters)
+        )));
+    }
+    let text = match header.compression {
+        COMPRESSION_NONE => data,
+        COMPRESSION_DEFLATE => {
+            let mut text = Vec::with_capacity(header.uncompressed_size as usize);
+            ZlibDecoder::new(data.as_slice()).read_to_end(&mut text)?;
+            text
+        }
+        compression => {
+            return Err(invalid_gcode(format!(
+                "Unsupported metadata compression {}",
+                compression
+            )))
+        }
+    };
+    Ok(String::from_utf8_lossy(&text)
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect())
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Reads the printer and print metadata of a PrusaSlicer binary G-code (.bgcode) file.
+ *
+ * All metadata blocks come before the G-code blocks, so reading stops at the first G-code
+ * block; thumbnails and other blocks are skipped without being read. The checksums of the
+ * decoded blocks are verified.
+ *
+ * @param file Binary G-code file, at any position.
+ * @return io::Result<Vec<(String, String)>> The entries of the printer and the print metadata,
+ *         in file order, or an `InvalidData` error if the file is damaged.
+ */
+pub fn read_metadata_entries<F: Read + Seek>(file: &mut F) -> io::Result<Vec<(String, String)>> {
+    file.seek(SeekFrom::Start(0))?;
+    let file_header = read_bytes(file, 10)
+        .map_err(|_| invalid_gcode("The binary G-code header is truncated".to_string()))?;
+    if &file_header[..4] != MAGIC {
+        return Err(invalid_gcode("Not a binary G-code file".to_string()));
+    }
+    if le_u32(&file_header[4..]) != FILE_VERSION {
+        return Err(invalid_gcode(format!(
+            "Unsupported binary G-code version {}",
+            le_u32(&file_header[4..])
+        )));
+    }
+    let checksum_size = match le_u16(&file_header[8..]) {
+        CHECKSUM_NONE => 0,
+        CHECKSUM_CRC32 => 4,
+        checksum_type => {
+            return Err(invalid_gcode(format!(
+                "Unsupported checksum type {}",
+                checksum_type
+            )))
+        }
+    };
+
+    let mut entries = Vec::new();
+    while let Some(header) = read_block_header(file)? {
+        if header.block_type == BLOCK_GCODE {
+            break;
+        }
+        let parameters_size = if header.block_type == BLOCK_THUMBNAIL {
+            6
+        } else {
+            2
+        };
+        if !matches!(
+            header.block_type,
+            BLOCK_PRINTER_METADATA | BLOCK_PRINT_METADATA
+        ) {
+            let block_size = parameters_size + i64::from(header.compressed_size) + checksum_size;
+            file.seek(SeekFrom::Current(block_size))?;
+            continue;
+        }
+        let truncated = |_| invalid_gcode("A metadata block is truncated".to_string());
+        let parameters = read_bytes(file, parameters_size as usize).map_err(truncated)?;
+        let data = read_bytes(file, header.compressed_size as usize).map_err(truncated)?;
+        if checksum_size > 0 {
+            let checksum = read_bytes(file, 4).map_err(truncated)?;
+            let mut hasher = crc32fast::Hasher::new();
+            hasher.update(&header.bytes);
+            hasher.update(&parameters);
+            hasher.update(&data);
+            if hasher.finalize() != le_u32(&checksum) {
+                return Err(invalid_gcode(format!(
+                    "Checksum of metadata block {} does not match",
+                    header.block_type
+                )));
+            }
+        }
+        entries.extend(decode_metadata(&header, &parameters, data)?);
+    }
+    Ok(entries)
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn sample_file() -> Vec<u8> {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("test_files/gcode/handwritten_prusaslicer_2_7_mk4.bgcode");
+        std::fs::read(path).unwrap()
+    }
+
+    #[test]
+    fn test_metadata_blocks_are_decoded_and_others_skipped() {
+        let entries = read_metadata_entries(&mut Cursor::new(sample_file())).unwrap();
+        let keys: Vec<&str> = entries.iter().map(|(key, _)| key.as_str()).collect();
+        // Printer metadata, then the deflate compressed print metadata
+        assert_eq!(keys.first(), Some(&"printer_model"));
+        assert_eq!(
+            keys.last(),
+            Some(&"estimated first layer printing time (silent mode)")
+        );
+        assert!(!keys.contains(&"Producer"));
+        assert!(!keys.contains(&"binary_gcode"));
+    }
+
+    #[test]
+    fn test_damaged_files_are_rejected() {
+        let mut damaged = sample_file();
+        // Change a character of the printer model in the printer metadata block
+        let position = damaged.windows(3).position(|w| w == b"MK4").unwrap();
+        damaged[position + 2] = b'3';
+        let error = read_metadata_entries(&mut Cursor::new(damaged)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut truncated = sample_file();
+        truncated.truncate(60);
+        assert!(read_metadata_entries(&mut Cursor::new(truncated)).is_err());
+
+        assert!(read_metadata_entries(&mut Cursor::new(b"; text G-code\n".to_vec())).is_err());
+    }
+}
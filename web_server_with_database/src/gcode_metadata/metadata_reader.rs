@@ -0,0 +1,557 @@
+/* IMPORTS FROM LIBRARIES */
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::GcodeMetadata;
+use crate::gcode_metadata::binary_gcode;
+use crate::gcode_metadata::text_gcode;
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Bytes read from the end of a text G-code file at first. PrusaSlicer writes its statistics
+/// before its configuration, which takes about 30 kB.
+const INITIAL_TAIL_SIZE: u64 = 64 * 1024;
+
+/* PUBLIC TYPES AND VARIABLES */
+
+/* PRIVATE FUNCTIONS */
+/**
+ * @brief Parses an amount the slicer lists once per extruder, such as "3.68, 0.00".
+ *
+ * @param value Value of the metadata entry.
+ * @return Option<Vec<f64>> Amount of every extruder, or None if one is not a number.
+ */
+fn parse_amounts(value: &str) -> Option<Vec<f64>> {
+    value
+        .split(',')
+        .map(|amount| amount.trim().parse::<f64>().ok())
+        .collect()
+}
+
+/**
+ * @brief Stores a metadata entry written by the slicer; unknown entries are ignored.
+ *
+ * @param metadata Metadata read so far; later entries replace earlier ones.
+ * @param key Key of the entry, e.g. "filament used [mm]".
+ * @param value Value of the entry, e.g. "1234.56, 0.00".
+ */
+fn apply_entry(metadata: &mut GcodeMetadata, key: &str, value: &str) {
+    match key {
+        "estimated printing time (normal mode)" => {
+            metadata.normal_mode_time = parse_duration(value).or(metadata.normal_mode_time)
+        }
+        "estimated printing time (silent mode)" => {
+            metadata.silent_mode_time = parse_duration(value).or(metadata.silent_mode_time)
+        }
+        "filament used [mm]" => set_amounts(&mut metadata.filament_used_mm, value),
+        "filament used [cm3]" => set_amounts(&mut metadata.filament_used_cm3, value),
+        "filament used [g]" => set_amounts(&mut metadata.filament_used_g, value),
+        "filament cost" => set_amounts(&mut metadata.filament_cost, value),
+        "total filament used [g]" => {
+            metadata.total_filament_used_g = value.parse().ok().or(metadata.total_filament_used_g)
+        }
+        "total filament cost" => {
+            metadata.total_filament_cost = value.parse().ok().or(metadata.total_filament_cost)
+        }
+        "total filament used for wipe tower [g]" => {
+            metadata.wipe_tower_g = value.parse().ok().or(metadata.wipe_tower_g)
+        }
+        "total layers count" => metadata.layer_count = value.parse().ok().or(metadata.layer_count),
+        "max_layer_z" => metadata.max_layer_z = value.parse().ok().or(metadata.max_layer_z),
+        _ => {}
+    }
+}
+
+fn set_amounts(amounts: &mut Vec<f64>, value: &str) {
+    if let Some(parsed) = parse_amounts(value) {
+        *amounts = parsed;
+    }
+}
+
+fn collect_metadata(entries: &[(String, String)]) -> GcodeMetadata {
+    let mut metadata = GcodeMetadata::default();
+    for (key, value) in entries {
+        apply_entry(&mut metadata, key, value);
+    }
+    metadata
+}
+
+/**
+ * @brief Stores an entry of the header OrcaSlicer writes before the first command; unknown
+ *        entries are ignored.
+ *
+ * @param metadata Metadata read so far.
+ * @param key Key of the entry, e.g. "total filament length [mm]".
+ * @param value Value of the entry, e.g. "1234.56".
+ */
+fn apply_orca_header_entry(metadata: &mut GcodeMetadata, key: &str, value: &str) {
+    match key {
+        "total estimated time" => metadata.normal_mode_time = parse_duration(value),
+        "total filament length [mm]" => set_amounts(&mut metadata.filament_used_mm, value),
+        "total filament weight [g]" => metadata.total_filament_used_g = value.parse().ok(),
+        "total layer number" => metadata.layer_count = value.parse().ok(),
+        "max_z_height" => metadata.max_layer_z = value.parse().ok(),
+        _ => {}
+    }
+}
+
+/**
+ * @brief Stores an entry of the header CuraEngine writes before the first command; unknown
+ *        entries are ignored.
+ *
+ * @param metadata Metadata read so far.
+ * @param key Key of the entry, e.g. "Filament used".
+ * @param value Value of the entry, e.g. "1.2m, 0.3m".
+ */
+fn apply_cura_header_entry(metadata: &mut GcodeMetadata, key: &str, value: &str) {
+    match key {
+        "TIME" => metadata.normal_mode_time = value.parse().ok(),
+        "Filament used" => {
+            let metres: Option<Vec<f64>> = value
+                .split(',')
+                .map(|amount| amount.trim().trim_end_matches('m').parse().ok())
+                .collect();
+            if let Some(metres) = metres {
+                metadata.filament_used_mm = metres.iter().map(|m| m * 1000.0).collect();
+            }
+        }
+        "MAXZ" => metadata.max_layer_z = value.parse().ok(),
+        _ => {}
+    }
+}
+
+/**
+ * @brief Reads the metadata of a text G-code file from its end.
+ *
+ * The window read from the end of the file grows until it contains the print time and the
+ * filament length, or covers the whole file.
+ *
+ * @param file Text G-code file.
+ * @param tail_size Size of the first window.
+ * @param header Metadata read from the header of the file, replaced by the entries at its end.
+ * @return io::Result<GcodeMetadata> The metadata found in the last window read.
+ */
+fn read_text_metadata<F: Read + Seek>(
+    file: &mut F,
+    tail_size: u64,
+    header: &GcodeMetadata,
+) -> io::Result<GcodeMetadata> {
+    let mut tail_size = tail_size.max(1);
+    loop {
+        let (entries, whole_file) = text_gcode::read_comment_entries(file, tail_size)?;
+        let mut metadata = header.clone();
+        for (key, value) in &entries {
+            apply_entry(&mut metadata, key, value);
+        }
+        if whole_file || (metadata.print_time().is_some() && metadata.filament_mm().is_some()) {
+            return Ok(metadata);
+        }
+        tail_size = tail_size.saturating_mul(4);
+    }
+}
+
+/**
+ * @brief Reads the time CuraEngine reports after every layer, from the end of a G-code file.
+ *
+ * @param file Text G-code file.
+ * @param tail_size Size of the first window, which grows until a time is found.
+ * @return io::Result<Option<u32>> The time elapsed at the end of the last layer in seconds.
+ */
+fn read_cura_elapsed_time<F: Read + Seek>(file: &mut F, tail_size: u64) -> io::Result<Option<u32>> {
+    let mut tail_size = tail_size.max(1);
+    loop {
+        let (comments, whole_file) = text_gcode::read_tail_comments(file, tail_size)?;
+        let elapsed_time = comments.iter().rev().find_map(|comment| {
+            let seconds: f64 = comment.strip_prefix("TIME_ELAPSED:")?.trim().parse().ok()?;
+            (seconds >= 0.0 && seconds <= f64::from(u32::MAX)).then(|| seconds.round() as u32)
+        });
+        if whole_file || elapsed_time.is_some() {
+            return Ok(elapsed_time);
+        }
+        tail_size = tail_size.saturating_mul(4);
+    }
+}
+
+/**
+ * @brief Adds the layers and the extrusion per feature counted from the moves of a text
+ *        G-code file, keeping the layer count and height the slicer reported.
+ *
+ * @param file Text G-code file.
+ * @param metadata Metadata read from the comments of the file.
+ * @return io::Result<()> An error if the file cannot be read.
+ */
+fn add_move_statistics<F: Read + Seek>(
+    file: &mut F,
+    metadata: &mut GcodeMetadata,
+) -> io::Result<()> {
+    let move_statistics = text_gcode::read_move_statistics(file)?;
+    if move_statistics.layer_count > 0 {
+        metadata.layer_count = metadata.layer_count.or(Some(move_statistics.layer_count));
+    }
+    metadata.max_layer_z = metadata.max_layer_z.or(move_statistics.max_layer_z);
+    metadata.extrusion = Some(move_statistics.extrusion);
+    Ok(())
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Parses a slicer duration such as "1d 3h 4m 5s", "2m 3s" or "45s".
+ *
+ * @param text Duration made of whole days, hours, minutes and seconds.
+ * @return Option<u32> The duration in seconds, or None if it is malformed or too long.
+ */
+pub fn parse_duration(text: &str) -> Option<u32> {
+    let mut parts = text.split_whitespace().peekable();
+    parts.peek()?;
+    parts.try_fold(0u32, |total, part| {
+        let unit_seconds = match part.chars().last()? {
+            'd' => 86_400,
+            'h' => 3_600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        let amount: u32 = part[..part.len() - 1].parse().ok()?;
+        total.checked_add(amount.checked_mul(unit_seconds)?)
+    })
+}
+
+impl GcodeMetadata {
+    /**
+     * @brief Returns the print time orders are priced with.
+     *
+     * @return Option<u32> The normal mode time in seconds, or the silent mode time if the
+     *         slicer only reported that one.
+     */
+    pub fn print_time(&self) -> Option<u32> {
+        self.normal_mode_time.or(self.silent_mode_time)
+    }
+
+    /// Filament length used by all extruders, in millimetres.
+    pub fn filament_mm(&self) -> Option<f64> {
+        (!self.filament_used_mm.is_empty()).then(|| self.filament_used_mm.iter().sum())
+    }
+
+    /// Filament volume used by all extruders, in cm3.
+    pub fn filament_cm3(&self) -> Option<f64> {
+        (!self.filament_used_cm3.is_empty()).then(|| self.filament_used_cm3.iter().sum())
+    }
+
+    /// Filament mass used by all extruders, in grams, preferring the slicer's total.
+    pub fn filament_g(&self) -> Option<f64> {
+        self.total_filament_used_g.or_else(|| {
+            (!self.filament_used_g.is_empty()).then(|| self.filament_used_g.iter().sum())
+        })
+    }
+
+    /// Filament cost of all extruders, preferring the slicer's total.
+    pub fn filament_total_cost(&self) -> Option<f64> {
+        self.total_filament_cost
+            .or_else(|| (!self.filament_cost.is_empty()).then(|| self.filament_cost.iter().sum()))
+    }
+}
+
+/**
+ * @brief Reads the print time, filament usage and print statistics from a G-code file written
+ *        by PrusaSlicer.
+ *
+ * Both text G-code and binary G-code are read; the format is recognized by the content of the
+ * file, not by its extension. The statistics of text files are read from their end. The moves
+ * of text files are then gone through once for the extrusion per feature, and for the layer
+ * count and height if the slicer did not report them. The G-code blocks of binary files are not
+ * decoded, so their extrusion per feature is unknown.
+ *
+ * @param gcode_path Path of the G-code file.
+ * @return io::Result<GcodeMetadata> The metadata found, with missing entries left empty, or an
+ *         error if the file cannot be read or is a damaged binary G-code file.
+ */
+pub fn read_gcode_metadata(gcode_path: &Path) -> io::Result<GcodeMetadata> {
+    let mut file = File::open(gcode_path)?;
+    let mut magic = [0; 4];
+    let is_binary = file.read_exact(&mut magic).is_ok() && &magic == binary_gcode::MAGIC;
+    if is_binary {
+        let entries = binary_gcode::read_metadata_entries(&mut file)?;
+        return Ok(collect_metadata(&entries));
+    }
+    file.seek(SeekFrom::Start(0))?;
+    let mut metadata = read_text_metadata(&mut file, INITIAL_TAIL_SIZE, &GcodeMetadata::default())?;
+    add_move_statistics(&mut file, &mut metadata)?;
+    Ok(metadata)
+}
+
+/**
+ * @brief Reads the print time, filament usage and print statistics from a G-code file written
+ *        by OrcaSlicer.
+ *
+ * OrcaSlicer writes the same statistics as PrusaSlicer at the end of the file, and a summary
+ * in its header block. The entries at the end are preferred; the header fills in what they
+ * lack.
+ *
+ * @param gcode_path Path of the text G-code file.
+ * @return io::Result<GcodeMetadata> The metadata found, with missing entries left empty, or an
+ *         error if the file cannot be read.
+ */
+pub fn read_orca_gcode_metadata(gcode_path: &Path) -> io::Result<GcodeMetadata> {
+    let mut file = File::open(gcode_path)?;
+    let mut header = GcodeMetadata::default();
+    for (key, value) in text_gcode::read_header_entries(&mut file)? {
+        apply_orca_header_entry(&mut header, &key, &value);
+    }
+    let mut metadata = read_text_metadata(&mut file, INITIAL_TAIL_SIZE, &header)?;
+    add_move_statistics(&mut file, &mut metadata)?;
+    Ok(metadata)
+}
+
+/**
+ * @brief Reads the print time, filament usage and print statistics from a G-code file written
+ *        by CuraEngine.
+ *
+ * The print time is the time elapsed at the end of the last layer, since CuraEngine run from
+ * the command line may leave a placeholder in the ";TIME:" header. When the header reports no
+ * filament, the filament length is added up from the moves.
+ *
+ * @param gcode_path Path of the text G-code file.
+ * @return io::Result<GcodeMetadata> The metadata found, with missing entries left empty, or an
+ *         error if the file cannot be read.
+ */
+pub fn read_cura_gcode_metadata(gcode_path: &Path) -> io::Result<GcodeMetadata> {
+    let mut file = File::open(gcode_path)?;
+    let mut metadata = GcodeMetadata::default();
+    for (key, value) in text_gcode::read_header_entries(&mut file)? {
+        apply_cura_header_entry(&mut metadata, &key, &value);
+    }
+    if let Some(elapsed_time) = read_cura_elapsed_time(&mut file, INITIAL_TAIL_SIZE)? {
+        metadata.normal_mode_time = Some(elapsed_time);
+    }
+    add_move_statistics(&mut file, &mut metadata)?;
+    if metadata.filament_mm().unwrap_or_default() <= 0.0 {
+        metadata.filament_used_mm = metadata
+            .extrusion
+            .iter()
+            .map(|e| e.perimeter_mm + e.infill_mm + e.support_mm + e.wipe_tower_mm + e.other_mm)
+            .filter(|total| *total > 0.0)
+            .collect();
+    }
+    Ok(metadata)
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_utils::global_types::FeatureExtrusion;
+    use std::io::Cursor;
+
+    // The fixtures are written by hand in the format of the slicer version in their name, they
+    // are not output of that slicer
+    fn sample_file(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("test_files/gcode")
+            .join(name)
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("1d 3h 4m 5s"), Some(97_445));
+        assert_eq!(parse_duration("2m 3s"), Some(123));
+        assert_eq!(parse_duration("45s"), Some(45));
+        assert_eq!(parse_duration("1h"), Some(3_600));
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("1x 2s"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration("99999d"), None);
+    }
+
+    #[test]
+    fn test_multi_day_multi_material_gcode() {
+        let metadata = read_gcode_metadata(&sample_file(
+            "handwritten_prusaslicer_2_7_mmu_multi_day.gcode",
+        ))
+        .unwrap();
+        assert_eq!(metadata.normal_mode_time, Some(97_445));
+        assert_eq!(metadata.silent_mode_time, Some(105_160));
+        assert_eq!(metadata.print_time(), Some(97_445));
+        assert_eq!(metadata.filament_used_mm, [152_340.27, 10_234.55]);
+        assert!((metadata.filament_mm().unwrap() - 162_574.82).abs() < 1e-6);
+        assert!((metadata.filament_cm3().unwrap() - 391.04).abs() < 1e-6);
+        // The total is the sum of the extruders, whose usage already covers the wipe tower
+        assert_eq!(metadata.filament_g(), Some(495.88));
+        assert_eq!(metadata.filament_cost, [11.82, 0.64]);
+        assert_eq!(metadata.filament_total_cost(), Some(12.46));
+        assert_eq!(metadata.wipe_tower_g, Some(12.08));
+    }
+
+    #[test]
+    fn test_gcode_without_filament_mass() {
+        let metadata = read_gcode_metadata(&sample_file(
+            "handwritten_prusaslicer_2_5_no_filament_mass.gcode",
+        ))
+        .unwrap();
+        assert_eq!(metadata.print_time(), Some(2_712));
+        assert_eq!(metadata.filament_mm(), Some(2_486.91));
+        assert_eq!(metadata.filament_cm3(), Some(5.98));
+        assert_eq!(metadata.filament_g(), None);
+        assert_eq!(metadata.filament_total_cost(), None);
+        assert_eq!(metadata.silent_mode_time, None);
+    }
+
+    #[test]
+    fn test_gcode_with_zero_filament_mass() {
+        // Profiles without a density report a mass of zero, which prices ignore
+        let metadata = read_text_metadata(
+            &mut Cursor::new(
+                "G1 X1 E.5\n\
+                 ; filament used [mm] = 2486.91\n\
+                 ; filament used [cm3] = 5.98\n\
+                 ; filament used [g] = 0.00\n\
+                 ; total filament used [g] = 0.00\n\
+                 ; estimated printing time (normal mode) = 45m 12s\n",
+            ),
+            4096,
+            &GcodeMetadata::default(),
+        )
+        .unwrap();
+        assert_eq!(metadata.filament_mm(), Some(2_486.91));
+        assert_eq!(metadata.filament_g(), Some(0.0));
+    }
+
+    #[test]
+    fn test_layer_and_feature_statistics() {
+        let metadata = read_gcode_metadata(&sample_file(
+            "handwritten_prusaslicer_2_7_feature_moves.gcode",
+        ))
+        .unwrap();
+        // The reported layer count is preferred, the height is taken from the layer markers
+        assert_eq!(metadata.layer_count, Some(2));
+        assert_eq!(metadata.max_layer_z, Some(0.35));
+        let extrusion = metadata.extrusion.unwrap();
+        let expected = FeatureExtrusion {
+            perimeter_mm: 11.0,
+            infill_mm: 7.0,
+            support_mm: 3.0,
+            wipe_tower_mm: 4.0,
+            other_mm: 0.5,
+        };
+        for (actual, expected) in [
+            (extrusion.perimeter_mm, expected.perimeter_mm),
+            (extrusion.infill_mm, expected.infill_mm),
+            (extrusion.support_mm, expected.support_mm),
+            (extrusion.wipe_tower_mm, expected.wipe_tower_mm),
+            (extrusion.other_mm, expected.other_mm),
+        ] {
+            assert!(
+                (actual - expected).abs() < 1e-6,
+                "{} != {}",
+                actual,
+                expected
+            );
+        }
+
+        // Counted from the layer markers of a file with absolute extrusion
+        let metadata = read_gcode_metadata(&sample_file(
+            "handwritten_prusaslicer_2_5_absolute_extrusion.gcode",
+        ))
+        .unwrap();
+        assert_eq!(metadata.layer_count, Some(3));
+        assert_eq!(metadata.max_layer_z, Some(0.6));
+        let extrusion = metadata.extrusion.unwrap();
+        assert!((extrusion.perimeter_mm - 25.0).abs() < 1e-6);
+        assert_eq!(extrusion.infill_mm, 0.0);
+    }
+
+    #[test]
+    fn test_binary_gcode() {
+        let metadata =
+            read_gcode_metadata(&sample_file("handwritten_prusaslicer_2_7_mk4.bgcode")).unwrap();
+        assert_eq!(metadata.normal_mode_time, Some(5_462));
+        assert_eq!(metadata.silent_mode_time, Some(5_678));
+        assert_eq!(metadata.filament_mm(), Some(3_281.06));
+        assert_eq!(metadata.filament_cm3(), Some(7.89));
+        assert_eq!(metadata.filament_g(), Some(9.79));
+        assert_eq!(metadata.filament_total_cost(), Some(0.25));
+        assert_eq!(metadata.max_layer_z, Some(24.2));
+        assert_eq!(metadata.extrusion, None);
+    }
+
+    #[test]
+    fn test_orcaslicer_gcode() {
+        let metadata =
+            read_orca_gcode_metadata(&sample_file("handwritten_orcaslicer_2_0_mk4.gcode")).unwrap();
+        assert_eq!(metadata.print_time(), Some(7_210));
+        assert_eq!(metadata.filament_mm(), Some(2_051.2));
+        assert_eq!(metadata.filament_g(), Some(6.12));
+        assert_eq!(metadata.filament_total_cost(), Some(0.15));
+        assert_eq!(metadata.layer_count, Some(3));
+        assert_eq!(metadata.max_layer_z, Some(0.6));
+        let extrusion = metadata.extrusion.unwrap();
+        assert!((extrusion.perimeter_mm - 6.0).abs() < 1e-6);
+        assert!((extrusion.infill_mm - 6.0).abs() < 1e-6);
+        assert!((extrusion.support_mm - 1.0).abs() < 1e-6);
+        assert!((extrusion.wipe_tower_mm - 1.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_orcaslicer_header_fills_in_missing_statistics() {
+        let path = std::env::temp_dir().join(format!("orca_header_{}.gcode", std::process::id()));
+        std::fs::write(
+            &path,
+            "; HEADER_BLOCK_START\n\
+             ; model printing time: 58m 2s; total estimated time: 1h 0m 10s\n\
+             ; total filament length [mm] : 1025.60\n\
+             ; total filament weight [g] : 3.06\n\
+             ; HEADER_BLOCK_END\n\
+             G1 X1 E.5\n",
+        )
+        .unwrap();
+        let metadata = read_orca_gcode_metadata(&path).unwrap();
+        assert_eq!(metadata.print_time(), Some(3_610));
+        assert_eq!(metadata.filament_mm(), Some(1_025.6));
+        assert_eq!(metadata.filament_g(), Some(3.06));
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_curaengine_gcode() {
+        let metadata =
+            read_cura_gcode_metadata(&sample_file("handwritten_curaengine_5_7_marlin.gcode"))
+                .unwrap();
+        // The ";TIME:6666" placeholder is replaced by the time elapsed after the last layer
+        assert_eq!(metadata.print_time(), Some(3_610));
+        // The header reports no filament, so it is added up from the moves
+        assert!((metadata.filament_mm().unwrap() - 27.4).abs() < 1e-6);
+        assert_eq!(metadata.filament_g(), None);
+        assert_eq!(metadata.layer_count, Some(3));
+        assert_eq!(metadata.max_layer_z, Some(0.6));
+        let extrusion = metadata.extrusion.unwrap();
+        assert!((extrusion.perimeter_mm - 12.0).abs() < 1e-6);
+        assert!((extrusion.infill_mm - 12.0).abs() < 1e-6);
+        assert!((extrusion.support_mm - 3.0).abs() < 1e-6);
+        assert!((extrusion.other_mm - 0.4).abs() < 1e-6);
+
+        let mut header = GcodeMetadata::default();
+        apply_cura_header_entry(&mut header, "Filament used", "1.2m, 0.3m");
+        assert_eq!(header.filament_used_mm, [1_200.0, 300.0]);
+    }
+
+    #[test]
+    fn test_tail_window_grows_until_the_statistics_are_found() {
+        let content = std::fs::read(sample_file(
+            "handwritten_prusaslicer_2_7_mmu_multi_day.gcode",
+        ))
+        .unwrap();
+        let metadata =
+            read_text_metadata(&mut Cursor::new(&content), 100, &GcodeMetadata::default()).unwrap();
+        assert_eq!(metadata.print_time(), Some(97_445));
+        assert_eq!(metadata.filament_used_mm.len(), 2);
+
+        // A file without statistics is read completely and yields empty metadata
+        let metadata = read_text_metadata(
+            &mut Cursor::new("G1 X1\nG1 X2\n"),
+            4,
+            &GcodeMetadata::default(),
+        )
+        .unwrap();
+        assert_eq!(metadata, GcodeMetadata::default());
+    }
+}
@@ -0,0 +1,7 @@
+// PUBLIC MODULES
+pub mod metadata_reader;
+pub use metadata_reader::*;
+
+// PRIVATE MODULES
+mod binary_gcode;
+mod text_gcode;
@@ -0,0 +1,326 @@
+/* IMPORTS FROM LIBRARIES */
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::FeatureExtrusion;
+
+/* PRIVATE TYPES AND VARIABLES */
+
+/* PUBLIC TYPES AND VARIABLES */
+/// Statistics gathered from the moves and layer markers of a text G-code file.
+#[derive(Debug, Default, PartialEq)]
+pub struct MoveStatistics {
+    /// Number of ";LAYER_CHANGE" markers, or ";LAYER:" markers of CuraEngine.
+    pub layer_count: u32,
+    /// Highest ";Z:" marker.
+    pub max_layer_z: Option<f64>,
+    pub extrusion: FeatureExtrusion,
+}
+
+/* PRIVATE FUNCTIONS */
+/**
+ * @brief Splits a G-code comment such as "filament used [mm] = 1234.56" into its key and value.
+ *
+ * @param comment Comment without its ";".
+ * @param separator Character between the key and the value.
+ * @return Option<(String, String)> The trimmed key and value, if the comment is such an entry.
+ */
+fn parse_comment_entry(comment: &str, separator: char) -> Option<(String, String)> {
+    let (key, value) = comment.split_once(separator)?;
+    let key = key.trim();
+    (!key.is_empty()).then(|| (key.to_string(), value.trim().to_string()))
+}
+
+/**
+ * @brief Finds the counter of the feature a ";TYPE:" marker starts, e.g. "External perimeter"
+ *        (PrusaSlicer), "Outer wall" (OrcaSlicer) or "WALL-OUTER" (CuraEngine).
+ *
+ * @param extrusion Counters of all features.
+ * @param feature Name of the feature as written by the slicer.
+ * @return &mut f64 Counter the extrusion of the feature is added to.
+ */
+fn feature_counter<'a>(extrusion: &'a mut FeatureExtrusion, feature: &str) -> &'a mut f64 {
+    let feature = feature.to_lowercase().replace(['-', '_'], " ");
+    if feature.contains("perimeter") || feature.contains("wall") {
+        &mut extrusion.perimeter_mm
+    } else if feature.contains("support") {
+        &mut extrusion.support_mm
+    } else if feature.contains("wipe tower") || feature.contains("prime tower") {
+        &mut extrusion.wipe_tower_mm
+    } else if ["fill", "skin", "surface", "bridge", "ironing"]
+        .iter()
+        .any(|name| feature.contains(name))
+    {
+        &mut extrusion.infill_mm
+    } else {
+        &mut extrusion.other_mm
+    }
+}
+
+/**
+ * @brief Reads the value of a parameter of a G-code command, e.g. E of "G1 X1 E.05".
+ *
+ * @param words Words of the command, without its comment.
+ * @param letter Letter of the parameter.
+ * @return Option<f64> Value of the parameter, if it is given.
+ */
+fn parameter(words: &[&str], letter: char) -> Option<f64> {
+    words
+        .iter()
+        .find_map(|word| word.strip_prefix(letter)?.parse().ok())
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Reads the comments at the end of a text G-code file.
+ *
+ * Slicers write the print statistics into the comment block after the last move, so only the
+ * last `tail_size` bytes are read. A line cut by the start of that window is skipped.
+ *
+ * @param file G-code file, at any position.
+ * @param tail_size Number of bytes to read from the end of the file.
+ * @return io::Result<(Vec<String>, bool)> The trimmed comments without their ";", in file
+ *         order, and whether the window covered the whole file.
+ */
+pub fn read_tail_comments<F: Read + Seek>(
+    file: &mut F,
+    tail_size: u64,
+) -> io::Result<(Vec<String>, bool)> {
+    let file_size = file.seek(SeekFrom::End(0))?;
+    let start = file_size.saturating_sub(tail_size);
+    file.seek(SeekFrom::Start(start))?;
+    let mut reader = BufReader::new(file);
+    let mut line = Vec::new();
+    if start > 0 {
+        reader.read_until(b'\n', &mut line)?;
+    }
+    let mut comments = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        if let Some(comment) = String::from_utf8_lossy(&line).trim().strip_prefix(';') {
+            comments.push(comment.trim().to_string());
+        }
+    }
+    Ok((comments, start == 0))
+}
+
+/**
+ * @brief Reads the "; key = value" comments from the end of a text G-code file.
+ *
+ * @param file G-code file, at any position.
+ * @param tail_size Number of bytes to read from the end of the file.
+ * @return io::Result<(Vec<(String, String)>, bool)> The comments in file order, and whether
+ *         the window covered the whole file.
+ */
+pub fn read_comment_entries<F: Read + Seek>(
+    file: &mut F,
+    tail_size: u64,
+) -> io::Result<(Vec<(String, String)>, bool)> {
+    let (comments, whole_file) = read_tail_comments(file, tail_size)?;
+    let entries = comments
+        .iter()
+        .filter_map(|comment| parse_comment_entry(comment, '='))
+        .collect();
+    Ok((entries, whole_file))
+}
+
+/**
+ * @brief Reads the "key: value" comments of the header a slicer writes before the first
+ *        command, e.g. ";TIME:3723" (CuraEngine) or "; total layer number: 120" (OrcaSlicer).
+ *
+ * Several entries on one line, separated by ";", are split apart.
+ *
+ * @param file G-code file, at any position.
+ * @return io::Result<Vec<(String, String)>> The trimmed keys and values, in file order.
+ */
+pub fn read_header_entries<F: Read + Seek>(file: &mut F) -> io::Result<Vec<(String, String)>> {
+    file.seek(SeekFrom::Start(0))?;
+    let mut reader = BufReader::new(file);
+    let mut entries = Vec::new();
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        let line = String::from_utf8_lossy(&line);
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some(comment) = line.strip_prefix(';') else {
+            break;
+        };
+        entries.extend(
+            comment
+                .split(';')
+                .filter_map(|part| parse_comment_entry(part, ':')),
+        );
+    }
+    Ok(entries)
+}
+
+/**
+ * @brief Goes through all moves of a text G-code file and adds up the filament extruded for
+ *        every kind of feature, as marked by ";TYPE:" comments.
+ *
+ * Only moves that extrude while moving along X or Y are counted, so retractions and the
+ * matching unretractions cancel out. Relative (M83) and absolute (M82) extrusion are supported.
+ *
+ * @param file G-code file, at any position.
+ * @return io::Result<MoveStatistics> Layers and extrusion found in the file.
+ */
+pub fn read_move_statistics<F: Read + Seek>(file: &mut F) -> io::Result<MoveStatistics> {
+    file.seek(SeekFrom::Start(0))?;
+    let mut reader = BufReader::new(file);
+    let mut statistics = MoveStatistics::default();
+    let mut feature = String::new();
+    let mut relative_extrusion = false;
+    let mut last_e = 0.0;
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        let line = String::from_utf8_lossy(&line);
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix(';') {
+            if comment == "LAYER_CHANGE" || comment.starts_with("LAYER:") {
+                statistics.layer_count += 1;
+            } else if let Some(z) = comment.strip_prefix("Z:").and_then(|z| z.parse().ok()) {
+                statistics.max_layer_z =
+                    Some(statistics.max_layer_z.map_or(z, |max: f64| max.max(z)));
+            } else if let Some(name) = comment.strip_prefix("TYPE:") {
+                feature = name.to_string();
+            }
+            continue;
+        }
+        let command = line.split(';').next().unwrap_or_default();
+        let words: Vec<&str> = command.split_whitespace().collect();
+        match words.first().copied() {
+            Some("M82") => relative_extrusion = false,
+            Some("M83") => relative_extrusion = true,
+            Some("G92") => last_e = parameter(&words, 'E').unwrap_or(last_e),
+            Some("G0" | "G1" | "G2" | "G3") => {
+                let Some(e) = parameter(&words, 'E') else {
+                    continue;
+                };
+                let extruded = if relative_extrusion { e } else { e - last_e };
+                if !relative_extrusion {
+                    last_e = e;
+                }
+                let moves_xy = words
+                    .iter()
+                    .any(|word| word.starts_with('X') || word.starts_with('Y'));
+                if moves_xy && extruded > 0.0 {
+                    *feature_counter(&mut statistics.extrusion, &feature) += extruded;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(statistics)
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_only_complete_comment_lines_of_the_tail_are_read() {
+        let gcode =
+            "G1 X1\n; first = 1\nG1 X2 ; move\n; filament used [mm] = 12.5, 3\n;TYPE:Custom\n";
+        let (entries, whole_file) = read_comment_entries(&mut Cursor::new(gcode), 1024).unwrap();
+        assert!(whole_file);
+        assert_eq!(
+            entries,
+            [
+                ("first".to_string(), "1".to_string()),
+                ("filament used [mm]".to_string(), "12.5, 3".to_string()),
+            ]
+        );
+
+        // The window starts inside "; first = 1", which is skipped
+        let tail_size = gcode.len() as u64 - 8;
+        let (entries, whole_file) =
+            read_comment_entries(&mut Cursor::new(gcode), tail_size).unwrap();
+        assert!(!whole_file);
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn test_extrusion_is_counted_per_feature() {
+        let gcode = "M83\n;LAYER_CHANGE\n;Z:0.2\n;TYPE:External perimeter\n\
+                     G1 X1 Y1 E.5\nG1 X2 E.25 ; comment\nG1 X1 E-.1\nG1 E-.7\nG1 E.8\n\
+                     ;TYPE:Support material interface\nG1 Y2 E1\n\
+                     ;LAYER_CHANGE\n;Z:0.4\nM82\nG92 E0\n;TYPE:Bridge infill\n\
+                     G1 X3 E2\nG1 X4 E3.5\nG1 E2.7\nG1 E3.5\nG1 X5 E4\n;TYPE:Custom\nG1 Z5\n";
+        let statistics = read_move_statistics(&mut Cursor::new(gcode)).unwrap();
+        assert_eq!(statistics.layer_count, 2);
+        assert_eq!(statistics.max_layer_z, Some(0.4));
+        assert_eq!(
+            statistics.extrusion,
+            FeatureExtrusion {
+                perimeter_mm: 0.75,
+                infill_mm: 4.0,
+                support_mm: 1.0,
+                wipe_tower_mm: 0.0,
+                other_mm: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_header_entries_are_read_until_the_first_command() {
+        let gcode = ";FLAVOR:Marlin\n;TIME:3723\n\n;Filament used: 1.2m, 0.3m\n\
+                     ; model printing time: 1h 2m 3s; total estimated time: 1h 5m 0s\n\
+                     M140 S60\n;LAYER_COUNT:12\n";
+        let entries = read_header_entries(&mut Cursor::new(gcode)).unwrap();
+        let keys: Vec<&str> = entries.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(
+            keys,
+            [
+                "FLAVOR",
+                "TIME",
+                "Filament used",
+                "model printing time",
+                "total estimated time"
+            ]
+        );
+        assert_eq!(entries[4].1, "1h 5m 0s");
+    }
+
+    #[test]
+    fn test_features_of_other_slicers_are_recognized() {
+        let mut extrusion = FeatureExtrusion::default();
+        for (feature, amount) in [
+            ("Outer wall", 1.0),
+            ("WALL-INNER", 2.0),
+            ("Sparse infill", 4.0),
+            ("SKIN", 8.0),
+            ("Top surface", 16.0),
+            ("SUPPORT-INTERFACE", 32.0),
+            ("Prime tower", 64.0),
+            ("PRIME-TOWER", 128.0),
+            ("SKIRT", 256.0),
+        ] {
+            *feature_counter(&mut extrusion, feature) += amount;
+        }
+        assert_eq!(
+            extrusion,
+            FeatureExtrusion {
+                perimeter_mm: 3.0,
+                infill_mm: 28.0,
+                support_mm: 32.0,
+                wipe_tower_mm: 192.0,
+                other_mm: 256.0,
+            }
+        );
+    }
+}
@@ -2,6 +2,8 @@
 mod api;
 mod common_utils; // Add this line to declare the module
 mod database_handler;
+mod gcode_metadata;
+mod model_inspection;
 mod prusa_slicer_interface;
 
 /* IMPORTS FROM LIBRARIES */
@@ -11,12 +13,18 @@ use clap::Parser;
 
 /* IMPORTS FROM OTHER MODULES */
 use api::{
-    app_init_status_handler, eval_result_websocket_handler, get_completed_orders_handler,
-    get_orders_handler, initialize_api_handler, modify_completed_order_handler,
-    modify_order_handler,
+    app_init_status_handler, eval_result_websocket_handler, evaluate_order_handler,
+    get_completed_orders_handler, get_materials_handler, get_orders_handler,
+    get_pricing_parameters_handler, get_pricing_versions_handler, initialize_api_handler,
+    initialize_slicing_job_pool, modify_completed_order_handler, modify_order_handler,
+    pricing_versions_diff_handler, reload_pricing_handler, reprice_order_handler,
+    simulate_price_handler, update_pricing_parameters_handler,
+};
+use database_handler::{add_pricing_version_to_db, initialize_db};
+use prusa_slicer_interface::{
+    initialize_prusa_slicer_if, set_pricing_version_store, set_slicing_timeout,
+    watch_printer_configuration,
 };
-use database_handler::initialize_db;
-use prusa_slicer_interface::initialize_prusa_slicer_if;
 
 /* PRIVATE TYPES AND VARIABLES */
 /// Command-line arguments
@@ -30,6 +38,30 @@ struct Args {
         default_value = "data_files/print_price_evaluator_config.json"
     )]
     app_params: String,
+    #[clap(
+        long = "slicing-workers",
+        help = "Number of models sliced concurrently",
+        default_value = "2"
+    )]
+    slicing_workers: usize,
+    #[clap(
+        long = "slicing-queue-capacity",
+        help = "Maximum number of models waiting to be sliced",
+        default_value = "32"
+    )]
+    slicing_queue_capacity: usize,
+    #[clap(
+        long = "slicing-timeout",
+        help = "Time in seconds after which a running slicer is killed",
+        default_value = "600"
+    )]
+    slicing_timeout: u64,
+    #[clap(
+        long = "config-poll-interval",
+        help = "Time in seconds between checks of the price parameters file for changes, 0 to never reload it automatically",
+        default_value = "2"
+    )]
+    config_poll_interval: u64,
 }
 
 /* PUBLIC TYPES AND VARIABLES */
@@ -57,8 +89,14 @@ fn initialize_modules_with_cmd_arguments(args: Args) {
     let db_name = "data_files/price_evaluator_database.db"; // Hardcoded for now, might be part of config later
     let ws_path = get_current_working_directory();
     initialize_db(&db_name);
+    set_pricing_version_store(add_pricing_version_to_db);
     initialize_prusa_slicer_if(&ws_path, &print_price_evaluator_config_path)
         .expect("Failed to initialize Prusa Slicer interface");
+    set_slicing_timeout(std::time::Duration::from_secs(args.slicing_timeout));
+    if args.config_poll_interval > 0 {
+        watch_printer_configuration(std::time::Duration::from_secs(args.config_poll_interval));
+    }
+    initialize_slicing_job_pool(args.slicing_workers, args.slicing_queue_capacity);
     initialize_api_handler(true);
 }
 
@@ -72,6 +110,7 @@ async fn main() -> std::io::Result<()> {
     HttpServer::new(|| {
         App::new()
             .route("/api/backendstatus", web::get().to(app_init_status_handler))
+            .route("/api/materials", web::get().to(get_materials_handler))
             .route("/api/orders", web::get().to(get_orders_handler))
             .route("/api/orders/modify", web::put().to(modify_order_handler))
             .route(
@@ -86,6 +125,35 @@ async fn main() -> std::io::Result<()> {
                 "/api/websocket_evaluation",
                 web::get().to(eval_result_websocket_handler),
             ) // Add WebSocket route
+            .route("/api/evaluate", web::post().to(evaluate_order_handler))
+            .route(
+                "/api/pricing/simulate",
+                web::post().to(simulate_price_handler),
+            )
+            .route(
+                "/api/admin/pricing",
+                web::get().to(get_pricing_parameters_handler),
+            )
+            .route(
+                "/api/admin/pricing",
+                web::put().to(update_pricing_parameters_handler),
+            )
+            .route(
+                "/api/admin/pricing/reload",
+                web::post().to(reload_pricing_handler),
+            )
+            .route(
+                "/api/admin/pricing/versions",
+                web::get().to(get_pricing_versions_handler),
+            )
+            .route(
+                "/api/admin/pricing/versions/diff",
+                web::get().to(pricing_versions_diff_handler),
+            )
+            .route(
+                "/api/admin/orders/reprice",
+                web::post().to(reprice_order_handler),
+            )
             // The index page has to be initialized after API endpoints
             .service(fs::Files::new("/", "./src/frontend").index_file("index.html"))
     })
@@ -0,0 +1,103 @@
+/* IMPORTS FROM LIBRARIES */
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::{BuildVolumeFit, ModelStatistics};
+
+/* PRIVATE TYPES AND VARIABLES */
+
+/* PUBLIC TYPES AND VARIABLES */
+
+/* PRIVATE FUNCTIONS */
+fn fits_within(model_size: &[f64; 3], build_volume: &[f64; 3]) -> bool {
+    model_size
+        .iter()
+        .zip(build_volume)
+        .all(|(size, limit)| size <= limit)
+}
+
+fn sorted(mut dimensions: [f64; 3]) -> [f64; 3] {
+    dimensions.sort_by(f64::total_cmp);
+    dimensions
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Checks whether a model fits in the build volume of the printer.
+ *
+ * Besides the uploaded orientation, every orientation reachable by rotating the model in
+ * 90 degree steps is considered. Pairing the sorted model dimensions with the sorted build
+ * volume dimensions is the best of those orientations.
+ *
+ * @param model_statistics Geometry of the model.
+ * @param build_volume Printable size along X, Y and Z in millimetres.
+ * @return BuildVolumeFit Whether the model fits, and what would make it fit.
+ */
+pub fn check_build_volume_fit(
+    model_statistics: &ModelStatistics,
+    build_volume: [f64; 3],
+) -> BuildVolumeFit {
+    let mut model_size = [0.0; 3];
+    for (axis, size) in model_size.iter_mut().enumerate() {
+        *size = model_statistics.bounding_box_max[axis] - model_statistics.bounding_box_min[axis];
+    }
+    let max_uniform_scale = model_size
+        .iter()
+        .zip(&build_volume)
+        .filter(|(size, _)| **size > 0.0)
+        .map(|(size, limit)| limit / size)
+        .fold(f64::INFINITY, f64::min);
+    BuildVolumeFit {
+        model_size,
+        build_volume,
+        fits: fits_within(&model_size, &build_volume),
+        fits_when_rotated: fits_within(&sorted(model_size), &sorted(build_volume)),
+        max_uniform_scale,
+    }
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn box_statistics(size: [f64; 3]) -> ModelStatistics {
+        ModelStatistics {
+            triangle_count: 12,
+            bounding_box_min: [-5.0, -5.0, 0.0],
+            bounding_box_max: [size[0] - 5.0, size[1] - 5.0, size[2]],
+            volume: size.iter().product(),
+            surface_area: 0.0,
+            is_watertight: true,
+        }
+    }
+
+    #[test]
+    fn test_model_inside_build_volume_fits() {
+        let fit =
+            check_build_volume_fit(&box_statistics([100.0, 50.0, 20.0]), [250.0, 210.0, 210.0]);
+        assert!(fit.fits);
+        assert!(fit.fits_when_rotated);
+        assert_eq!(fit.model_size, [100.0, 50.0, 20.0]);
+        assert_eq!(fit.max_uniform_scale, 2.5);
+    }
+
+    #[test]
+    fn test_tall_model_fits_when_laid_down() {
+        let fit =
+            check_build_volume_fit(&box_statistics([20.0, 20.0, 240.0]), [250.0, 210.0, 210.0]);
+        assert!(!fit.fits);
+        assert!(fit.fits_when_rotated);
+        assert_eq!(fit.max_uniform_scale, 210.0 / 240.0);
+    }
+
+    #[test]
+    fn test_oversized_model_needs_scaling() {
+        let fit = check_build_volume_fit(
+            &box_statistics([500.0, 100.0, 100.0]),
+            [250.0, 210.0, 210.0],
+        );
+        assert!(!fit.fits);
+        assert!(!fit.fits_when_rotated);
+        assert_eq!(fit.max_uniform_scale, 0.5);
+    }
+}
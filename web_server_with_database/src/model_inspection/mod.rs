@@ -0,0 +1,5 @@
+// PUBLIC MODULES
+pub mod build_volume_fit;
+pub mod stl_inspection;
+pub use build_volume_fit::*;
+pub use stl_inspection::*;
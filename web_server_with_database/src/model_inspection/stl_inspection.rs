@@ -0,0 +1,314 @@
+/* IMPORTS FROM LIBRARIES */
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::ModelStatistics;
+
+/* PRIVATE TYPES AND VARIABLES */
+const BINARY_HEADER_SIZE: usize = 80;
+const BINARY_TRIANGLE_SIZE: usize = 50;
+
+type Vertex = [f32; 3];
+type Triangle = [Vertex; 3];
+
+/* PUBLIC TYPES AND VARIABLES */
+
+/* PRIVATE FUNCTIONS */
+fn invalid_model(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_vertex(bytes: &[u8]) -> Vertex {
+    let coordinate = |i: usize| f32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+    [coordinate(0), coordinate(1), coordinate(2)]
+}
+
+/**
+ * @brief Recognizes a binary STL by its size matching the triangle count in the header.
+ *
+ * Many exporters start binary files with "solid" too, so the size is the only reliable hint.
+ *
+ * @param content Content of the file.
+ * @return Option<usize> Number of triangles if the content is a binary STL.
+ */
+fn binary_triangle_count(content: &[u8]) -> Option<usize> {
+    let count_bytes = content.get(BINARY_HEADER_SIZE..BINARY_HEADER_SIZE + 4)?;
+    let count = u32::from_le_bytes(count_bytes.try_into().unwrap()) as usize;
+    let expected_size = count
+        .checked_mul(BINARY_TRIANGLE_SIZE)?
+        .checked_add(BINARY_HEADER_SIZE + 4)?;
+    (expected_size == content.len()).then_some(count)
+}
+
+fn parse_binary_stl(content: &[u8], count: usize) -> Vec<Triangle> {
+    content[BINARY_HEADER_SIZE + 4..]
+        .chunks_exact(BINARY_TRIANGLE_SIZE)
+        .take(count)
+        // Skip the normal vector, which slicers recompute anyway
+        .map(|t| {
+            [
+                read_vertex(&t[12..24]),
+                read_vertex(&t[24..36]),
+                read_vertex(&t[36..48]),
+            ]
+        })
+        .collect()
+}
+
+fn parse_ascii_stl(text: &str) -> io::Result<Vec<Triangle>> {
+    let mut tokens = text.split_whitespace();
+    if tokens.next() != Some("solid") {
+        return Err(invalid_model(
+            "The file is neither a binary nor an ASCII STL".to_string(),
+        ));
+    }
+    let mut triangles = Vec::new();
+    let mut facet: Vec<Vertex> = Vec::with_capacity(3);
+    while let Some(token) = tokens.next() {
+        match token {
+            "facet" => facet.clear(),
+            "vertex" => {
+                let mut vertex = [0.0; 3];
+                for coordinate in vertex.iter_mut() {
+                    *coordinate = tokens
+                        .next()
+                        .and_then(|t| t.parse::<f32>().ok())
+                        .ok_or_else(|| {
+                            invalid_model(format!(
+                                "Malformed vertex in facet {} of the ASCII STL",
+                                triangles.len() + 1
+                            ))
+                        })?;
+                }
+                facet.push(vertex);
+            }
+            "endfacet" => {
+                if facet.len() != 3 {
+                    return Err(invalid_model(format!(
+                        "Facet {} of the ASCII STL has {} vertices instead of 3",
+                        triangles.len() + 1,
+                        facet.len()
+                    )));
+                }
+                triangles.push([facet[0], facet[1], facet[2]]);
+            }
+            _ => {}
+        }
+    }
+    Ok(triangles)
+}
+
+fn vertex_key(vertex: &Vertex) -> [u32; 3] {
+    // Adding 0.0 turns -0.0 into 0.0, so both map to the same vertex
+    vertex.map(|c| (c + 0.0).to_bits())
+}
+
+/**
+ * @brief Checks that every edge is shared by exactly two triangles with opposite orientation.
+ *
+ * @param triangles Triangles of the mesh.
+ * @return bool True if the mesh is closed and consistently oriented.
+ */
+fn is_watertight(triangles: &[Triangle]) -> bool {
+    let mut vertex_ids: HashMap<[u32; 3], u32> = HashMap::new();
+    let mut edges: Vec<(u32, u32)> = Vec::with_capacity(triangles.len() * 3);
+    for triangle in triangles {
+        let ids = triangle.map(|vertex| {
+            let next_id = vertex_ids.len() as u32;
+            *vertex_ids.entry(vertex_key(&vertex)).or_insert(next_id)
+        });
+        for i in 0..3 {
+            edges.push((ids[i], ids[(i + 1) % 3]));
+        }
+    }
+    edges.sort_unstable();
+    if edges.windows(2).any(|pair| pair[0] == pair[1]) {
+        return false;
+    }
+    edges
+        .iter()
+        .all(|&(from, to)| edges.binary_search(&(to, from)).is_ok())
+}
+
+fn compute_statistics(triangles: &[Triangle]) -> ModelStatistics {
+    let mut bounding_box_min = [f64::MAX; 3];
+    let mut bounding_box_max = [f64::MIN; 3];
+    let mut signed_volume = 0.0;
+    let mut surface_area = 0.0;
+    for triangle in triangles {
+        let [a, b, c] = triangle.map(|vertex| vertex.map(f64::from));
+        for vertex in [a, b, c] {
+            for axis in 0..3 {
+                bounding_box_min[axis] = bounding_box_min[axis].min(vertex[axis]);
+                bounding_box_max[axis] = bounding_box_max[axis].max(vertex[axis]);
+            }
+        }
+        // Signed volume of the tetrahedron spanned by the triangle and the origin
+        signed_volume += (a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+            + a[2] * (b[0] * c[1] - b[1] * c[0]))
+            / 6.0;
+        let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+        let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+        let cross = [
+            u[1] * v[2] - u[2] * v[1],
+            u[2] * v[0] - u[0] * v[2],
+            u[0] * v[1] - u[1] * v[0],
+        ];
+        surface_area +=
+            (cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]).sqrt() / 2.0;
+    }
+    ModelStatistics {
+        triangle_count: triangles.len() as u32,
+        bounding_box_min,
+        bounding_box_max,
+        volume: signed_volume.abs(),
+        surface_area,
+        is_watertight: is_watertight(triangles),
+    }
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Parses a binary or ASCII STL model and computes its geometry.
+ *
+ * @param content Content of the STL file.
+ * @return io::Result<ModelStatistics> Statistics of the model, or an `InvalidData` error if
+ *         the content is not an STL model with at least one finite triangle.
+ */
+pub fn inspect_stl(content: &[u8]) -> io::Result<ModelStatistics> {
+    let triangles = match binary_triangle_count(content) {
+        Some(count) => parse_binary_stl(content, count),
+        None => {
+            let text = std::str::from_utf8(content).map_err(|_| {
+                invalid_model("The file is neither a binary nor an ASCII STL".to_string())
+            })?;
+            parse_ascii_stl(text)?
+        }
+    };
+    if triangles.is_empty() {
+        return Err(invalid_model("The model contains no triangles".to_string()));
+    }
+    if triangles.iter().flatten().flatten().any(|c| !c.is_finite()) {
+        return Err(invalid_model(
+            "The model contains non-finite coordinates".to_string(),
+        ));
+    }
+    Ok(compute_statistics(&triangles))
+}
+
+/**
+ * @brief Reads an STL model from disk and computes its geometry.
+ *
+ * @param model_path Path of the STL file.
+ * @return io::Result<ModelStatistics> Statistics of the model. Content that is not an STL
+ *         model yields an `InvalidData` error, failures to read the file any other error kind.
+ */
+pub fn inspect_stl_file(model_path: &Path) -> io::Result<ModelStatistics> {
+    inspect_stl(&std::fs::read(model_path)?)
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CUBE_CORNERS: [Vertex; 8] = [
+        [0.0, 0.0, 0.0],
+        [10.0, 0.0, 0.0],
+        [10.0, 10.0, 0.0],
+        [0.0, 10.0, 0.0],
+        [0.0, 0.0, 10.0],
+        [10.0, 0.0, 10.0],
+        [10.0, 10.0, 10.0],
+        [0.0, 10.0, 10.0],
+    ];
+    // Counter-clockwise when seen from outside the cube
+    const CUBE_FACES: [[usize; 3]; 12] = [
+        [0, 2, 1],
+        [0, 3, 2],
+        [4, 5, 6],
+        [4, 6, 7],
+        [0, 1, 5],
+        [0, 5, 4],
+        [1, 2, 6],
+        [1, 6, 5],
+        [2, 3, 7],
+        [2, 7, 6],
+        [3, 0, 4],
+        [3, 4, 7],
+    ];
+
+    fn cube_triangles() -> Vec<Triangle> {
+        CUBE_FACES
+            .iter()
+            .map(|face| face.map(|i| CUBE_CORNERS[i]))
+            .collect()
+    }
+
+    fn binary_stl(triangles: &[Triangle]) -> Vec<u8> {
+        // The header deliberately starts like an ASCII STL
+        let mut content = b"solid exported by a CAD tool".to_vec();
+        content.resize(BINARY_HEADER_SIZE, 0);
+        content.extend_from_slice(&(triangles.len() as u32).to_le_bytes());
+        for triangle in triangles {
+            content.extend_from_slice(&[0; 12]);
+            for coordinate in triangle.iter().flatten() {
+                content.extend_from_slice(&coordinate.to_le_bytes());
+            }
+            content.extend_from_slice(&[0; 2]);
+        }
+        content
+    }
+
+    fn ascii_stl(triangles: &[Triangle]) -> String {
+        let mut content = "solid cube\n".to_string();
+        for triangle in triangles {
+            content.push_str("  facet normal 0 0 0\n    outer loop\n");
+            for [x, y, z] in triangle {
+                content.push_str(&format!("      vertex {} {} {}\n", x, y, z));
+            }
+            content.push_str("    endloop\n  endfacet\n");
+        }
+        content.push_str("endsolid cube\n");
+        content
+    }
+
+    #[test]
+    fn test_binary_and_ascii_cube_statistics() {
+        for content in [
+            binary_stl(&cube_triangles()),
+            ascii_stl(&cube_triangles()).into_bytes(),
+        ] {
+            let statistics = inspect_stl(&content).unwrap();
+            assert_eq!(statistics.triangle_count, 12);
+            assert_eq!(statistics.bounding_box_min, [0.0; 3]);
+            assert_eq!(statistics.bounding_box_max, [10.0; 3]);
+            assert!((statistics.volume - 1000.0).abs() < 1e-6);
+            assert!((statistics.surface_area - 600.0).abs() < 1e-6);
+            assert!(statistics.is_watertight);
+        }
+    }
+
+    #[test]
+    fn test_open_mesh_is_not_watertight() {
+        let mut triangles = cube_triangles();
+        triangles.pop();
+        assert!(!inspect_stl(&binary_stl(&triangles)).unwrap().is_watertight);
+    }
+
+    #[test]
+    fn test_garbage_is_rejected() {
+        let png_header = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert_eq!(
+            inspect_stl(png_header).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert!(inspect_stl(b"solid empty\nendsolid empty\n").is_err());
+        assert!(
+            inspect_stl(b"solid broken\nfacet normal 0 0 0\nouter loop\nvertex 1 2\n").is_err()
+        );
+        assert!(inspect_stl(&binary_stl(&[])).is_err());
+    }
+}
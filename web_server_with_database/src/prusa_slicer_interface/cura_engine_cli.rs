@@ -0,0 +1,199 @@
+/* IMPORTS FROM LIBRARIES */
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+use strum::IntoEnumIterator;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_traits::SlicerInterfaceImpl;
+use crate::common_utils::global_types::{
+    EvaluatedPrintingParameters, PrintType, SlicerBackend, SlicerError, SubmittedOrderData,
+};
+use crate::common_utils::material_catalogue::Material;
+use crate::common_utils::model_storage::{processed_gcode_path, received_model_path};
+use crate::gcode_metadata::read_cura_gcode_metadata;
+use crate::prusa_slicer_interface::slicer_process::{
+    check_profiles_exist, classify_slicer_failure, io_error, print_profile_path,
+    read_output_gcode_file, run_slicer_with_timeout,
+};
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Machine definition of the printer. The definitions it inherits from, such as
+/// `fdmprinter.def.json`, are looked up in the same directory.
+const PRINTER_DEFINITION: &str = "cura_printer.def.json";
+
+/// Environment variable telling CuraEngine where to look for inherited definitions.
+const SEARCH_PATH_VARIABLE: &str = "CURA_ENGINE_SEARCH_PATH";
+
+/* PUBLIC TYPES AND VARIABLES */
+/// CuraEngine, driven through its command line with a machine definition and setting files.
+pub struct CuraEngineCli;
+
+/* HELPER FUNCTIONS */
+/**
+ * @brief Reads a CuraEngine setting file made of "key = value" lines.
+ *
+ * Empty lines and lines starting with "#" or ";" are skipped.
+ *
+ * @param content Content of the setting file.
+ * @return Result<Vec<String>, String> The settings as "key=value" arguments of "-s", or the
+ *         first line that is not a setting.
+ */
+fn parse_settings(content: &str) -> Result<Vec<String>, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with(';'))
+        .map(|line| match line.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok(format!("{}={}", key.trim(), value.trim()))
+            }
+            _ => Err(format!("'{}' is not a 'key = value' setting", line)),
+        })
+        .collect()
+}
+
+fn read_settings(settings_path: &Path) -> Result<Vec<String>, SlicerError> {
+    let content = std::fs::read_to_string(settings_path).map_err(|e| {
+        SlicerError::Io(format!("Failed to read {}: {}", settings_path.display(), e))
+    })?;
+    parse_settings(&content)
+        .map_err(|e| SlicerError::Io(format!("{}: {}", settings_path.display(), e)))
+}
+
+fn slice_the_stl_file(
+    cura_path: &str,
+    order: &SubmittedOrderData,
+    filament_profile: Option<&str>,
+    ws_path: &str,
+    timeout: Duration,
+) -> Result<String, SlicerError> {
+    let backend = SlicerBackend::CuraEngine;
+    let received_file_path = received_model_path(ws_path, &order.model_key).map_err(io_error)?;
+    let processed_file_path = processed_gcode_path(ws_path, &order.model_key)
+        .map_err(io_error)?
+        .to_string_lossy()
+        .into_owned();
+    let profile_dir = Path::new(ws_path).join(backend.profile_dir());
+    let mut settings = read_settings(&print_profile_path(
+        ws_path,
+        backend,
+        &order.print_type,
+        "cfg",
+    ))?;
+    if let Some(filament_profile) = filament_profile {
+        // Passed last, so the filament settings override those of the print profile
+        settings.extend(read_settings(&profile_dir.join(filament_profile))?);
+    }
+
+    let mut command = Command::new(cura_path);
+    command
+        .env(SEARCH_PATH_VARIABLE, &profile_dir)
+        .arg("slice")
+        .arg("-j")
+        .arg(profile_dir.join(PRINTER_DEFINITION));
+    for setting in settings {
+        command.arg("-s").arg(setting);
+    }
+    command
+        .arg("-o")
+        .arg(&processed_file_path)
+        .arg("-l")
+        .arg(received_file_path);
+    let (status, stderr) = run_slicer_with_timeout(&mut command, timeout)?;
+    if !status.success() {
+        eprintln!("CuraEngine failed with {}: {}", status, stderr);
+        return Err(classify_slicer_failure(status.code(), &stderr));
+    }
+    Ok(processed_file_path)
+}
+
+/* PRIVATE FUNCTIONS */
+
+/* PUBLIC FUNCTIONS */
+impl SlicerInterfaceImpl for CuraEngineCli {
+    /**
+     * @brief Checks that the CuraEngine executable can be started and its profiles are present.
+     *
+     * The exit status of "CuraEngine help" differs between versions, so only starting it is
+     * checked.
+     *
+     * @param cura_path Path of CuraEngine.
+     * @param ws_path Path to the workspace directory.
+     * @return io::Result<()> Result indicating success or failure of the operation.
+     */
+    fn initialize_slicer_int_impl(&self, cura_path: &str, ws_path: &str) -> io::Result<()> {
+        Command::new(cura_path).arg("help").output()?;
+        check_profiles_exist(
+            ws_path,
+            SlicerBackend::CuraEngine,
+            "cfg",
+            &[PRINTER_DEFINITION],
+        )?;
+        for print_type in PrintType::iter() {
+            let profile =
+                print_profile_path(ws_path, SlicerBackend::CuraEngine, &print_type, "cfg");
+            read_settings(&profile)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /**
+     * @brief Slices the model of an order with CuraEngine and reads the resulting G-code.
+     *
+     * @param order Reference to the submitted order data.
+     * @param material Catalogued material of the order, which may select a filament profile.
+     * @param slicer_path Path to the CuraEngine executable.
+     * @param ws_path Path to the workspace directory.
+     * @param timeout Maximum time the slicer may run before it is killed.
+     * @return Result<EvaluatedPrintingParameters, SlicerError> Printing parameters read from the
+     *         generated G-code, or the reason why the model could not be sliced.
+     */
+    fn get_expected_print_parameters(
+        &self,
+        order: &SubmittedOrderData,
+        material: &Material,
+        slicer_path: &str,
+        ws_path: &str,
+        timeout: Duration,
+    ) -> Result<EvaluatedPrintingParameters, SlicerError> {
+        let output_file_path = slice_the_stl_file(
+            slicer_path,
+            order,
+            material.filament_profile.as_deref(),
+            ws_path,
+            timeout,
+        )?;
+        read_output_gcode_file(&output_file_path, read_cura_gcode_metadata)
+    }
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_are_passed_as_key_value_arguments() {
+        let settings = parse_settings(
+            "# Thick and strong\n\
+             layer_height = 0.3\n\n\
+             ; walls\n\
+             wall_line_count=4\n\
+             infill_sparse_density = 40\n",
+        )
+        .unwrap();
+        assert_eq!(
+            settings,
+            [
+                "layer_height=0.3",
+                "wall_line_count=4",
+                "infill_sparse_density=40"
+            ]
+        );
+        assert!(parse_settings("layer_height 0.3\n").is_err());
+        assert!(parse_settings("= 0.3\n").is_err());
+    }
+}
@@ -0,0 +1,253 @@
+/* IMPORTS FROM LIBRARIES */
+use std::f64::consts::PI;
+use std::io;
+use std::time::Duration;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_traits::SlicerInterfaceImpl;
+use crate::common_utils::global_types::{
+    default_estimator_coefficients, EstimatorCoefficients, EvaluatedPrintingParameters,
+    GeometricEstimatorSettings, ModelStatistics, PrintType, SlicerError, SubmittedOrderData,
+};
+use crate::common_utils::material_catalogue::Material;
+use crate::common_utils::model_storage::received_model_path;
+use crate::model_inspection::inspect_stl_file;
+
+/* PRIVATE TYPES AND VARIABLES */
+
+/* PUBLIC TYPES AND VARIABLES */
+/// Estimates filament use and print time from the volume and surface area of a model, for
+/// deployments without a slicer and as a fallback when the slicer fails.
+pub struct GeometricEstimator {
+    pub settings: GeometricEstimatorSettings,
+}
+
+/* PRIVATE FUNCTIONS */
+fn invalid_settings(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/* PUBLIC FUNCTIONS */
+/**
+ * @brief Checks the coefficients of the estimator.
+ *
+ * @param settings Settings of the estimator.
+ * @return io::Result<()> An `InvalidData` error naming the first invalid coefficient.
+ */
+pub fn validate_estimator_settings(settings: &GeometricEstimatorSettings) -> io::Result<()> {
+    for (i, coefficients) in settings.print_types.iter().enumerate() {
+        let field = format!("geometric_estimator.print_types[{}]", i);
+        if settings.print_types[..i]
+            .iter()
+            .any(|other| other.print_type == coefficients.print_type)
+        {
+            return Err(invalid_settings(format!(
+                "{}: print type {} is listed more than once",
+                field, coefficients.print_type
+            )));
+        }
+        if !(coefficients.wall_thickness_mm.is_finite() && coefficients.wall_thickness_mm > 0.0) {
+            return Err(invalid_settings(format!(
+                "{}.wall_thickness_mm: must be positive",
+                field
+            )));
+        }
+        if !(0.0..=100.0).contains(&coefficients.infill_percent) {
+            return Err(invalid_settings(format!(
+                "{}.infill_percent: must be between 0 and 100",
+                field
+            )));
+        }
+        if !(coefficients.volumetric_speed_mm3_s.is_finite()
+            && coefficients.volumetric_speed_mm3_s > 0.0)
+        {
+            return Err(invalid_settings(format!(
+                "{}.volumetric_speed_mm3_s: must be positive",
+                field
+            )));
+        }
+    }
+    Ok(())
+}
+
+impl GeometricEstimator {
+    /// Coefficients of a print type, from the settings or the built-in ones.
+    fn coefficients(&self, print_type: &PrintType) -> EstimatorCoefficients {
+        self.settings
+            .print_types
+            .iter()
+            .find(|coefficients| coefficients.print_type == *print_type)
+            .cloned()
+            .unwrap_or_else(|| default_estimator_coefficients(print_type))
+    }
+
+    /**
+     * @brief Estimates the printing parameters of one copy of a model.
+     *
+     * The model is taken as a shell of walls as thick as `wall_thickness_mm` over its whole
+     * surface, filled with `infill_percent` of infill. The print time is the time the printer
+     * needs to extrude that plastic at `volumetric_speed_mm3_s`. Support material is not
+     * accounted for.
+     *
+     * @param model_statistics Geometry of the model, in millimetres.
+     * @param material Material of the order, giving the filament diameter and density.
+     * @param print_type Print type of the order.
+     * @return EvaluatedPrintingParameters The estimated parameters, marked as estimated.
+     */
+    pub fn estimate(
+        &self,
+        model_statistics: &ModelStatistics,
+        material: &Material,
+        print_type: &PrintType,
+    ) -> EvaluatedPrintingParameters {
+        let coefficients = self.coefficients(print_type);
+        let volume_mm3 = model_statistics.volume.abs();
+        let shell_mm3 =
+            (model_statistics.surface_area * coefficients.wall_thickness_mm).min(volume_mm3);
+        let plastic_mm3 =
+            shell_mm3 + (volume_mm3 - shell_mm3) * coefficients.infill_percent / 100.0;
+        let radius_mm = material.filament_diameter_mm / 2.0;
+        let material_cm3 = plastic_mm3 / 1000.0;
+        EvaluatedPrintingParameters {
+            time: (plastic_mm3 / coefficients.volumetric_speed_mm3_s).ceil() as u32,
+            material_mm: (plastic_mm3 / (PI * radius_mm * radius_mm)).round() as u32,
+            material_g: Some(material_cm3 * material.density_g_cm3),
+            material_cm3: Some(material_cm3),
+            max_z_mm: Some(
+                model_statistics.bounding_box_max[2] - model_statistics.bounding_box_min[2],
+            ),
+            estimated: true,
+            ..Default::default()
+        }
+    }
+}
+
+impl SlicerInterfaceImpl for GeometricEstimator {
+    /**
+     * @brief Checks the coefficients of the estimator; no executable or profile is needed.
+     *
+     * @param _slicer_path Unused, the estimator runs no slicer.
+     * @param _ws_path Unused, the estimator reads no profiles.
+     * @return io::Result<()> An `InvalidData` error naming the first invalid coefficient.
+     */
+    fn initialize_slicer_int_impl(&self, _slicer_path: &str, _ws_path: &str) -> io::Result<()> {
+        validate_estimator_settings(&self.settings)
+    }
+
+    /**
+     * @brief Estimates the printing parameters of an order from the geometry of its model.
+     *
+     * @param order Reference to the submitted order data.
+     * @param material Catalogued material of the order.
+     * @param _slicer_path Unused, the estimator runs no slicer.
+     * @param ws_path Path to the workspace directory.
+     * @param _timeout Unused, estimating takes no noticeable time.
+     * @return Result<EvaluatedPrintingParameters, SlicerError> The estimated parameters, or
+     *         `ModelUnprintable` if the model cannot be read or has no volume.
+     */
+    fn get_expected_print_parameters(
+        &self,
+        order: &SubmittedOrderData,
+        material: &Material,
+        _slicer_path: &str,
+        ws_path: &str,
+        _timeout: Duration,
+    ) -> Result<EvaluatedPrintingParameters, SlicerError> {
+        let model_statistics = received_model_path(ws_path, &order.model_key)
+            .and_then(|model_path| inspect_stl_file(&model_path))
+            .map_err(|e| SlicerError::ModelUnprintable(e.to_string()))?;
+        if model_statistics.volume.abs() <= 0.0 {
+            return Err(SlicerError::ModelUnprintable(
+                "The model is empty, it has no volume".to_string(),
+            ));
+        }
+        Ok(self.estimate(&model_statistics, material, &order.print_type))
+    }
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_utils::material_catalogue::{HourlyRateTier, MaterialPrice};
+    use crate::common_utils::money::Money;
+
+    fn pla() -> Material {
+        Material {
+            name: "PLA".to_string(),
+            density_g_cm3: 1.25,
+            filament_diameter_mm: 1.75,
+            price: MaterialPrice::PerKg(Money::from_pln(100)),
+            hourly_rates: vec![HourlyRateTier {
+                from_hours: 0,
+                rate: Money::from_pln(30),
+            }],
+            filament_profile: None,
+        }
+    }
+
+    fn cube(size: f64) -> ModelStatistics {
+        ModelStatistics {
+            triangle_count: 12,
+            bounding_box_min: [0.0; 3],
+            bounding_box_max: [size; 3],
+            volume: size * size * size,
+            surface_area: 6.0 * size * size,
+            is_watertight: true,
+        }
+    }
+
+    #[test]
+    fn test_shell_and_infill_of_a_cube_are_estimated() {
+        let estimator = GeometricEstimator {
+            settings: GeometricEstimatorSettings {
+                fallback: false,
+                print_types: vec![EstimatorCoefficients {
+                    print_type: PrintType::ThickSoft,
+                    wall_thickness_mm: 1.0,
+                    infill_percent: 20.0,
+                    volumetric_speed_mm3_s: 10.0,
+                }],
+            },
+        };
+        // 20 mm cube: 2400 mm3 of walls, 20% of the remaining 5600 mm3 as infill
+        let params = estimator.estimate(&cube(20.0), &pla(), &PrintType::ThickSoft);
+        assert!(params.estimated);
+        assert_eq!(params.time, 352);
+        assert!((params.material_cm3.unwrap() - 3.52).abs() < 1e-9);
+        assert!((params.material_g.unwrap() - 4.4).abs() < 1e-9);
+        assert_eq!(params.material_mm, 1463);
+        assert_eq!(params.max_z_mm, Some(20.0));
+
+        // A model thinner than the walls is solid
+        let params = estimator.estimate(&cube(1.0), &pla(), &PrintType::ThickSoft);
+        assert!((params.material_cm3.unwrap() - 0.001).abs() < 1e-9);
+
+        // Print types that are not listed use the built-in coefficients
+        let precise = estimator.estimate(&cube(20.0), &pla(), &PrintType::PreciseStrong);
+        let thick = estimator.estimate(&cube(20.0), &pla(), &PrintType::ThickStrong);
+        assert_eq!(precise.material_mm, thick.material_mm);
+        assert!(precise.time > thick.time);
+    }
+
+    #[test]
+    fn test_invalid_coefficients_are_rejected() {
+        let coefficients = default_estimator_coefficients(&PrintType::ThickStrong);
+        let mut settings = GeometricEstimatorSettings {
+            fallback: true,
+            print_types: vec![coefficients.clone()],
+        };
+        assert!(validate_estimator_settings(&settings).is_ok());
+
+        settings.print_types[0].infill_percent = 120.0;
+        assert!(validate_estimator_settings(&settings).is_err());
+        settings.print_types[0] = EstimatorCoefficients {
+            volumetric_speed_mm3_s: 0.0,
+            ..coefficients.clone()
+        };
+        assert!(validate_estimator_settings(&settings).is_err());
+        settings.print_types = vec![coefficients.clone(), coefficients];
+        let error = validate_estimator_settings(&settings).unwrap_err();
+        assert!(error.to_string().contains("more than once"));
+    }
+}
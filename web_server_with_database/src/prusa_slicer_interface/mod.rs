@@ -3,6 +3,11 @@ pub mod prusa_slicer_interface;
 pub use prusa_slicer_interface::*;
 
 // PRIVATE MODULES
+mod cura_engine_cli;
+mod geometric_estimator;
+mod orca_slicer_cli;
 mod prusa_slicer_cli;
+#[cfg(test)]
 mod prusa_slicer_mock;
 mod prusa_slicer_price_calculator;
+mod slicer_process;
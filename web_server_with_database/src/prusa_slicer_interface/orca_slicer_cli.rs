@@ -0,0 +1,152 @@
+/* IMPORTS FROM LIBRARIES */
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_traits::SlicerInterfaceImpl;
+use crate::common_utils::global_types::{
+    EvaluatedPrintingParameters, SlicerBackend, SlicerError, SubmittedOrderData,
+};
+use crate::common_utils::material_catalogue::Material;
+use crate::common_utils::model_storage::{processed_gcode_path, received_model_path};
+use crate::gcode_metadata::read_orca_gcode_metadata;
+use crate::prusa_slicer_interface::slicer_process::{
+    check_profiles_exist, classify_slicer_failure, io_error, print_profile_path,
+    read_output_gcode_file, run_slicer_with_timeout,
+};
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Printer profile loaded before the print profile of the order.
+const MACHINE_PROFILE: &str = "orca_machine.json";
+
+/// G-code file OrcaSlicer writes into its output directory for the first plate.
+const PLATE_GCODE_FILE: &str = "plate_1.gcode";
+
+/* PUBLIC TYPES AND VARIABLES */
+/// OrcaSlicer, driven through its command line with JSON profiles.
+pub struct OrcaSlicerCli;
+
+/* HELPER FUNCTIONS */
+/**
+ * @brief Slices a model with OrcaSlicer and moves the G-code to the processed orders.
+ *
+ * OrcaSlicer writes one G-code file per plate into an output directory, so it is given a
+ * directory of its own next to the final G-code file, which is removed afterwards.
+ *
+ * @param orca_path Path of the OrcaSlicer executable.
+ * @param order Submitted order, giving the model and the print type.
+ * @param filament_profile Filament profile of the material, in the OrcaSlicer profile directory.
+ * @param ws_path Path to the workspace directory.
+ * @param timeout Maximum time the slicer may run.
+ * @return Result<String, SlicerError> Path of the G-code file.
+ */
+fn slice_the_stl_file(
+    orca_path: &str,
+    order: &SubmittedOrderData,
+    filament_profile: Option<&str>,
+    ws_path: &str,
+    timeout: Duration,
+) -> Result<String, SlicerError> {
+    let backend = SlicerBackend::OrcaSlicer;
+    let received_file_path = received_model_path(ws_path, &order.model_key).map_err(io_error)?;
+    let processed_file_path = processed_gcode_path(ws_path, &order.model_key).map_err(io_error)?;
+    let output_dir = processed_file_path.with_extension("orca");
+    std::fs::create_dir_all(&output_dir).map_err(io_error)?;
+
+    let profile_dir = Path::new(ws_path).join(backend.profile_dir());
+    let settings = format!(
+        "{};{}",
+        profile_dir.join(MACHINE_PROFILE).display(),
+        print_profile_path(ws_path, backend, &order.print_type, "json").display()
+    );
+    let mut command = Command::new(orca_path);
+    command
+        .arg("--arrange")
+        .arg("1")
+        .arg("--slice")
+        .arg("0")
+        .arg("--load-settings")
+        .arg(settings);
+    if let Some(filament_profile) = filament_profile {
+        command
+            .arg("--load-filaments")
+            .arg(profile_dir.join(filament_profile));
+    }
+    command
+        .arg("--outputdir")
+        .arg(&output_dir)
+        .arg(received_file_path);
+    let result = run_slicer_with_timeout(&mut command, timeout).and_then(|(status, stderr)| {
+        if !status.success() {
+            eprintln!("OrcaSlicer failed with {}: {}", status, stderr);
+            return Err(classify_slicer_failure(status.code(), &stderr));
+        }
+        std::fs::rename(output_dir.join(PLATE_GCODE_FILE), &processed_file_path).map_err(|e| {
+            SlicerError::Io(format!(
+                "OrcaSlicer did not write {}: {}",
+                PLATE_GCODE_FILE, e
+            ))
+        })
+    });
+    std::fs::remove_dir_all(&output_dir).ok();
+    result?;
+    Ok(processed_file_path.to_string_lossy().into_owned())
+}
+
+/* PRIVATE FUNCTIONS */
+
+/* PUBLIC FUNCTIONS */
+impl SlicerInterfaceImpl for OrcaSlicerCli {
+    /**
+     * @brief Pings the OrcaSlicer executable and checks that its profiles are present.
+     *
+     * @param orca_path Path of OrcaSlicer.
+     * @param ws_path Path to the workspace directory.
+     * @return io::Result<()> Result indicating success or failure of the operation.
+     */
+    fn initialize_slicer_int_impl(&self, orca_path: &str, ws_path: &str) -> io::Result<()> {
+        let output = Command::new(orca_path).arg("--help").output()?;
+        if !output.status.success() {
+            io::stderr().write_all(&output.stderr)?;
+            println!("Failed to ping OrcaSlicer at path: {:?}", orca_path);
+            return Err(io::Error::other("Failed to ping OrcaSlicer"));
+        }
+        check_profiles_exist(
+            ws_path,
+            SlicerBackend::OrcaSlicer,
+            "json",
+            &[MACHINE_PROFILE],
+        )
+    }
+
+    /**
+     * @brief Slices the model of an order with OrcaSlicer and reads the resulting G-code.
+     *
+     * @param order Reference to the submitted order data.
+     * @param material Catalogued material of the order, which may select a filament profile.
+     * @param slicer_path Path to the OrcaSlicer executable.
+     * @param ws_path Path to the workspace directory.
+     * @param timeout Maximum time the slicer may run before it is killed.
+     * @return Result<EvaluatedPrintingParameters, SlicerError> Printing parameters read from the
+     *         generated G-code, or the reason why the model could not be sliced.
+     */
+    fn get_expected_print_parameters(
+        &self,
+        order: &SubmittedOrderData,
+        material: &Material,
+        slicer_path: &str,
+        ws_path: &str,
+        timeout: Duration,
+    ) -> Result<EvaluatedPrintingParameters, SlicerError> {
+        let output_file_path = slice_the_stl_file(
+            slicer_path,
+            order,
+            material.filament_profile.as_deref(),
+            ws_path,
+            timeout,
+        )?;
+        read_output_gcode_file(&output_file_path, read_orca_gcode_metadata)
+    }
+}
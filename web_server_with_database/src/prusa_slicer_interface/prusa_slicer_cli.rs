@@ -1,118 +1,101 @@
 /* IMPORTS FROM LIBRARIES */
-use regex::Regex;
-use std::fs::File;
 use std::io::{self, Write};
-use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
 use std::process::Command;
-use strum::IntoEnumIterator;
+use std::time::Duration;
 
 /* IMPORTS FROM OTHER MODULES */
 use crate::common_utils::global_traits::SlicerInterfaceImpl;
 use crate::common_utils::global_types::{
-    EvaluatedPrintingParameters, PrintMaterialType, PrintType, SubmittedOrderData,
+    EvaluatedPrintingParameters, PrintType, SlicerBackend, SlicerError, SubmittedOrderData,
+};
+use crate::common_utils::material_catalogue::Material;
+use crate::common_utils::model_storage::{processed_gcode_path, received_model_path};
+use crate::gcode_metadata::read_gcode_metadata;
+use crate::prusa_slicer_interface::slicer_process::{
+    check_profiles_exist, classify_slicer_failure, io_error, print_profile_path,
+    read_output_gcode_file, run_slicer_with_timeout,
 };
 
 /* PRIVATE TYPES AND VARIABLES */
 
 /* PUBLIC TYPES AND VARIABLES */
-pub struct PrusaSlicerCli;
+/// PrusaSlicer, or SuperSlicer which shares its command line, profiles and G-code statistics.
+pub struct PrusaSlicerCli {
+    /// Slicer whose profile directory is used.
+    pub backend: SlicerBackend,
+    /// Printer profile loaded before the print profile, if any.
+    pub printer_profile: Option<String>,
+}
 
 /* HELPER FUNCTIONS */
+/**
+ * @brief Lists the profiles loaded to slice an order, in loading order.
+ *
+ * Every profile overrides the settings of those loaded before it, so the printer profile
+ * comes first, then the print profile of the print type and last the filament profile of the
+ * material, whose temperatures, speeds and density apply.
+ *
+ * @param ws_path Path to the workspace directory.
+ * @param backend Slicer the profiles are written for.
+ * @param printer_profile Printer profile, if any.
+ * @param print_type Print type of the order.
+ * @param filament_profile Filament profile of the material, if any.
+ * @return Vec<PathBuf> Paths of the profiles, each passed with its own "--load".
+ */
+fn profile_layers(
+    ws_path: &str,
+    backend: SlicerBackend,
+    printer_profile: Option<&str>,
+    print_type: &PrintType,
+    filament_profile: Option<&str>,
+) -> Vec<PathBuf> {
+    let profile_dir = Path::new(ws_path).join(backend.profile_dir());
+    printer_profile
+        .map(|profile| profile_dir.join(profile))
+        .into_iter()
+        .chain([print_profile_path(ws_path, backend, print_type, "ini")])
+        .chain(filament_profile.map(|profile| profile_dir.join(profile)))
+        .collect()
+}
+
 fn slice_the_stl_file(
     prusa_path: &str,
-    file_name: &str,
+    slicer: &PrusaSlicerCli,
+    model_key: &str,
     ws_path: &str,
     print_type: &PrintType,
-) -> io::Result<String> {
-    let received_file_path = format!("{}/data_files/received_orders/{}", ws_path, file_name);
-    let processed_file_path = format!(
-        "{}/data_files/processed_orders/{}.gcode",
-        ws_path, file_name
-    );
-    let prusa_config_path = format!(
-        "{}/data_files/prusa_config_files/prusa_config_{}.ini",
-        ws_path, print_type
-    );
+    filament_profile: Option<&str>,
+    timeout: Duration,
+) -> Result<String, SlicerError> {
+    let backend = slicer.backend;
+    let received_file_path = received_model_path(ws_path, model_key).map_err(io_error)?;
+    let processed_file_path = processed_gcode_path(ws_path, model_key)
+        .map_err(io_error)?
+        .to_string_lossy()
+        .into_owned();
 
-    match Command::new(prusa_path)
-        .arg("-g")
-        .arg("--load")
-        .arg(prusa_config_path)
+    let mut command = Command::new(prusa_path);
+    command.arg("-g");
+    for profile in profile_layers(
+        ws_path,
+        backend,
+        slicer.printer_profile.as_deref(),
+        print_type,
+        filament_profile,
+    ) {
+        command.arg("--load").arg(profile);
+    }
+    command
         .arg("--output")
         .arg(&processed_file_path)
-        .arg(received_file_path)
-        .output()
-    {
-        Ok(_) => {
-            return Ok(processed_file_path);
-        }
-        Err(e) => {
-            eprintln!("Error running Prusa Slicer: {}", e);
-            io::stderr()
-                .write_all(format!("Error running Prusa Slicer: {}", e).as_bytes())
-                .unwrap();
-            return Err(e);
-        }
-    }
-}
-
-fn extract_time_from_line(line: &str, re: &Regex) -> Option<u32> {
-    if let Some(caps) = re.captures(line) {
-        let hours = caps
-            .get(1)
-            .map_or(0, |m| m.as_str().parse::<u32>().unwrap());
-        let minutes = caps
-            .get(2)
-            .map_or(0, |m| m.as_str().parse::<u32>().unwrap());
-        let seconds = caps
-            .get(3)
-            .map_or(0, |m| m.as_str().parse::<u32>().unwrap());
-        let estimated_time = hours * 3600 + minutes * 60 + seconds;
-        return Some(estimated_time);
-    }
-    return None;
-}
-
-fn extract_material_from_line(line: &str, re: &Regex) -> Option<u32> {
-    if let Some(caps) = re.captures(line) {
-        if let Ok(material_mm) = caps.get(1).map_or(Ok(0), |m| m.as_str().parse::<u32>()) {
-            return Some(material_mm);
-        }
+        .arg(received_file_path);
+    let (status, stderr) = run_slicer_with_timeout(&mut command, timeout)?;
+    if !status.success() {
+        eprintln!("{} failed with {}: {}", backend, status, stderr);
+        return Err(classify_slicer_failure(status.code(), &stderr));
     }
-    return None;
-}
-
-fn read_output_gcode_file(
-    material_type: PrintMaterialType,
-    gcode_file_path: &str,
-) -> EvaluatedPrintingParameters {
-    let file: File = File::open(gcode_file_path).expect("Failed to open G-code file");
-    let reader = BufReader::new(file);
-    let re_time =
-        Regex::new(r"^; estimated printing time \(normal mode\) = (?:(\d+)h )?(?:(\d+)m )?(\d+)s$")
-            .unwrap();
-    let re_material = Regex::new(r"^; filament used \[mm\] = (\d+)").unwrap();
-    let mut time: Option<u32> = None;
-    let mut material_mm: Option<u32> = None;
-    for line in reader.lines() {
-        let line_string = line.unwrap();
-        let line_str = line_string.as_str();
-        if re_time.is_match(line_str) {
-            time = extract_time_from_line(line_str, &re_time);
-        } else if re_material.is_match(line_str) {
-            println!("Matched material regex in line: {}", line_str);
-            material_mm = extract_material_from_line(line_str, &re_material);
-        }
-    }
-    println!("Extracted time: {:?}, material_mm: {:?}", time, material_mm);
-    if let (Some(t), Some(m)) = (time, material_mm) {
-        return EvaluatedPrintingParameters {
-            time: t,
-            material_mm: m,
-            material_type: material_type,
-        };
-    }
-    panic!("Failed to find estimated printing time in G-code file");
+    Ok(processed_file_path)
 }
 
 /* PRIVATE FUNCTIONS */
@@ -122,7 +105,9 @@ impl SlicerInterfaceImpl for PrusaSlicerCli {
     /**
      * @brief Pings the Prusa Slicer executable. And check that init files are present.
      *
-     * This function checks if the Prusa Slicer executable is reachable by running a command.
+     * This function checks if the Prusa Slicer executable is reachable by running a command,
+     * and that the printer profile and the print profiles of all print types exist. The
+     * filament profiles are checked with the material catalogue.
      *
      * @param prusa_path Path of Prusa Slicer
      * @return io::Result<()> Result indicating success or failure of the operation.
@@ -131,27 +116,27 @@ impl SlicerInterfaceImpl for PrusaSlicerCli {
         let output = Command::new(prusa_path).arg("--help").output()?;
         if !output.status.success() {
             io::stderr().write_all(&output.stderr)?;
-            println!("Failed to ping Prusa Slicer at path: {:?}", prusa_path);
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "Failed to ping Prusa Slicer",
-            ));
+            println!("Failed to ping {} at path: {:?}", self.backend, prusa_path);
+            return Err(io::Error::other(format!("Failed to ping {}", self.backend)));
         }
-
-        for print_type in PrintType::iter() {
-            // This now correctly uses IntoEnumIterator
-            let prusa_config_path = format!(
-                "{}/data_files/prusa_config_files/prusa_config_{:?}.ini",
-                ws_path, print_type
-            );
-            if !std::path::Path::new(&prusa_config_path).exists() {
+        if let Some(printer_profile) = &self.printer_profile {
+            if Path::new(printer_profile).file_name() != Some(printer_profile.as_ref()) {
                 return Err(io::Error::new(
-                    io::ErrorKind::NotFound,
-                    format!("Prusa config file not found: {}", prusa_config_path),
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "Printer profile '{}' is not a file name in {}",
+                        printer_profile,
+                        self.backend.profile_dir()
+                    ),
                 ));
             }
         }
-        Ok(())
+        check_profiles_exist(
+            ws_path,
+            self.backend,
+            "ini",
+            self.printer_profile.as_deref().as_slice(),
+        )
     }
 
     /**
@@ -161,30 +146,70 @@ impl SlicerInterfaceImpl for PrusaSlicerCli {
      * to perform an evaluation or retrieve specific information.
      *
      * @param order Reference to the submitted order data.
+     * @param material Catalogued material of the order, which may select a filament profile.
      * @param slicer_exec_path Path to the Prusa Slicer executable.
      * @param ws_path Path to the workspace directory.
-     * @return EvaluationResult Result containing the evaluation details.
+     * @param timeout Maximum time the slicer may run before it is killed.
+     * @return Result<EvaluatedPrintingParameters, SlicerError> Printing parameters read from the
+     *         generated G-code, or the reason why the model could not be sliced.
      */
     fn get_expected_print_parameters(
         &self,
         order: &SubmittedOrderData,
+        material: &Material,
         slicer_path: &str,
         ws_path: &str,
-    ) -> EvaluatedPrintingParameters {
-        let output_file_path =
-            match slice_the_stl_file(slicer_path, &order.file_name, ws_path, &order.print_type) {
-                Ok(path) => path,
-                Err(_) => {
-                    // You may want to handle the error differently or return a default EvaluationResult
-                    return EvaluatedPrintingParameters {
-                        time: 0,
-                        material_mm: 0,
-                        material_type: order.material_type.clone(),
-                    };
-                }
-            };
-        return read_output_gcode_file(order.material_type.clone(), output_file_path.as_str());
+        timeout: Duration,
+    ) -> Result<EvaluatedPrintingParameters, SlicerError> {
+        let output_file_path = slice_the_stl_file(
+            slicer_path,
+            self,
+            &order.model_key,
+            ws_path,
+            &order.print_type,
+            material.filament_profile.as_deref(),
+            timeout,
+        )?;
+        read_output_gcode_file(output_file_path.as_str(), read_gcode_metadata)
     }
 }
 
 /* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles_are_loaded_from_printer_to_filament() {
+        let profiles = profile_layers(
+            "ws",
+            SlicerBackend::PrusaSlicer,
+            Some("prusa_printer_MK4.ini"),
+            &PrintType::PreciseSoft,
+            Some("prusa_filament_ASA.ini"),
+        );
+        assert_eq!(
+            profiles,
+            [
+                "ws/data_files/prusa_config_files/prusa_printer_MK4.ini",
+                "ws/data_files/prusa_config_files/prusa_config_PreciseSoft.ini",
+                "ws/data_files/prusa_config_files/prusa_filament_ASA.ini",
+            ]
+            .map(PathBuf::from)
+        );
+
+        let profiles = profile_layers(
+            "ws",
+            SlicerBackend::SuperSlicer,
+            None,
+            &PrintType::ThickStrong,
+            None,
+        );
+        assert_eq!(
+            profiles,
+            [PathBuf::from(
+                "ws/data_files/superslicer_config_files/superslicer_config_ThickStrong.ini"
+            )]
+        );
+    }
+}
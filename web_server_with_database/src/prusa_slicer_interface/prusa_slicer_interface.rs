@@ -1,38 +1,94 @@
 /* IMPORTS FROM LIBRARIES */
 use lazy_static::lazy_static;
 use std::io;
-use std::sync::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 
 /* IMPORTS FROM OTHER MODULES */
 use crate::common_utils::global_traits::SlicerInterfaceImpl;
 use crate::common_utils::global_types::{
-    EvaluationResult, PrinterConfiguration, StatusType, SubmittedOrderData,
+    default_build_volume, default_order_fee, BuildVolumeFit, ConfigurationFieldError,
+    EvaluatedPrintingParameters, EvaluationResult, HourlyBilling, ModelStatistics, PriceBreakdown,
+    PriceSimulation, PricingUpdateError, PrinterConfiguration, SlicerBackend, SlicerError,
+    SlicerSettings, StatusType, SubmittedOrderData,
 };
+use crate::common_utils::material_catalogue::{
+    find_material, validate_material_catalogue, LegacyMaterialRates, Material,
+};
+use crate::common_utils::money::{Money, RoundingPolicy};
+use crate::common_utils::pricing_parameters::{apply_pricing_update, pricing_file_content};
+use crate::common_utils::pricing_versions::canonical_configuration;
+use crate::model_inspection::check_build_volume_fit;
+use crate::prusa_slicer_interface::cura_engine_cli::CuraEngineCli;
+use crate::prusa_slicer_interface::geometric_estimator::{
+    validate_estimator_settings, GeometricEstimator,
+};
+use crate::prusa_slicer_interface::orca_slicer_cli::OrcaSlicerCli;
 use crate::prusa_slicer_interface::prusa_slicer_cli::PrusaSlicerCli;
 use crate::prusa_slicer_interface::prusa_slicer_price_calculator::calculate_the_price;
 
 /* PRIVATE TYPES AND VARIABLES */
+const DEFAULT_SLICING_TIMEOUT: Duration = Duration::from_secs(600);
+
 struct State {
     ws_path: Mutex<Option<String>>,
     slicer_exec_path: Mutex<Option<String>>,
-    slicer_interface: Mutex<Box<dyn SlicerInterfaceImpl>>,
+    slicer_interface: Mutex<Arc<dyn SlicerInterfaceImpl>>,
+    /// Slicer `slicer_interface` drives.
+    slicer_backend: Mutex<SlicerBackend>,
+    /// Estimator used when the slicer cannot be started or fails on a model, if enabled.
+    fallback_interface: Mutex<Option<Arc<dyn SlicerInterfaceImpl>>>,
+    slicing_timeout: Mutex<Duration>,
     printer_configuration: Mutex<PrinterConfiguration>,
+    printer_configuration_path: Mutex<Option<String>>,
+    /// Stored version of `printer_configuration`, always updated while holding its lock.
+    pricing_version: Mutex<Option<i64>>,
+    store_pricing_version_cb: Mutex<Option<StorePricingVersionCb>>,
+    /// Held while the configuration file is read or rewritten, so reloads and updates apply
+    /// one after another.
+    configuration_update: Mutex<()>,
 }
 
+/// Stores a configuration given by its hash and canonical JSON, returning the version ID.
+type StorePricingVersionCb = fn(&str, &str) -> io::Result<i64>;
+
 lazy_static! {
     static ref SLICER_IF_STATE: State = State {
         ws_path: Mutex::new(None),
         slicer_exec_path: Mutex::new(None),
-        slicer_interface: Mutex::new(Box::new(PrusaSlicerCli {})),
+        slicer_interface: Mutex::new(Arc::new(PrusaSlicerCli {
+            backend: SlicerBackend::PrusaSlicer,
+            printer_profile: None,
+        })),
+        slicer_backend: Mutex::new(SlicerBackend::PrusaSlicer),
+        fallback_interface: Mutex::new(None),
+        slicing_timeout: Mutex::new(DEFAULT_SLICING_TIMEOUT),
         printer_configuration: Mutex::new(PrinterConfiguration {
-            material_rate_pla: 0,
-            material_rate_pet: 0,
-            material_rate_asa: 0,
-            hourly_rate_time_threshold: [0, 10, 100],
-            hourly_rate_pla_price: [30, 25, 20],
-            hourly_rate_pet_price: [35, 30, 25],
-            hourly_rate_asa_price: [40, 35, 30],
+            build_volume: default_build_volume(),
+            materials: LegacyMaterialRates {
+                material_rate_pla: 0,
+                material_rate_pet: 0,
+                material_rate_asa: 0,
+                hourly_rate_time_threshold: vec![0, 10, 100],
+                hourly_rate_pla_price: [30, 25, 20].map(Money::from_pln).to_vec(),
+                hourly_rate_pet_price: [35, 30, 25].map(Money::from_pln).to_vec(),
+                hourly_rate_asa_price: [40, 35, 30].map(Money::from_pln).to_vec(),
+            }
+            .into_catalogue()
+            .unwrap_or_default(),
+            hourly_billing: HourlyBilling::WholeJob,
+            print_type_adjustments: Vec::new(),
+            order_fee: default_order_fee(),
+            minimum_order_value: Money::ZERO,
+            quantity_discounts: Vec::new(),
+            price_rounding: RoundingPolicy::default(),
+            legacy_catalogue: true,
         }),
+        printer_configuration_path: Mutex::new(None),
+        pricing_version: Mutex::new(None),
+        store_pricing_version_cb: Mutex::new(None),
+        configuration_update: Mutex::new(()),
     };
 }
 
@@ -54,46 +110,264 @@ fn open_and_parse_json_file_into_printer_configuration(
     Ok(printer_config)
 }
 
-fn set_printer_configuration(ws_path: &str, printer_configuration: &str) -> io::Result<()> {
-    // In test environment, use default configuration
-    if cfg!(test) || ws_path == "foobar" || printer_configuration == "foobar" {
-        // Use default configuration for tests
-        // Keep existing configuration (it's already initialized with defaults in SLICER_IF_STATE)
-        return Ok(());
+/// Directory of the profiles of the selected slicer in a workspace.
+fn filament_profile_dir(ws_path: &str) -> PathBuf {
+    let backend = *SLICER_IF_STATE.slicer_backend.lock().unwrap();
+    Path::new(ws_path).join(backend.profile_dir())
+}
+
+/// Whether the selected slicer slices models, so every material needs a filament profile.
+fn filament_profiles_required() -> bool {
+    SLICER_IF_STATE
+        .slicer_backend
+        .lock()
+        .unwrap()
+        .slices_models()
+}
+
+/**
+ * @brief Creates the interface driving the configured slicer.
+ *
+ * @param slicer_settings Slicer entries of the configuration file.
+ * @return Arc<dyn SlicerInterfaceImpl> Interface of the slicer.
+ */
+fn create_slicer_interface(slicer_settings: &SlicerSettings) -> Arc<dyn SlicerInterfaceImpl> {
+    match slicer_settings.slicer {
+        backend @ (SlicerBackend::PrusaSlicer | SlicerBackend::SuperSlicer) => {
+            Arc::new(PrusaSlicerCli {
+                backend,
+                printer_profile: slicer_settings.printer_profile.clone(),
+            })
+        }
+        SlicerBackend::OrcaSlicer => Arc::new(OrcaSlicerCli),
+        SlicerBackend::CuraEngine => Arc::new(CuraEngineCli),
+        SlicerBackend::GeometricEstimate => Arc::new(GeometricEstimator {
+            settings: slicer_settings.geometric_estimator.clone(),
+        }),
     }
-    
-    let full_path = format!("{}/{}", ws_path, printer_configuration);
-    let config = open_and_parse_json_file_into_printer_configuration(&full_path).map_err(|e| {
+}
+
+/**
+ * @brief Makes the configured slicer the one orders are sliced with.
+ *
+ * An interface already driving the selected slicer is kept, unless the settings configure
+ * it: a printer profile or the geometric estimator always get a new interface.
+ *
+ * @param slicer_settings Slicer entries of the configuration file.
+ */
+fn select_slicer(slicer_settings: &SlicerSettings) {
+    let backend = slicer_settings.slicer;
+    let is_configured =
+        slicer_settings.printer_profile.is_some() || backend == SlicerBackend::GeometricEstimate;
+    let mut backend_lock = SLICER_IF_STATE.slicer_backend.lock().unwrap();
+    if *backend_lock != backend || is_configured {
+        *SLICER_IF_STATE.slicer_interface.lock().unwrap() =
+            create_slicer_interface(slicer_settings);
+        *backend_lock = backend;
+    }
+}
+
+/**
+ * @brief Tells whether an error comes from the slicer rather than from the order.
+ *
+ * Only such errors are worth retrying with the geometric estimator; a model the slicer
+ * refused or an unknown material would be refused again.
+ *
+ * @param error Error returned by the slicer interface.
+ * @return bool True if the slicer could not be run or its output could not be read.
+ */
+fn is_slicer_failure(error: &SlicerError) -> bool {
+    matches!(
+        error,
+        SlicerError::SlicerNotFound(_)
+            | SlicerError::SlicerTimeout(_)
+            | SlicerError::SlicerFailed { .. }
+            | SlicerError::MetadataMissing(_)
+            | SlicerError::Io(_)
+    )
+}
+
+/// Error of a price whose amounts do not fit into `Money`.
+fn price_out_of_range(copies_nbr: u32) -> SlicerError {
+    SlicerError::PriceOutOfRange(format!("the price of {} copies is too large", copies_nbr))
+}
+
+/**
+ * @brief Prices a print job with a given configuration.
+ *
+ * @param simulation Print job to price.
+ * @param printer_configuration Configuration to price the job with.
+ * @return Result<(EvaluatedPrintingParameters, PriceBreakdown), SlicerError> The print
+ *         parameters the job was priced from and its price.
+ */
+fn price_simulated_job(
+    simulation: &PriceSimulation,
+    printer_configuration: &PrinterConfiguration,
+) -> Result<(EvaluatedPrintingParameters, PriceBreakdown), SlicerError> {
+    let material = find_material(&printer_configuration.materials, &simulation.material_type)
+        .ok_or_else(|| SlicerError::UnknownMaterial(simulation.material_type.clone()))?;
+    let material_mm = match (simulation.material_mm, simulation.material_g) {
+        (Some(material_mm), _) => material_mm,
+        (None, Some(material_g)) => material.filament_length_mm(material_g).ok_or_else(|| {
+            SlicerError::ModelUnprintable(format!(
+                "{} g of {} is more filament than can be printed",
+                material_g, material.name
+            ))
+        })?,
+        (None, None) => {
+            return Err(SlicerError::MetadataMissing(
+                "Either the filament length or its weight is required".to_string(),
+            ))
+        }
+    };
+    let print_params = EvaluatedPrintingParameters {
+        time: simulation.time,
+        material_mm,
+        material_g: simulation.material_g,
+        ..Default::default()
+    };
+    let price_breakdown = calculate_the_price(
+        printer_configuration,
+        material,
+        &simulation.print_type,
+        print_params.clone(),
+        simulation.copies_nbr,
+    )
+    .ok_or_else(|| price_out_of_range(simulation.copies_nbr))?;
+    Ok((print_params, price_breakdown))
+}
+
+/**
+ * @brief Reads a printer configuration and checks that it can price orders.
+ *
+ * @param ws_path Workspace path, containing the PrusaSlicer profiles.
+ * @param file_path Path of the configuration file.
+ * @return io::Result<PrinterConfiguration> The configuration, or an error naming the first
+ *         problem found in it.
+ */
+fn load_printer_configuration(ws_path: &str, file_path: &str) -> io::Result<PrinterConfiguration> {
+    let config = open_and_parse_json_file_into_printer_configuration(file_path).map_err(|e| {
         io::Error::new(
             e.kind(),
             format!("Failed to parse printer configuration: {}", e),
         )
     })?;
+    let filament_profiles_required = filament_profiles_required();
+    // Catalogues converted from the rates of older files name no filament profiles
+    validate_material_catalogue(
+        &config.materials,
+        &filament_profile_dir(ws_path),
+        filament_profiles_required && !config.legacy_catalogue,
+    )
+    .map_err(|e| io::Error::new(e.kind(), format!("Invalid material catalogue: {}", e)))?;
+    if filament_profiles_required && config.legacy_catalogue {
+        println!(
+            "Warning: {} lists no materials, so PLA, PET and ASA are sliced with the filament \
+             settings of the print profiles. List the materials with a filament profile each.",
+            file_path
+        );
+    }
+    Ok(config)
+}
+
+/**
+ * @brief Records a configuration as a pricing version and starts pricing orders with it.
+ *
+ * @param config Validated configuration.
+ * @return io::Result<()> An error if the version could not be stored; the current
+ *         configuration is kept then.
+ */
+fn activate_printer_configuration(config: PrinterConfiguration) -> io::Result<()> {
+    let store_pricing_version_cb = *SLICER_IF_STATE.store_pricing_version_cb.lock().unwrap();
+    let pricing_version = match store_pricing_version_cb {
+        Some(store_pricing_version_cb) => {
+            let (json, hash) = canonical_configuration(&config);
+            Some(store_pricing_version_cb(&hash, &json)?)
+        }
+        None => None,
+    };
     let mut printer_config_lock = SLICER_IF_STATE.printer_configuration.lock().unwrap();
     *printer_config_lock = config;
+    *SLICER_IF_STATE.pricing_version.lock().unwrap() = pricing_version;
+    Ok(())
+}
+
+/**
+ * @brief Replaces a file by first writing a temporary file next to it and then renaming it,
+ *        so readers never see a partially written file.
+ *
+ * @param file_path Path of the file to replace.
+ * @param content New content of the file.
+ * @return io::Result<()> An error if the file could not be written; it is unchanged then.
+ */
+fn write_file_atomically(file_path: &str, content: &str) -> io::Result<()> {
+    let temporary_path = format!("{}.tmp", file_path);
+    let mut file = std::fs::File::create(&temporary_path)?;
+    io::Write::write_all(&mut file, content.as_bytes())?;
+    file.sync_all()?;
+    std::fs::rename(&temporary_path, file_path)
+}
+
+/// Returns the paths of the workspace and the configuration file loaded at startup.
+fn configuration_paths() -> io::Result<(String, String)> {
+    let config_path = SLICER_IF_STATE
+        .printer_configuration_path
+        .lock()
+        .unwrap()
+        .clone();
+    let ws_path = SLICER_IF_STATE.ws_path.lock().unwrap().clone();
+    match (ws_path, config_path) {
+        (Some(ws_path), Some(config_path)) => Ok((ws_path, config_path)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No printer configuration file was loaded at startup",
+        )),
+    }
+}
+
+fn set_printer_configuration(ws_path: &str, printer_configuration: &str) -> io::Result<()> {
+    // In test environment, use default configuration
+    if cfg!(test) || ws_path == "foobar" || printer_configuration == "foobar" {
+        // Use default configuration for tests
+        // Keep existing configuration (it's already initialized with defaults in SLICER_IF_STATE)
+        return Ok(());
+    }
+
+    let full_path = format!("{}/{}", ws_path, printer_configuration);
+    let config = load_printer_configuration(ws_path, &full_path)?;
+    activate_printer_configuration(config)?;
+    *SLICER_IF_STATE.printer_configuration_path.lock().unwrap() = Some(full_path);
     Ok(())
 }
 
-fn get_string_param_from_json_file(param_name: &str, file_path: &str) -> String {
+fn config_file_modification_time(file_path: &str) -> Option<SystemTime> {
+    std::fs::metadata(file_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/**
+ * @brief Reads which slicer to use and where its executable is from the configuration file.
+ *
+ * @param file_path Path of the configuration file.
+ * @return io::Result<SlicerSettings> The settings, or an error if the file cannot be read or
+ *         names an unknown slicer.
+ */
+fn read_slicer_settings(file_path: &str) -> io::Result<SlicerSettings> {
     // In test environment, return a mock path
     if cfg!(test) || file_path == "foobar" {
-        return "foobar".to_string();
-    }
-    
-    // Throw an error and exit if file cannot be read
-    let file_content =
-        std::fs::read_to_string(file_path).expect("Failed to read the JSON configuration file");
-    // Parse the JSON content
-    let json_value: serde_json::Value =
-        serde_json::from_str(&file_content).expect("Failed to parse JSON configuration file");
-    // Extract the parameter value as a string
-    json_value[param_name]
-        .as_str()
-        .expect(&format!(
-            "Parameter '{}' not found or is not a string",
-            param_name
-        ))
-        .to_string()
+        return Ok(SlicerSettings {
+            prusa_path: Some("foobar".to_string()),
+            ..Default::default()
+        });
+    }
+    let file_content = std::fs::read_to_string(file_path)?;
+    serde_json::from_str(&file_content).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid slicer settings in {}: {}", file_path, e),
+        )
+    })
 }
 
 /* PUBLIC FUNCTIONS */
@@ -101,22 +375,59 @@ pub fn initialize_prusa_slicer_if(
     ws_path: &str,
     print_price_evaluator_config_path: &str,
 ) -> io::Result<()> {
-    let prusa_path =
-        get_string_param_from_json_file("prusa_path", print_price_evaluator_config_path);
-    // let prusa_path = "/Applications/PrusaSlicer.app/Contents/MacOS/PrusaSlicer";
-    if let Err(e) = setup_paths_in_state(ws_path, &prusa_path) {
+    let slicer_settings = read_slicer_settings(print_price_evaluator_config_path)?;
+    let estimator_settings = &slicer_settings.geometric_estimator;
+    if let Err(e) = validate_estimator_settings(estimator_settings) {
         return Err(io::Error::new(
             e.kind(),
-            format!("Failed to set up paths in state: {}", e),
+            format!(
+                "Invalid settings in {}: {}",
+                print_price_evaluator_config_path, e
+            ),
         ));
     }
-    let slicer_interface_lock = SLICER_IF_STATE.slicer_interface.lock().unwrap();
-    if let Err(e) = slicer_interface_lock.initialize_slicer_int_impl(&prusa_path, ws_path) {
+    let slicer_path = match slicer_settings.executable() {
+        Some(slicer_path) => slicer_path,
+        // The estimator runs no executable
+        None if slicer_settings.slicer == SlicerBackend::GeometricEstimate => "",
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Neither 'slicer_path' nor 'prusa_path' is set in {}",
+                    print_price_evaluator_config_path
+                ),
+            ))
+        }
+    };
+    if let Err(e) = setup_paths_in_state(ws_path, slicer_path) {
         return Err(io::Error::new(
             e.kind(),
-            format!("Failed to initialize Prusa Slicer: {}", e),
+            format!("Failed to set up paths in state: {}", e),
         ));
     }
+    select_slicer(&slicer_settings);
+    let fallback_interface: Option<Arc<dyn SlicerInterfaceImpl>> =
+        estimator_settings.fallback.then(|| {
+            Arc::new(GeometricEstimator {
+                settings: estimator_settings.clone(),
+            }) as Arc<dyn SlicerInterfaceImpl>
+        });
+    *SLICER_IF_STATE.fallback_interface.lock().unwrap() = fallback_interface.clone();
+    let slicer_interface = SLICER_IF_STATE.slicer_interface.lock().unwrap().clone();
+    if let Err(e) = slicer_interface.initialize_slicer_int_impl(slicer_path, ws_path) {
+        let Some(fallback_interface) = fallback_interface else {
+            return Err(io::Error::new(
+                e.kind(),
+                format!("Failed to initialize {}: {}", slicer_settings.slicer, e),
+            ));
+        };
+        eprintln!(
+            "Failed to initialize {}, orders are estimated from their geometry instead: {}",
+            slicer_settings.slicer, e
+        );
+        *SLICER_IF_STATE.slicer_interface.lock().unwrap() = fallback_interface;
+    }
     if let Err(e) = set_printer_configuration(ws_path, print_price_evaluator_config_path) {
         return Err(io::Error::new(
             e.kind(),
@@ -126,29 +437,362 @@ pub fn initialize_prusa_slicer_if(
     Ok(())
 }
 
-pub fn get_prusa_slicer_evaluation(order: &SubmittedOrderData) -> EvaluationResult {
+/**
+ * @brief Sets where every loaded printer configuration is stored as a pricing version.
+ *
+ * Must be called before `initialize_prusa_slicer_if`, so the configuration loaded at startup
+ * is versioned too.
+ *
+ * @param store_pricing_version_cb Stores a configuration given by its hash and canonical JSON
+ *        and returns the ID of its version.
+ */
+pub fn set_pricing_version_store(store_pricing_version_cb: StorePricingVersionCb) {
+    *SLICER_IF_STATE.store_pricing_version_cb.lock().unwrap() = Some(store_pricing_version_cb);
+}
+
+/**
+ * @brief Returns the stored version of the configuration orders are currently priced with.
+ *
+ * @return Option<i64> ID of the pricing version, None if versions are not stored.
+ */
+pub fn get_pricing_version() -> Option<i64> {
+    let _printer_config_lock = SLICER_IF_STATE.printer_configuration.lock().unwrap();
+    *SLICER_IF_STATE.pricing_version.lock().unwrap()
+}
+
+/**
+ * @brief Reads the printer configuration file again and starts pricing orders with it.
+ *
+ * The new configuration is validated completely before it replaces the current one, so
+ * evaluations see either the old or the new configuration, never a mix of both. If it is
+ * invalid, the server keeps pricing with the current configuration.
+ *
+ * @return io::Result<()> An error describing why the new configuration was rejected.
+ */
+pub fn reload_printer_configuration() -> io::Result<()> {
+    let (ws_path, config_path) = configuration_paths()?;
+    let _update_lock = SLICER_IF_STATE.configuration_update.lock().unwrap();
+    match load_printer_configuration(&ws_path, &config_path)
+        .and_then(activate_printer_configuration)
+    {
+        Ok(()) => {
+            println!(
+                "Reloaded printer configuration from {} as pricing version {:?}",
+                config_path,
+                get_pricing_version()
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!(
+                "Rejected printer configuration {}, keeping the current one: {}",
+                config_path, e
+            );
+            Err(e)
+        }
+    }
+}
+
+/**
+ * @brief Returns the pricing parameters orders are currently priced with.
+ *
+ * @return (serde_json::Value, Option<i64>) The configuration in the layout of the
+ *         configuration file, and its pricing version.
+ */
+pub fn get_pricing_parameters() -> (serde_json::Value, Option<i64>) {
+    let printer_config_lock = SLICER_IF_STATE.printer_configuration.lock().unwrap();
+    let config = serde_json::to_value(&*printer_config_lock)
+        .expect("Pricing configurations are serializable");
+    (config, *SLICER_IF_STATE.pricing_version.lock().unwrap())
+}
+
+/**
+ * @brief Changes some of the pricing parameters, saves them to the configuration file and
+ *        prices new quotes with them.
+ *
+ * Fields of the update replace the current ones, see `apply_pricing_update`. The updated
+ * configuration is validated completely before the file is replaced, and the previous file
+ * is restored if the configuration cannot be recorded as a pricing version.
+ *
+ * @param update Changed fields of the configuration.
+ * @return Result<(), PricingUpdateError> Every invalid field of the updated configuration, or
+ *         an error if the configuration could not be written or recorded.
+ */
+pub fn update_pricing_parameters(update: &serde_json::Value) -> Result<(), PricingUpdateError> {
+    let (ws_path, config_path) = configuration_paths().map_err(PricingUpdateError::Io)?;
+    let _update_lock = SLICER_IF_STATE.configuration_update.lock().unwrap();
+    let config = {
+        let printer_config_lock = SLICER_IF_STATE.printer_configuration.lock().unwrap();
+        apply_pricing_update(
+            &printer_config_lock,
+            update,
+            &filament_profile_dir(&ws_path),
+            filament_profiles_required(),
+        )
+        .map_err(PricingUpdateError::Invalid)?
+    };
+    let previous_content = std::fs::read_to_string(&config_path).map_err(PricingUpdateError::Io)?;
+    let file: serde_json::Value = serde_json::from_str(&previous_content)
+        .map_err(|e| PricingUpdateError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))?;
+    let content = serde_json::to_string_pretty(&pricing_file_content(&file, &config))
+        .expect("Configuration files are serializable");
+    write_file_atomically(&config_path, &content).map_err(PricingUpdateError::Io)?;
+    if let Err(e) = activate_printer_configuration(config) {
+        // Orders are still priced with the previous configuration, so the file must match it
+        if let Err(restore_error) = write_file_atomically(&config_path, &previous_content) {
+            eprintln!(
+                "Failed to restore printer configuration {}: {}",
+                config_path, restore_error
+            );
+        }
+        return Err(PricingUpdateError::Io(e));
+    }
+    println!(
+        "Updated printer configuration {} as pricing version {:?}",
+        config_path,
+        get_pricing_version()
+    );
+    Ok(())
+}
+
+/**
+ * @brief Reloads the printer configuration whenever its file is modified.
+ *
+ * Polls the modification time of the file loaded at startup from a background thread.
+ *
+ * @param poll_interval Time between two checks of the file.
+ */
+pub fn watch_printer_configuration(poll_interval: Duration) {
+    let Some(config_path) = SLICER_IF_STATE
+        .printer_configuration_path
+        .lock()
+        .unwrap()
+        .clone()
+    else {
+        return;
+    };
+    std::thread::spawn(move || {
+        let mut last_modified = config_file_modification_time(&config_path);
+        loop {
+            std::thread::sleep(poll_interval);
+            let modified = config_file_modification_time(&config_path);
+            if modified.is_some() && modified != last_modified {
+                last_modified = modified;
+                // A rejected configuration is logged, and stays rejected until it is modified
+                let _ = reload_printer_configuration();
+            }
+        }
+    });
+}
+
+/**
+ * @brief Sets the time after which a running slicer process is killed.
+ *
+ * @param timeout Maximum duration of a single slicing job.
+ */
+pub fn set_slicing_timeout(timeout: Duration) {
+    *SLICER_IF_STATE.slicing_timeout.lock().unwrap() = timeout;
+}
+
+/**
+ * @brief Checks a model against the build volume of the configured printer.
+ *
+ * @param model_statistics Geometry of the model.
+ * @return BuildVolumeFit Whether the model fits, and what would make it fit.
+ */
+pub fn check_model_fits_build_volume(model_statistics: &ModelStatistics) -> BuildVolumeFit {
+    let build_volume = SLICER_IF_STATE
+        .printer_configuration
+        .lock()
+        .unwrap()
+        .build_volume
+        .map(f64::from);
+    check_build_volume_fit(model_statistics, build_volume)
+}
+
+/**
+ * @brief Returns the materials orders may be printed in.
+ *
+ * @return Vec<Material> Copy of the configured material catalogue.
+ */
+pub fn get_material_catalogue() -> Vec<Material> {
+    SLICER_IF_STATE
+        .printer_configuration
+        .lock()
+        .unwrap()
+        .materials
+        .clone()
+}
+
+/**
+ * @brief Looks up a material of the configured catalogue by name.
+ *
+ * @param name Name of the material as submitted with the order.
+ * @return Option<Material> The material, if it is catalogued.
+ */
+pub fn find_catalogued_material(name: &str) -> Option<Material> {
+    let printer_configuration = SLICER_IF_STATE.printer_configuration.lock().unwrap();
+    find_material(&printer_configuration.materials, name).cloned()
+}
+
+/**
+ * @brief Slices the submitted model and prices the order.
+ *
+ * @param order Reference to the submitted order data.
+ * @return Result<EvaluationResult, SlicerError> The priced order, or the reason why the model
+ *         could not be sliced. No price is produced for models that failed to slice.
+ */
+pub fn get_prusa_slicer_evaluation(
+    order: &SubmittedOrderData,
+) -> Result<EvaluationResult, SlicerError> {
     let current_utc_time = chrono::Utc::now();
-    let prusa_path = &*SLICER_IF_STATE.slicer_exec_path.lock().unwrap();
-    let workspace_path = &*SLICER_IF_STATE.ws_path.lock().unwrap();
-    let slicer_interface_lock = SLICER_IF_STATE.slicer_interface.lock().unwrap();
-    let print_params = slicer_interface_lock.get_expected_print_parameters(
+    // Copy everything needed out of the state, so no lock is held while the slicer runs
+    let prusa_path = SLICER_IF_STATE.slicer_exec_path.lock().unwrap().clone();
+    let workspace_path = SLICER_IF_STATE.ws_path.lock().unwrap().clone();
+    let slicer_interface = SLICER_IF_STATE.slicer_interface.lock().unwrap().clone();
+    let fallback_interface = SLICER_IF_STATE.fallback_interface.lock().unwrap().clone();
+    let slicing_timeout = *SLICER_IF_STATE.slicing_timeout.lock().unwrap();
+    let (Some(prusa_path), Some(workspace_path)) = (prusa_path, workspace_path) else {
+        return Err(SlicerError::SlicerNotFound(
+            "Slicer interface is not initialized".to_string(),
+        ));
+    };
+    let material = find_catalogued_material(&order.material_type)
+        .ok_or_else(|| SlicerError::UnknownMaterial(order.material_type.clone()))?;
+    let sliced_params = slicer_interface.get_expected_print_parameters(
         order,
-        prusa_path.as_deref().unwrap(),
-        workspace_path.as_deref().unwrap(),
+        &material,
+        &prusa_path,
+        &workspace_path,
+        slicing_timeout,
     );
+    let print_params = match (sliced_params, fallback_interface) {
+        (Err(e), Some(fallback_interface)) if is_slicer_failure(&e) => {
+            eprintln!(
+                "Slicing {} failed, estimating it from its geometry instead: {}",
+                order.model_key, e
+            );
+            fallback_interface.get_expected_print_parameters(
+                order,
+                &material,
+                &prusa_path,
+                &workspace_path,
+                slicing_timeout,
+            )?
+        }
+        (sliced_params, _) => sliced_params?,
+    };
     let printer_configuration = SLICER_IF_STATE.printer_configuration.lock().unwrap();
-    let price = calculate_the_price(&printer_configuration, print_params, order.copies_nbr);
+    let pricing_version = *SLICER_IF_STATE.pricing_version.lock().unwrap();
+    // The configuration may have been reloaded while the slicer ran, so the material is looked
+    // up again: the quote must be reproducible from the pricing version it is stored with
+    let material = find_material(&printer_configuration.materials, &order.material_type)
+        .ok_or_else(|| SlicerError::UnknownMaterial(order.material_type.clone()))?;
+    let price_breakdown = calculate_the_price(
+        &printer_configuration,
+        material,
+        &order.print_type,
+        print_params.clone(),
+        order.copies_nbr,
+    )
+    .ok_or_else(|| price_out_of_range(order.copies_nbr))?;
 
-    EvaluationResult {
+    Ok(EvaluationResult {
+        id: None,
         date: current_utc_time,
         name: order.name.clone(),
         email: order.email.clone(),
         copies_nbr: order.copies_nbr,
         file_name: order.file_name.clone(),
-        price,
+        price: price_breakdown.total,
         material_type: order.material_type.clone(),
         print_type: order.print_type.clone(),
         status: StatusType::New,
+        model_key: order.model_key.clone(),
+        model_statistics: None,
+        price_breakdown: Some(price_breakdown),
+        print_parameters: Some(print_params),
+        pricing_version,
+    })
+}
+
+/**
+ * @brief Prices a stored order again under another pricing configuration.
+ *
+ * The print parameters recorded with the order are reused, so the model is not sliced again.
+ *
+ * @param order Stored order.
+ * @param printer_configuration Configuration to price the order with.
+ * @return Result<PriceBreakdown, SlicerError> The price under that configuration, or an error
+ *         if the configuration does not offer the material or the order has no recorded print
+ *         parameters.
+ */
+pub fn reprice_order(
+    order: &EvaluationResult,
+    printer_configuration: &PrinterConfiguration,
+) -> Result<PriceBreakdown, SlicerError> {
+    let print_params = order.print_parameters.clone().ok_or_else(|| {
+        SlicerError::MetadataMissing(
+            "The order was priced before its print parameters were recorded".to_string(),
+        )
+    })?;
+    let material = find_material(&printer_configuration.materials, &order.material_type)
+        .ok_or_else(|| SlicerError::UnknownMaterial(order.material_type.clone()))?;
+    calculate_the_price(
+        printer_configuration,
+        material,
+        &order.print_type,
+        print_params,
+        order.copies_nbr,
+    )
+    .ok_or_else(|| price_out_of_range(order.copies_nbr))
+}
+
+/**
+ * @brief Validates changed pricing parameters without applying them, e.g. to try out prices.
+ *
+ * @param update Changed fields of the configuration, see `apply_pricing_update`.
+ * @return Result<PrinterConfiguration, Vec<ConfigurationFieldError>> The current configuration
+ *         with the changes, or every invalid field.
+ */
+pub fn preview_pricing_update(
+    update: &serde_json::Value,
+) -> Result<PrinterConfiguration, Vec<ConfigurationFieldError>> {
+    let ws_path = SLICER_IF_STATE.ws_path.lock().unwrap().clone();
+    let profile_dir = filament_profile_dir(ws_path.as_deref().unwrap_or("."));
+    let printer_config_lock = SLICER_IF_STATE.printer_configuration.lock().unwrap();
+    apply_pricing_update(
+        &printer_config_lock,
+        update,
+        &profile_dir,
+        filament_profiles_required(),
+    )
+}
+
+/**
+ * @brief Prices a print job from its print time and filament usage, without slicing a model.
+ *
+ * @param simulation Print job to price.
+ * @param candidate_configuration Configuration to price the job with instead of the current one.
+ * @return Result<(EvaluatedPrintingParameters, PriceBreakdown), SlicerError> The print
+ *         parameters the job was priced from and its price, or an error if the material is
+ *         not catalogued or the filament usage is missing.
+ */
+pub fn simulate_price(
+    simulation: &PriceSimulation,
+    candidate_configuration: Option<&PrinterConfiguration>,
+) -> Result<(EvaluatedPrintingParameters, PriceBreakdown), SlicerError> {
+    match candidate_configuration {
+        Some(candidate_configuration) => price_simulated_job(simulation, candidate_configuration),
+        None => {
+            // Priced on a copy, so the configuration is not locked while calculating
+            let printer_configuration = SLICER_IF_STATE
+                .printer_configuration
+                .lock()
+                .unwrap()
+                .clone();
+            price_simulated_job(simulation, &printer_configuration)
+        }
     }
 }
 
@@ -156,36 +800,51 @@ pub fn get_prusa_slicer_evaluation(order: &SubmittedOrderData) -> EvaluationResu
 mod tests {
     use super::*;
     use crate::{
-        common_utils::global_types::{PrintMaterialType, PrintType},
+        common_utils::global_types::{GeometricEstimatorSettings, PrintType},
+        common_utils::material_catalogue::MaterialPrice,
+        common_utils::model_storage::RECEIVED_ORDERS_DIR,
         prusa_slicer_interface::prusa_slicer_mock::PrusaSlicerMock,
     };
+    use std::sync::MutexGuard;
+
+    /// Serializes the tests, as they all share the global slicer interface state
+    static TEST_SERIALIZATION_LOCK: Mutex<()> = Mutex::new(());
 
     /// Helper function to reset the global state and set paths
     fn reset_state_and_setup_mocked_interface(
         ping_result: bool,
+        evaluation_error: Option<SlicerError>,
         time_result: u32,
         material_mm_result: u32,
         ws_path: Option<&str>,
         prusa_path: Option<&str>,
-    ) {
+    ) -> MutexGuard<'static, ()> {
+        let test_guard = TEST_SERIALIZATION_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
         let mut ws_path_lock = SLICER_IF_STATE.ws_path.lock().unwrap();
         let mut slicer_exec_path_lock = SLICER_IF_STATE.slicer_exec_path.lock().unwrap();
         let mut slicer_interface_lock = SLICER_IF_STATE.slicer_interface.lock().unwrap();
 
+        *SLICER_IF_STATE.fallback_interface.lock().unwrap() = None;
+        *SLICER_IF_STATE.slicer_backend.lock().unwrap() = SlicerBackend::default();
         *ws_path_lock = ws_path.map(|s| s.to_string());
         *slicer_exec_path_lock = prusa_path.map(|s| s.to_string());
-        *slicer_interface_lock = Box::new(PrusaSlicerMock {
+        *slicer_interface_lock = Arc::new(PrusaSlicerMock {
             time: time_result,
             material_mm: material_mm_result,
             ping_result,
-            material_type: PrintMaterialType::PLA, // Assuming PLA for simplicity
+            evaluation_error,
+            while_slicing: None,
         });
+        test_guard
     }
 
     #[test]
     fn test_initialize_prusa_slicer_if_successfull_ping() {
         // Set up mock with ping_result = true
-        reset_state_and_setup_mocked_interface(true, 1234, 5678, None, None);
+        let _test_guard =
+            reset_state_and_setup_mocked_interface(true, None, 1234, 5678, None, None);
 
         let ws_path = "foobar";
         let config_path = "foobar";
@@ -203,15 +862,19 @@ mod tests {
             Some(ws_path.to_string()),
             "Workspace path not set correctly"
         );
-        // We just need to check that some path was set, as the actual path 
+        // We just need to check that some path was set, as the actual path
         // comes from the configuration file
-        assert!(slicer_exec_path_lock.is_some(), "Slicer path not set correctly");
+        assert!(
+            slicer_exec_path_lock.is_some(),
+            "Slicer path not set correctly"
+        );
     }
 
     #[test]
     fn test_initialize_prusa_slicer_if_failed_ping() {
         // Set up mock with ping_result = false
-        reset_state_and_setup_mocked_interface(false, 1234, 5678, None, None);
+        let _test_guard =
+            reset_state_and_setup_mocked_interface(false, None, 1234, 5678, None, None);
 
         let ws_path = "foobar";
         let config_path = "foobar";
@@ -219,12 +882,48 @@ mod tests {
         assert!(initialize_prusa_slicer_if(ws_path, config_path).is_err());
     }
 
+    #[test]
+    fn test_configured_slicer_selects_the_backend_and_its_profiles() {
+        let _test_guard =
+            reset_state_and_setup_mocked_interface(true, None, 1234, 5678, None, None);
+        let settings: SlicerSettings = serde_json::from_str(
+            r#"{"slicer": "cura_engine", "slicer_path": "/usr/bin/CuraEngine",
+                "prusa_path": "/usr/bin/prusa-slicer"}"#,
+        )
+        .unwrap();
+        assert_eq!(settings.executable(), Some("/usr/bin/CuraEngine"));
+        select_slicer(&settings);
+        assert_eq!(
+            filament_profile_dir("ws"),
+            Path::new("ws/data_files/cura_config_files")
+        );
+        select_slicer(&SlicerSettings::default());
+        assert_eq!(
+            filament_profile_dir("ws"),
+            Path::new("ws/data_files/prusa_config_files")
+        );
+
+        // Configuration files written before the selector keep using PrusaSlicer
+        let settings: SlicerSettings =
+            serde_json::from_str(r#"{"prusa_path": "/usr/bin/prusa-slicer"}"#).unwrap();
+        assert_eq!(settings.slicer, SlicerBackend::PrusaSlicer);
+        assert_eq!(settings.executable(), Some("/usr/bin/prusa-slicer"));
+        assert!(serde_json::from_str::<SlicerSettings>(r#"{"slicer": "slic3r"}"#).is_err());
+    }
+
     #[test]
     fn test_get_prusa_slicer_evaluation_success() {
         // Set up mock and state directly
         let ws_path = "workspace_path";
         let prusa_path = "prusa_path";
-        reset_state_and_setup_mocked_interface(true, 1234, 5678, Some(ws_path), Some(prusa_path));
+        let _test_guard = reset_state_and_setup_mocked_interface(
+            true,
+            None,
+            1234,
+            5678,
+            Some(ws_path),
+            Some(prusa_path),
+        );
 
         let order = SubmittedOrderData {
             name: "John Doe".to_string(),
@@ -232,11 +931,424 @@ mod tests {
             copies_nbr: 5,
             file_name: "file.stl".to_string(),
             nbr_of_chunks: 42,
-            material_type: PrintMaterialType::PLA,
+            material_type: "PLA".to_string(),
+            print_type: PrintType::ThickStrong,
+            model_key: "model.stl".to_string(),
+        };
+
+        let result = get_prusa_slicer_evaluation(&order).unwrap();
+        assert!(
+            result.price > Money::ZERO,
+            "Evaluation result price is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_get_prusa_slicer_evaluation_propagates_slicer_error() {
+        let slicer_error = SlicerError::ModelUnprintable("Nothing to print".to_string());
+        let _test_guard = reset_state_and_setup_mocked_interface(
+            true,
+            Some(slicer_error.clone()),
+            0,
+            0,
+            Some("workspace_path"),
+            Some("prusa_path"),
+        );
+
+        let order = SubmittedOrderData {
+            name: "John Doe".to_string(),
+            email: "john.doe@example.com".to_string(),
+            copies_nbr: 1,
+            file_name: "empty.stl".to_string(),
+            nbr_of_chunks: 1,
+            material_type: "PLA".to_string(),
+            print_type: PrintType::ThickStrong,
+            model_key: "empty.stl".to_string(),
+        };
+
+        assert_eq!(
+            get_prusa_slicer_evaluation(&order).err(),
+            Some(slicer_error)
+        );
+    }
+
+    #[test]
+    fn test_get_prusa_slicer_evaluation_rejects_unknown_material() {
+        let _test_guard = reset_state_and_setup_mocked_interface(
+            true,
+            None,
+            1234,
+            5678,
+            Some("workspace_path"),
+            Some("prusa_path"),
+        );
+
+        let order = SubmittedOrderData {
+            name: "John Doe".to_string(),
+            email: "john.doe@example.com".to_string(),
+            copies_nbr: 1,
+            file_name: "file.stl".to_string(),
+            nbr_of_chunks: 1,
+            material_type: "Unobtainium".to_string(),
+            print_type: PrintType::ThickStrong,
+            model_key: "model.stl".to_string(),
+        };
+
+        assert_eq!(
+            get_prusa_slicer_evaluation(&order).err(),
+            Some(SlicerError::UnknownMaterial("Unobtainium".to_string()))
+        );
+    }
+
+    /// Sets the price of PLA, as reloading a configuration with another price would.
+    fn set_pla_price(price: MaterialPrice) {
+        let mut printer_configuration = SLICER_IF_STATE.printer_configuration.lock().unwrap();
+        for material in &mut printer_configuration.materials {
+            if material.name == "PLA" {
+                material.price = price;
+            }
+        }
+    }
+
+    fn raise_pla_price() {
+        set_pla_price(MaterialPrice::PerMetre(Money::from_pln(50)));
+    }
+
+    #[test]
+    fn test_configuration_reloaded_while_slicing_prices_the_order() {
+        let _test_guard = reset_state_and_setup_mocked_interface(
+            true,
+            None,
+            3600,
+            1000,
+            Some("workspace_path"),
+            Some("prusa_path"),
+        );
+        *SLICER_IF_STATE.slicer_interface.lock().unwrap() = Arc::new(PrusaSlicerMock {
+            time: 3600,
+            material_mm: 1000,
+            ping_result: true,
+            evaluation_error: None,
+            while_slicing: Some(raise_pla_price),
+        });
+        let original_price = find_catalogued_material("PLA").unwrap().price;
+        let order = SubmittedOrderData {
+            name: "John Doe".to_string(),
+            email: "john.doe@example.com".to_string(),
+            copies_nbr: 1,
+            file_name: "file.stl".to_string(),
+            nbr_of_chunks: 1,
+            material_type: "PLA".to_string(),
             print_type: PrintType::ThickStrong,
+            model_key: "model.stl".to_string(),
         };
 
         let result = get_prusa_slicer_evaluation(&order);
-        assert!(result.price > 0.0, "Evaluation result price is incorrect");
+        set_pla_price(original_price);
+        // One metre of the PLA of the new configuration, not of the one sliced with
+        let price_breakdown = result.unwrap().price_breakdown.unwrap();
+        assert_eq!(price_breakdown.material_cost, Money::from_pln(50));
+        assert_eq!(
+            price_breakdown.material_price,
+            Some(MaterialPrice::PerMetre(Money::from_pln(50)))
+        );
+    }
+
+    #[test]
+    fn test_failed_slicing_falls_back_to_the_geometric_estimator() {
+        let ws_path =
+            std::env::temp_dir().join(format!("estimator_fallback_{}", std::process::id()));
+        let received_dir = ws_path.join(RECEIVED_ORDERS_DIR);
+        std::fs::create_dir_all(&received_dir).unwrap();
+        let mut stl = "solid tetrahedron\n".to_string();
+        for triangle in [
+            ["0 0 0", "0 10 0", "10 0 0"],
+            ["0 0 0", "10 0 0", "0 0 10"],
+            ["0 0 0", "0 0 10", "0 10 0"],
+            ["10 0 0", "0 10 0", "0 0 10"],
+        ] {
+            stl.push_str("facet normal 0 0 0\nouter loop\n");
+            for vertex in triangle {
+                stl.push_str(&format!("vertex {}\n", vertex));
+            }
+            stl.push_str("endloop\nendfacet\n");
+        }
+        stl.push_str("endsolid tetrahedron\n");
+        std::fs::write(received_dir.join("tetrahedron.stl"), stl).unwrap();
+
+        let slicer_error = SlicerError::SlicerTimeout(Duration::from_secs(600));
+        let _test_guard = reset_state_and_setup_mocked_interface(
+            true,
+            Some(slicer_error.clone()),
+            0,
+            0,
+            ws_path.to_str(),
+            Some("prusa_path"),
+        );
+        let order = SubmittedOrderData {
+            name: "John Doe".to_string(),
+            email: "john.doe@example.com".to_string(),
+            copies_nbr: 1,
+            file_name: "tetrahedron.stl".to_string(),
+            nbr_of_chunks: 1,
+            material_type: "PLA".to_string(),
+            print_type: PrintType::ThickStrong,
+            model_key: "tetrahedron.stl".to_string(),
+        };
+        assert_eq!(
+            get_prusa_slicer_evaluation(&order).err(),
+            Some(slicer_error)
+        );
+
+        *SLICER_IF_STATE.fallback_interface.lock().unwrap() = Some(Arc::new(GeometricEstimator {
+            settings: GeometricEstimatorSettings::default(),
+        }));
+        let result = get_prusa_slicer_evaluation(&order).unwrap();
+        let print_params = result.print_parameters.unwrap();
+        assert!(print_params.estimated);
+        assert!(print_params.time > 0 && print_params.material_mm > 0);
+        assert!(result.price > Money::ZERO);
+
+        // Models the slicer refused would be refused again, so they are not estimated
+        let slicer_error = SlicerError::ModelUnprintable("Nothing to print".to_string());
+        *SLICER_IF_STATE.slicer_interface.lock().unwrap() = Arc::new(PrusaSlicerMock {
+            time: 0,
+            material_mm: 0,
+            ping_result: true,
+            evaluation_error: Some(slicer_error.clone()),
+            while_slicing: None,
+        });
+        assert_eq!(
+            get_prusa_slicer_evaluation(&order).err(),
+            Some(slicer_error)
+        );
+        std::fs::remove_dir_all(&ws_path).unwrap();
+    }
+
+    #[test]
+    fn test_reload_keeps_the_current_configuration_if_the_new_one_is_invalid() {
+        let ws_path = std::env::temp_dir().join(format!("reload_config_{}", std::process::id()));
+        std::fs::create_dir_all(ws_path.join("data_files/prusa_config_files")).unwrap();
+        let ws_path = ws_path.to_str().unwrap().to_string();
+        let _test_guard =
+            reset_state_and_setup_mocked_interface(true, None, 3600, 1000, Some(&ws_path), None);
+        let config_path = format!("{}/config.json", ws_path);
+        let previous_config_path = SLICER_IF_STATE
+            .printer_configuration_path
+            .lock()
+            .unwrap()
+            .replace(config_path.clone());
+        std::fs::write(
+            format!(
+                "{}/data_files/prusa_config_files/filament_petg.ini",
+                ws_path
+            ),
+            "temperature = 240\n",
+        )
+        .unwrap();
+        let material = r#"{"name": "PETG", "density_g_cm3": 1.27, "price": {"per_kg": 90},
+                           "hourly_rates": [{"from_hours": 0, "rate": 35}],
+                           "filament_profile": "filament_petg.ini"}"#;
+
+        std::fs::write(&config_path, format!(r#"{{"materials": [{}]}}"#, material)).unwrap();
+        let default_config = std::mem::replace(
+            &mut *SLICER_IF_STATE.printer_configuration.lock().unwrap(),
+            load_printer_configuration(&ws_path, &config_path).unwrap(),
+        );
+        assert!(reload_printer_configuration().is_ok());
+        assert!(find_catalogued_material("PETG").is_some());
+
+        // Tiers that do not start at 0 hours are rejected, and PETG is still offered
+        let invalid_material = material.replace(r#""from_hours": 0"#, r#""from_hours": 5"#);
+        std::fs::write(
+            &config_path,
+            format!(r#"{{"materials": [{}]}}"#, invalid_material),
+        )
+        .unwrap();
+        let error = reload_printer_configuration().unwrap_err();
+        assert!(error.to_string().contains("Invalid material catalogue"));
+        assert!(find_catalogued_material("PETG").is_some());
+
+        *SLICER_IF_STATE.printer_configuration.lock().unwrap() = default_config;
+        *SLICER_IF_STATE.printer_configuration_path.lock().unwrap() = previous_config_path;
+        std::fs::remove_dir_all(&ws_path).unwrap();
+    }
+
+    #[test]
+    fn test_legacy_configuration_loads_with_a_slicing_backend() {
+        let ws_path = std::env::temp_dir().join(format!("legacy_config_{}", std::process::id()));
+        std::fs::create_dir_all(&ws_path).unwrap();
+        let ws_path = ws_path.to_str().unwrap().to_string();
+        let _test_guard =
+            reset_state_and_setup_mocked_interface(true, None, 3600, 1000, Some(&ws_path), None);
+        assert!(filament_profiles_required());
+        let config_path = format!("{}/config.json", ws_path);
+        std::fs::write(
+            &config_path,
+            r#"{"material_rate_pla": 60, "material_rate_pet": 80, "material_rate_asa": 100,
+                "hourly_rate_time_threshold": [0, 10], "hourly_rate_pla_price": [30, 25],
+                "hourly_rate_pet_price": [35, 30], "hourly_rate_asa_price": [40, 35]}"#,
+        )
+        .unwrap();
+        let config = load_printer_configuration(&ws_path, &config_path).unwrap();
+        assert!(config.legacy_catalogue);
+        assert_eq!(config.materials.len(), 3);
+
+        // Catalogues listed in the file must name a filament profile for every material
+        std::fs::write(
+            &config_path,
+            r#"{"materials": [{"name": "PLA", "density_g_cm3": 1.24, "price": {"per_kg": 90},
+                               "hourly_rates": [{"from_hours": 0, "rate": 30}]}]}"#,
+        )
+        .unwrap();
+        let error = load_printer_configuration(&ws_path, &config_path)
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("filament profile"));
+        std::fs::remove_dir_all(&ws_path).unwrap();
+    }
+
+    #[test]
+    fn test_simulated_price_uses_the_current_or_a_candidate_configuration() {
+        let _test_guard =
+            reset_state_and_setup_mocked_interface(true, None, 3600, 1000, None, None);
+        let simulation = PriceSimulation {
+            material_type: "PLA".to_string(),
+            print_type: PrintType::ThickStrong,
+            copies_nbr: 2,
+            time: 3600,
+            material_mm: Some(1000),
+            material_g: None,
+        };
+        let (_, breakdown) = simulate_price(&simulation, None).unwrap();
+        assert_eq!(breakdown.copies, 2);
+        assert_eq!(breakdown.machine_time_cost, Money::from_pln(30));
+
+        // The length is derived from the weight, and a candidate configuration is not applied
+        let by_weight = PriceSimulation {
+            material_mm: None,
+            material_g: Some(breakdown.material_mass_g.unwrap()),
+            ..simulation.clone()
+        };
+        let (print_params, _) = simulate_price(&by_weight, None).unwrap();
+        assert_eq!(print_params.material_mm, 1000);
+        let candidate = preview_pricing_update(&serde_json::json!({"order_fee": "10.00"})).unwrap();
+        let (_, candidate_breakdown) = simulate_price(&simulation, Some(&candidate)).unwrap();
+        assert_eq!(candidate_breakdown.order_fee, Money::from_pln(10));
+        assert_eq!(simulate_price(&simulation, None).unwrap().1, breakdown);
+
+        let too_heavy = PriceSimulation {
+            material_mm: None,
+            material_g: Some(1e12),
+            ..simulation.clone()
+        };
+        assert!(matches!(
+            simulate_price(&too_heavy, None),
+            Err(SlicerError::ModelUnprintable(_))
+        ));
+
+        // Prices out of range are reported without poisoning the configuration lock
+        let out_of_range = PriceSimulation {
+            copies_nbr: u32::MAX,
+            time: u32::MAX,
+            ..simulation.clone()
+        };
+        assert!(matches!(
+            simulate_price(&out_of_range, None),
+            Err(SlicerError::PriceOutOfRange(_))
+        ));
+        assert!(!SLICER_IF_STATE.printer_configuration.is_poisoned());
+
+        let unknown_material = PriceSimulation {
+            material_type: "Unobtainium".to_string(),
+            ..simulation
+        };
+        assert_eq!(
+            simulate_price(&unknown_material, None).err(),
+            Some(SlicerError::UnknownMaterial("Unobtainium".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_pricing_update_is_saved_and_applied() {
+        let ws_path = std::env::temp_dir().join(format!("update_config_{}", std::process::id()));
+        std::fs::create_dir_all(ws_path.join("data_files/prusa_config_files")).unwrap();
+        let ws_path = ws_path.to_str().unwrap().to_string();
+        let _test_guard =
+            reset_state_and_setup_mocked_interface(true, None, 3600, 1000, Some(&ws_path), None);
+        let config_path = format!("{}/config.json", ws_path);
+        let previous_config_path = SLICER_IF_STATE
+            .printer_configuration_path
+            .lock()
+            .unwrap()
+            .replace(config_path.clone());
+        std::fs::write(
+            format!(
+                "{}/data_files/prusa_config_files/filament_petg.ini",
+                ws_path
+            ),
+            "temperature = 240\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &config_path,
+            r#"{"prusa_path": "/usr/bin/prusa-slicer",
+                "materials": [{"name": "PETG", "density_g_cm3": 1.27, "price": {"per_kg": 90},
+                               "hourly_rates": [{"from_hours": 0, "rate": 35}],
+                               "filament_profile": "filament_petg.ini"}]}"#,
+        )
+        .unwrap();
+        let default_config = std::mem::replace(
+            &mut *SLICER_IF_STATE.printer_configuration.lock().unwrap(),
+            load_printer_configuration(&ws_path, &config_path).unwrap(),
+        );
+
+        let update = serde_json::json!({"minimum_order_value": "30.00"});
+        assert!(update_pricing_parameters(&update).is_ok());
+        let (config, _) = get_pricing_parameters();
+        assert_eq!(config["minimum_order_value"], "30.00");
+        let file: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(file["minimum_order_value"], "30.00");
+        assert_eq!(file["prusa_path"], "/usr/bin/prusa-slicer");
+        assert!(!std::path::Path::new(&format!("{}.tmp", config_path)).exists());
+
+        // A rejected update changes neither the file nor the prices
+        let update = serde_json::json!({"minimum_order_value": "-5.00"});
+        match update_pricing_parameters(&update) {
+            Err(PricingUpdateError::Invalid(errors)) => {
+                assert_eq!(errors[0].field, "minimum_order_value")
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+        assert_eq!(get_pricing_parameters().0["minimum_order_value"], "30.00");
+        assert!(std::fs::read_to_string(&config_path)
+            .unwrap()
+            .contains("30.00"));
+
+        // Orders are sliced, so a material without a filament profile is rejected
+        let update = serde_json::json!({"materials": [
+            {"name": "PLA", "density_g_cm3": 1.24, "price": {"per_kg": 80},
+             "hourly_rates": [{"from_hours": 0, "rate": 30}]}]});
+        match update_pricing_parameters(&update) {
+            Err(PricingUpdateError::Invalid(errors)) => {
+                assert_eq!(errors[0].field, "materials[0].filament_profile")
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+
+        // If the pricing version cannot be stored, the file is restored to the prices in use
+        set_pricing_version_store(|_, _| Err(io::Error::other("database is locked")));
+        let content = std::fs::read_to_string(&config_path).unwrap();
+        let update = serde_json::json!({"minimum_order_value": "40.00"});
+        let result = update_pricing_parameters(&update);
+        *SLICER_IF_STATE.store_pricing_version_cb.lock().unwrap() = None;
+        assert!(matches!(result, Err(PricingUpdateError::Io(_))));
+        assert_eq!(get_pricing_parameters().0["minimum_order_value"], "30.00");
+        assert_eq!(std::fs::read_to_string(&config_path).unwrap(), content);
+
+        *SLICER_IF_STATE.printer_configuration.lock().unwrap() = default_config;
+        *SLICER_IF_STATE.printer_configuration_path.lock().unwrap() = previous_config_path;
+        std::fs::remove_dir_all(&ws_path).unwrap();
     }
 }
@@ -1,11 +1,13 @@
 /* IMPORTS FROM LIBRARIES */
 use std::io;
+use std::time::Duration;
 
 /* IMPORTS FROM OTHER MODULES */
 use crate::common_utils::global_traits::SlicerInterfaceImpl;
 use crate::common_utils::global_types::{
-    EvaluatedPrintingParameters, PrintMaterialType, SubmittedOrderData,
+    EvaluatedPrintingParameters, SlicerError, SubmittedOrderData,
 };
+use crate::common_utils::material_catalogue::Material;
 
 /* PRIVATE TYPES AND VARIABLES */
 
@@ -14,7 +16,9 @@ pub struct PrusaSlicerMock {
     pub time: u32,
     pub material_mm: u32,
     pub ping_result: bool,
-    pub material_type: PrintMaterialType,
+    pub evaluation_error: Option<SlicerError>,
+    /// Called while the model is "sliced", to change the state in the meantime.
+    pub while_slicing: Option<fn()>,
 }
 
 /* PRIVATE FUNCTIONS */
@@ -25,8 +29,7 @@ impl SlicerInterfaceImpl for PrusaSlicerMock {
         if self.ping_result {
             Ok(())
         } else {
-            Err(io::Error::new(
-                io::ErrorKind::Other,
+            Err(io::Error::other(
                 "Error returned from mocked ping() function",
             ))
         }
@@ -35,14 +38,22 @@ impl SlicerInterfaceImpl for PrusaSlicerMock {
     fn get_expected_print_parameters(
         &self,
         _order: &SubmittedOrderData,
+        _material: &Material,
         _slicer_path: &str,
         _ws_path: &str,
-    ) -> EvaluatedPrintingParameters {
-        EvaluatedPrintingParameters {
+        _timeout: Duration,
+    ) -> Result<EvaluatedPrintingParameters, SlicerError> {
+        if let Some(while_slicing) = self.while_slicing {
+            while_slicing();
+        }
+        if let Some(error) = &self.evaluation_error {
+            return Err(error.clone());
+        }
+        Ok(EvaluatedPrintingParameters {
             time: self.time,
             material_mm: self.material_mm,
-            material_type: self.material_type.clone(),
-        }
+            ..Default::default()
+        })
     }
 }
 
@@ -2,103 +2,663 @@
 
 /* IMPORTS FROM OTHER MODULES */
 use crate::common_utils::global_types::{
-    EvaluatedPrintingParameters, PrintMaterialType, PrinterConfiguration,
+    default_print_type_multiplier, EvaluatedPrintingParameters, HourlyBandCost, HourlyBilling,
+    PriceBreakdown, PrintType, PrinterConfiguration,
 };
+use crate::common_utils::material_catalogue::{HourlyRateTier, Material, MaterialPrice};
+use crate::common_utils::money::Money;
 
 /* PRIVATE TYPES AND VARIABLES */
 
 /* PUBLIC TYPES AND VARIABLES */
 
 /* PRIVATE FUNCTIONS */
+/**
+ * @brief Determines the mass of the filament used by one copy.
+ *
+ * The mass reported by the slicer is preferred, then the reported volume, and only then the
+ * mass derived from the filament length, diameter and density. PrusaSlicer reports 0.00 g
+ * when the filament profile sets no density, so only positive amounts are trusted.
+ *
+ * @param material Catalogued material of the order.
+ * @param print_params Filament usage reported by the slicer.
+ * @return f64 Mass in grams.
+ */
+fn material_mass_g(material: &Material, print_params: &EvaluatedPrintingParameters) -> f64 {
+    let is_reported = |amount: &f64| amount.is_finite() && *amount > 0.0;
+    print_params
+        .material_g
+        .filter(is_reported)
+        .or_else(|| {
+            print_params
+                .material_cm3
+                .filter(is_reported)
+                .map(|cm3| cm3 * material.density_g_cm3)
+        })
+        .unwrap_or_else(|| material.filament_mass_g(print_params.material_mm))
+}
+
+/**
+ * @brief Prices the filament used by one copy.
+ *
+ * @param material Catalogued material with its price per kilogram or per metre.
+ * @param material_mm Length of the filament used.
+ * @param material_g Mass of the filament used.
+ * @return Option<Money> Cost of the filament, rounded to whole grosz, or None if it is out of
+ *         range.
+ */
+fn calculate_material_cost(
+    material: &Material,
+    material_mm: u32,
+    material_g: f64,
+) -> Option<Money> {
+    match material.price {
+        MaterialPrice::PerMetre(price) => i64::from(material_mm)
+            .checked_mul(price.grosz())
+            .map(|grosz| Money::from_grosz_fraction(grosz, 1000)),
+        MaterialPrice::PerKg(price) => {
+            let material_mg = (material_g * 1000.0).round();
+            if !(material_mg.is_finite() && material_mg.abs() < i64::MAX as f64) {
+                return None;
+            }
+            (material_mg as i64)
+                .checked_mul(price.grosz())
+                .map(|grosz| Money::from_grosz_fraction(grosz, 1_000_000))
+        }
+    }
+}
+
+/// Cost of a print time at an hourly rate, None if it is out of range.
+fn time_cost(seconds: u32, rate: Money) -> Option<Money> {
+    i64::from(seconds)
+        .checked_mul(rate.grosz())
+        .map(|grosz| Money::from_grosz_fraction(grosz, 3600))
+}
+
+/**
+ * @brief Splits the print time into the parts falling into each hourly tier.
+ *
+ * @param tiers Hourly rate tiers sorted by `from_hours`.
+ * @param time_seconds Print time of one copy.
+ * @return Option<Vec<HourlyBandCost>> Time and cost of every tier the print time reaches, or
+ *         None if a cost is out of range.
+ */
+fn calculate_hourly_bands(
+    tiers: &[HourlyRateTier],
+    time_seconds: u32,
+) -> Option<Vec<HourlyBandCost>> {
+    tiers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, tier)| {
+            let band_start = tier.from_hours.saturating_mul(3600);
+            let band_end = tiers
+                .get(i + 1)
+                .map_or(u32::MAX, |next| next.from_hours.saturating_mul(3600));
+            let seconds = time_seconds.min(band_end).checked_sub(band_start)?;
+            (seconds > 0).then(|| {
+                time_cost(seconds, tier.rate).map(|cost| HourlyBandCost {
+                    from_hours: tier.from_hours,
+                    seconds,
+                    rate: tier.rate,
+                    cost,
+                })
+            })
+        })
+        .collect()
+}
 
 /* PUBLIC FUNCTIONS */
+/**
+ * @brief Prices an order from the printing parameters of its model.
+ *
+ * @param printer_configuration Hourly billing, print type adjustments, order fee, minimum order
+ *        value, quantity discounts and rounding policy.
+ * @param material Catalogued material of the order, with its price and hourly rates.
+ * @param print_type Print type of the order, which may carry a multiplier and a surcharge.
+ * @param print_params Print time and filament usage of a single copy.
+ * @param nr_copies Number of copies ordered.
+ * @return Option<PriceBreakdown> Components of the price and the total, or None if an amount
+ *         is out of range.
+ */
 pub fn calculate_the_price(
     printer_configuration: &PrinterConfiguration,
+    material: &Material,
+    print_type: &PrintType,
     print_params: EvaluatedPrintingParameters,
     nr_copies: u32,
-) -> f64 {
+) -> Option<PriceBreakdown> {
     //     Formula for pricing:
-    // Gross Price = Material Cost + 1 + Print Time * Hourly Rate
-    // Material Cost = Material Usage * Material Rate / 1000
-    // Material Rate PLA = 60 groszy / m
-    // Material Rate PET = 80 groszy / m
-    // Material Rate ASA = 100 groszy / m
-    // Hourly Rate PLA:
-    // <10H = 30zl / H
-    // 10-100H = 25zl / H
-    // 100 = 20zl / H
-    // Hourly Rate PET + 5zl PLA Rate
-    // Hourly Rate ASA + 5zl PET Rate
+    // Gross Price = max(Copies Price - Quantity Discount + Order Fee, Minimum Order Value)
+    // Copies Price = ((Material Cost + Print Time Cost) * Print Type Multiplier
+    //                + Print Type Surcharge) * Number of Copies
+    // Quantity Discount = Copies Price * percent of the last bracket the number of copies reaches
+    // Material Cost = Material Usage (m) * Price per m
+    //              or Material Mass (kg) * Price per kg, the mass as reported by the slicer
+    // Print Time Cost = Print Time * rate of the tier the print time falls in,
+    //                  or the sum of the parts of the print time within every tier times
+    //                  the rate of that tier, depending on the billing mode
 
     let time_seconds = print_params.time;
     let material_mm = print_params.material_mm;
+    let material_g = material_mass_g(material, &print_params);
 
-    // Select material rate based on material type
-    let material_rate_cents_per_m = match print_params.material_type {
-        PrintMaterialType::PLA => printer_configuration.material_rate_pla,
-        PrintMaterialType::PET => printer_configuration.material_rate_pet,
-        PrintMaterialType::ASA => printer_configuration.material_rate_asa,
-    };
+    // Find the tier the print time falls in, the tiers are sorted by their start
+    let tiers = &material.hourly_rates;
+    let rate_index = tiers
+        .iter()
+        .rposition(|tier| time_seconds > tier.from_hours.saturating_mul(3600))
+        .unwrap_or(0);
+    let hourly_tier = tiers[rate_index];
 
-    // Find the appropriate hourly rate based on time thresholds
-    let time_thresholds = printer_configuration.hourly_rate_time_threshold;
-    let mut rate_index = 0;
-    for i in 0..time_thresholds.len() {
-        let print_time_lower_bound_seconds = time_thresholds[i] * 3600; // Convert hours to seconds
-        if time_seconds > print_time_lower_bound_seconds {
-            rate_index = i;
-        } else {
-            break;
+    // Price calculation, each component is rounded to whole grosz
+    let material_cost = calculate_material_cost(material, material_mm, material_g)?;
+    let (print_time_cost, hourly_bands) = match printer_configuration.hourly_billing {
+        HourlyBilling::WholeJob => (time_cost(time_seconds, hourly_tier.rate)?, Vec::new()),
+        HourlyBilling::Marginal => {
+            let bands = calculate_hourly_bands(tiers, time_seconds)?;
+            let cost = bands
+                .iter()
+                .try_fold(Money::ZERO, |sum, band| sum.checked_add(band.cost))?;
+            (cost, bands)
         }
-    }
-
-    let hourly_rate_pln = match print_params.material_type {
-        PrintMaterialType::PLA => printer_configuration.hourly_rate_pla_price[rate_index],
-        PrintMaterialType::PET => printer_configuration.hourly_rate_pet_price[rate_index],
-        PrintMaterialType::ASA => printer_configuration.hourly_rate_asa_price[rate_index],
     };
-
-    // Price calculation
-    let material_cost_pln = ((material_mm * material_rate_cents_per_m) as f64) / (1000.0 * 100.0);
-    let print_time_cost_pln = (time_seconds * hourly_rate_pln) as f64 / 3600.0; // Convert seconds to hours
-    let gross_unit_price = material_cost_pln + print_time_cost_pln;
-    let extra_fee_per_each_order = 1.0; // PLN
-    return gross_unit_price * nr_copies as f64 + extra_fee_per_each_order;
+    let (print_type_multiplier, print_type_surcharge) = printer_configuration
+        .print_type_adjustments
+        .iter()
+        .find(|adjustment| adjustment.print_type == *print_type)
+        .map_or(
+            (default_print_type_multiplier(), Money::ZERO),
+            |adjustment| (adjustment.multiplier, adjustment.surcharge),
+        );
+    let base_unit_price = material_cost.checked_add(print_time_cost)?;
+    let gross_unit_price = base_unit_price
+        .multiplied_by(print_type_multiplier)?
+        .checked_add(print_type_surcharge)?;
+    let copies_price = gross_unit_price.checked_mul(nr_copies)?;
+    let quantity_discount_percent = printer_configuration
+        .quantity_discounts
+        .iter()
+        .rev()
+        .find(|discount| nr_copies >= discount.from_copies)
+        .map_or(0.0, |discount| discount.percent);
+    let quantity_discount = copies_price.multiplied_by(quantity_discount_percent / 100.0)?;
+    let order_price = copies_price
+        .checked_sub(quantity_discount)?
+        .checked_add(printer_configuration.order_fee)?;
+    let minimum_order_adjustment = if order_price < printer_configuration.minimum_order_value {
+        printer_configuration
+            .minimum_order_value
+            .checked_sub(order_price)?
+    } else {
+        Money::ZERO
+    };
+    let unrounded_total = order_price.checked_add(minimum_order_adjustment)?;
+    let total = unrounded_total.round(&printer_configuration.price_rounding)?;
+    Some(PriceBreakdown {
+        material_cost,
+        material_price: Some(material.price),
+        material_mass_g: Some(material_g),
+        slicer_filament_cost: print_params.filament_cost,
+        machine_time_cost: print_time_cost,
+        hourly_billing: printer_configuration.hourly_billing,
+        hourly_tier: rate_index,
+        hourly_tier_from_hours: hourly_tier.from_hours,
+        hourly_rate: hourly_tier.rate,
+        hourly_bands,
+        print_type_multiplier,
+        print_type_surcharge,
+        print_type_adjustment: gross_unit_price.checked_sub(base_unit_price)?,
+        order_fee: printer_configuration.order_fee,
+        per_copy_subtotal: gross_unit_price,
+        copies: nr_copies,
+        quantity_discount_percent,
+        quantity_discount,
+        minimum_order_adjustment,
+        rounding_adjustment: total.checked_sub(unrounded_total)?,
+        total,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common_utils::global_types::{
+        default_build_volume, PrintTypeAdjustment, QuantityDiscount,
+    };
+    use crate::common_utils::material_catalogue::{find_material, LegacyMaterialRates};
+    use crate::common_utils::money::{RoundingMode, RoundingPolicy};
+    use crate::gcode_metadata::read_gcode_metadata;
+    use crate::prusa_slicer_interface::slicer_process::read_output_gcode_file;
 
     fn default_printer_configuration() -> PrinterConfiguration {
         PrinterConfiguration {
-            material_rate_pla: 60, // groszy per m
-            material_rate_pet: 80,
-            material_rate_asa: 100,
-            hourly_rate_time_threshold: [0, 10, 100], // hours
-            hourly_rate_pla_price: [30, 25, 20],      // PLN per hour
-            hourly_rate_pet_price: [35, 30, 25],
-            hourly_rate_asa_price: [40, 35, 30],
+            build_volume: default_build_volume(),
+            materials: LegacyMaterialRates {
+                material_rate_pla: 60, // groszy per m
+                material_rate_pet: 80,
+                material_rate_asa: 100,
+                hourly_rate_time_threshold: vec![0, 10, 100], // hours
+                hourly_rate_pla_price: [30, 25, 20].map(Money::from_pln).to_vec(), // PLN per hour
+                hourly_rate_pet_price: [35, 30, 25].map(Money::from_pln).to_vec(),
+                hourly_rate_asa_price: [40, 35, 30].map(Money::from_pln).to_vec(),
+            }
+            .into_catalogue()
+            .unwrap(),
+            hourly_billing: HourlyBilling::WholeJob,
+            print_type_adjustments: Vec::new(),
+            order_fee: Money::from_pln(1),
+            minimum_order_value: Money::ZERO,
+            quantity_discounts: Vec::new(),
+            price_rounding: RoundingPolicy::default(),
+            legacy_catalogue: true,
         }
     }
 
+    fn length_only_print_params(time: u32, material_mm: u32) -> EvaluatedPrintingParameters {
+        EvaluatedPrintingParameters {
+            time,
+            material_mm,
+            ..Default::default()
+        }
+    }
+
+    fn material<'a>(printer_config: &'a PrinterConfiguration, name: &str) -> &'a Material {
+        find_material(&printer_config.materials, name).unwrap()
+    }
+
     #[test]
     fn test_calculate_the_price_simple_pla() {
         let printer_config = default_printer_configuration();
+        let print_params = length_only_print_params(3600, 1000); // 1 hour, 1 meter
+        let nr_copies = 7;
+        let pla = material(&printer_config, "PLA");
+        let price = calculate_the_price(
+            &printer_config,
+            pla,
+            &PrintType::ThickStrong,
+            print_params,
+            nr_copies,
+        )
+        .unwrap()
+        .total;
+        println!("Price for 7 copies of PLA: {} PLN", price);
+        // 0.60 PLN for material + 30 PLN for 1 hour per copy, + 1 PLN extra fee
+        assert_eq!(price, Money::from_grosz(7 * (60 + 3000) + 100));
+    }
+
+    #[test]
+    fn test_price_breakdown_reports_the_applied_tier() {
+        let printer_config = default_printer_configuration();
+        let print_params = length_only_print_params(20 * 3600, 10000); // 20 hours, 10 meters
+        let pet = material(&printer_config, "PET");
+        let breakdown = calculate_the_price(
+            &printer_config,
+            pet,
+            &PrintType::ThickStrong,
+            print_params,
+            2,
+        )
+        .unwrap();
+        assert_eq!(breakdown.hourly_tier, 1);
+        assert_eq!(breakdown.hourly_tier_from_hours, 10);
+        assert_eq!(breakdown.hourly_rate, Money::from_pln(30));
+        assert_eq!(breakdown.material_cost, Money::from_pln(8));
+        assert_eq!(breakdown.machine_time_cost, Money::from_pln(600));
+        assert_eq!(breakdown.per_copy_subtotal, Money::from_pln(608));
+        assert_eq!(breakdown.copies, 2);
+        assert_eq!(
+            breakdown.total,
+            Money::from_pln(2 * 608)
+                .checked_add(breakdown.order_fee)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_total_follows_the_rounding_policy() {
+        let mut printer_config = default_printer_configuration();
+        printer_config.price_rounding = RoundingPolicy {
+            mode: RoundingMode::Up,
+            step: Money::from_grosz(10),
+        };
+        let print_params = length_only_print_params(1000, 100); // 8.33 + 0.06 PLN
+        let pla = material(&printer_config, "PLA");
+        let breakdown = calculate_the_price(
+            &printer_config,
+            pla,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        )
+        .unwrap();
+        assert_eq!(breakdown.per_copy_subtotal, Money::from_grosz(839));
+        assert_eq!(breakdown.total, Money::from_grosz(940));
+        assert_eq!(breakdown.rounding_adjustment, Money::from_grosz(1));
+    }
+
+    #[test]
+    fn test_material_priced_per_kg() {
+        let printer_config = default_printer_configuration();
+        let petg = Material {
+            name: "PETG".to_string(),
+            price: MaterialPrice::PerKg(Money::from_pln(100)),
+            ..material(&printer_config, "PET").clone()
+        };
+        let print_params = length_only_print_params(0, 10000); // 30.547 g of 1.75 mm filament at 1.27 g/cm3
+        let breakdown = calculate_the_price(
+            &printer_config,
+            &petg,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        )
+        .unwrap();
+        assert_eq!(breakdown.material_cost, Money::from_grosz(305));
+        assert_eq!(
+            breakdown.material_price,
+            Some(MaterialPrice::PerKg(Money::from_pln(100)))
+        );
+        assert!((breakdown.material_mass_g.unwrap() - 30.547).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_mass_reported_by_the_slicer_is_preferred() {
+        let printer_config = default_printer_configuration();
+        let petg = Material {
+            name: "PETG".to_string(),
+            price: MaterialPrice::PerKg(Money::from_pln(100)),
+            ..material(&printer_config, "PET").clone()
+        };
         let print_params = EvaluatedPrintingParameters {
-            time: 3600,        // 1 hour
-            material_mm: 1000, // 1 meter
-            material_type: PrintMaterialType::PLA,
+            material_g: Some(40.0),
+            material_cm3: Some(31.5),
+            filament_cost: Some(Money::from_grosz(370)),
+            ..length_only_print_params(0, 10000)
         };
-        let nr_copies = 7;
-        let price = calculate_the_price(&printer_config, print_params, nr_copies);
-        println!("Price for 7 copies of PLA: {:.2} PLN", price);
-        let expected = 7.0 * (0.6 + 30.0) + 1.0; // 0.6 PLN for material + 30 PLN for 1 hour + 1 PLN extra fee
-        assert!(
-            (price - expected).abs() < 1e-2,
-            "price: {}, expected: {}",
-            price,
-            expected
+        let breakdown = calculate_the_price(
+            &printer_config,
+            &petg,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        )
+        .unwrap();
+        assert_eq!(breakdown.material_cost, Money::from_pln(4));
+        assert_eq!(breakdown.material_mass_g, Some(40.0));
+        assert_eq!(breakdown.slicer_filament_cost, Some(Money::from_grosz(370)));
+
+        // Without the mass, the volume is converted with the catalogue density
+        let print_params = EvaluatedPrintingParameters {
+            material_cm3: Some(31.5),
+            ..length_only_print_params(0, 10000)
+        };
+        let breakdown = calculate_the_price(
+            &printer_config,
+            &petg,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        )
+        .unwrap();
+        assert_eq!(breakdown.material_cost, Money::from_grosz(400));
+        assert!((breakdown.material_mass_g.unwrap() - 40.005).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_costs_out_of_range_are_reported() {
+        let printer_config = default_printer_configuration();
+        let petg = Material {
+            name: "PETG".to_string(),
+            price: MaterialPrice::PerKg(Money::from_pln(100)),
+            ..material(&printer_config, "PET").clone()
+        };
+        let print_params = EvaluatedPrintingParameters {
+            material_g: Some(1e300),
+            ..length_only_print_params(0, 1)
+        };
+        let price = calculate_the_price(
+            &printer_config,
+            &petg,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        );
+        assert_eq!(price, None);
+
+        let print_params = length_only_print_params(u32::MAX, 1000);
+        let price = calculate_the_price(
+            &printer_config,
+            material(&printer_config, "PLA"),
+            &PrintType::ThickStrong,
+            print_params,
+            u32::MAX,
+        );
+        assert_eq!(price, None);
+    }
+
+    #[test]
+    fn test_zero_mass_reported_by_the_slicer_is_ignored() {
+        let printer_config = default_printer_configuration();
+        let petg = Material {
+            name: "PETG".to_string(),
+            price: MaterialPrice::PerKg(Money::from_pln(100)),
+            ..material(&printer_config, "PET").clone()
+        };
+        // A filament profile without a density makes PrusaSlicer report 0.00 g
+        let gcode_path =
+            std::env::temp_dir().join(format!("zero_mass_{}.gcode", std::process::id()));
+        std::fs::write(
+            &gcode_path,
+            "; filament used [mm] = 10000.00\n\
+             ; filament used [cm3] = 31.50\n\
+             ; filament used [g] = 0.00\n\
+             ; filament cost = 0.00\n\
+             ; total filament used [g] = 0.00\n\
+             ; total filament cost = 0.00\n\
+             ; estimated printing time (normal mode) = 0s\n",
+        )
+        .unwrap();
+        let print_params =
+            read_output_gcode_file(gcode_path.to_str().unwrap(), read_gcode_metadata).unwrap();
+        std::fs::remove_file(&gcode_path).ok();
+        assert_eq!(print_params.material_g, Some(0.0));
+        let breakdown = calculate_the_price(
+            &printer_config,
+            &petg,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        )
+        .unwrap();
+        assert_eq!(breakdown.material_cost, Money::from_grosz(400));
+
+        // Without any usable amount, the mass is derived from the length
+        let print_params = EvaluatedPrintingParameters {
+            material_g: Some(f64::NAN),
+            material_cm3: Some(0.0),
+            ..length_only_print_params(0, 10000)
+        };
+        let breakdown = calculate_the_price(
+            &printer_config,
+            &petg,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        )
+        .unwrap();
+        assert_eq!(breakdown.material_cost, Money::from_grosz(305));
+    }
+
+    #[test]
+    fn test_marginal_billing_charges_every_band_at_its_rate() {
+        let mut printer_config = default_printer_configuration();
+        printer_config.hourly_billing = HourlyBilling::Marginal;
+        let pla = Material {
+            hourly_rates: [(0, 30), (10, 25), (100, 20), (200, 15)]
+                .map(|(from_hours, rate)| HourlyRateTier {
+                    from_hours,
+                    rate: Money::from_pln(rate),
+                })
+                .to_vec(),
+            ..material(&printer_config, "PLA").clone()
+        };
+        let print_params = length_only_print_params(250 * 3600, 0); // 250 hours
+        let breakdown = calculate_the_price(
+            &printer_config,
+            &pla,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        )
+        .unwrap();
+        // 10 h at 30, 90 h at 25, 100 h at 20 and 50 h at 15 PLN/h
+        assert_eq!(
+            breakdown.machine_time_cost,
+            Money::from_pln(300 + 2250 + 2000 + 750)
+        );
+        assert_eq!(breakdown.hourly_tier, 3);
+        assert_eq!(breakdown.hourly_tier_from_hours, 200);
+        assert_eq!(breakdown.hourly_bands.len(), 4);
+        assert_eq!(breakdown.hourly_bands[3].seconds, 50 * 3600);
+
+        // The whole job is billed at the rate of the fourth tier otherwise
+        printer_config.hourly_billing = HourlyBilling::WholeJob;
+        let print_params = length_only_print_params(250 * 3600, 0);
+        let breakdown = calculate_the_price(
+            &printer_config,
+            &pla,
+            &PrintType::ThickStrong,
+            print_params,
+            1,
+        )
+        .unwrap();
+        assert_eq!(breakdown.machine_time_cost, Money::from_pln(250 * 15));
+        assert!(breakdown.hourly_bands.is_empty());
+    }
+
+    #[test]
+    fn test_print_type_multiplier_and_surcharge_apply_per_copy() {
+        let mut printer_config = default_printer_configuration();
+        printer_config.print_type_adjustments = vec![PrintTypeAdjustment {
+            print_type: PrintType::PreciseSoft,
+            multiplier: 1.15,
+            surcharge: Money::from_pln(2),
+        }];
+        let pla = material(&printer_config, "PLA");
+        let print_params = length_only_print_params(3600, 1000); // 30.60 PLN per copy
+        let breakdown = calculate_the_price(
+            &printer_config,
+            pla,
+            &PrintType::PreciseSoft,
+            print_params,
+            3,
+        )
+        .unwrap();
+        // 30.60 * 1.15 = 35.19 PLN, plus 2 PLN per copy
+        assert_eq!(breakdown.per_copy_subtotal, Money::from_grosz(3719));
+        assert_eq!(breakdown.print_type_adjustment, Money::from_grosz(659));
+        assert_eq!(breakdown.print_type_multiplier, 1.15);
+        assert_eq!(breakdown.print_type_surcharge, Money::from_pln(2));
+        assert_eq!(breakdown.total, Money::from_grosz(3 * 3719 + 100));
+
+        // Print types without an adjustment keep their price
+        let print_params = length_only_print_params(3600, 1000);
+        let breakdown = calculate_the_price(
+            &printer_config,
+            pla,
+            &PrintType::ThickStrong,
+            print_params,
+            3,
+        )
+        .unwrap();
+        assert_eq!(breakdown.per_copy_subtotal, Money::from_grosz(3060));
+        assert_eq!(breakdown.print_type_adjustment, Money::ZERO);
+        assert_eq!(breakdown.print_type_multiplier, 1.0);
+    }
+
+    #[test]
+    fn test_print_type_adjustments_are_validated() {
+        let config = |adjustments: &str| {
+            serde_json::from_str::<PrinterConfiguration>(&format!(
+                r#"{{"materials": [], "print_type_adjustments": {}}}"#,
+                adjustments
+            ))
+        };
+        let parsed = config(r#"[{"print_type": "PreciseSoft", "surcharge": "2.50"}]"#).unwrap();
+        assert_eq!(parsed.print_type_adjustments[0].multiplier, 1.0);
+        assert_eq!(
+            parsed.print_type_adjustments[0].surcharge,
+            Money::from_grosz(250)
         );
+        assert!(config(r#"[{"print_type": "PreciseSoft", "multiplier": 0}]"#).is_err());
+        assert!(config(r#"[{"print_type": "PreciseSoft", "multiplier": 1e15}]"#).is_err());
+        assert!(config(r#"[{"print_type": "ThickSoft", "surcharge": -1}]"#).is_err());
+        assert!(config(r#"[{"print_type": "ThickSoft"}, {"print_type": "ThickSoft"}]"#).is_err());
+    }
+
+    #[test]
+    fn test_quantity_discount_order_fee_and_minimum_order_value() {
+        let mut printer_config = default_printer_configuration();
+        printer_config.order_fee = Money::from_pln(5);
+        printer_config.minimum_order_value = Money::from_pln(50);
+        printer_config.quantity_discounts = vec![
+            QuantityDiscount {
+                from_copies: 10,
+                percent: 10.0,
+            },
+            QuantityDiscount {
+                from_copies: 50,
+                percent: 20.0,
+            },
+        ];
+        let pla = material(&printer_config, "PLA");
+        let price = |nr_copies| {
+            let print_params = length_only_print_params(3600, 1000); // 30.60 PLN per copy
+            calculate_the_price(
+                &printer_config,
+                pla,
+                &PrintType::ThickStrong,
+                print_params,
+                nr_copies,
+            )
+            .unwrap()
+        };
+
+        // A single copy is raised to the minimum order value
+        let breakdown = price(1);
+        assert_eq!(breakdown.quantity_discount, Money::ZERO);
+        assert_eq!(breakdown.minimum_order_adjustment, Money::from_grosz(1440));
+        assert_eq!(breakdown.total, Money::from_pln(50));
+
+        // 10 copies get 10% off, 60 copies 20% off, the order fee is not discounted
+        let breakdown = price(10);
+        assert_eq!(breakdown.quantity_discount_percent, 10.0);
+        assert_eq!(breakdown.quantity_discount, Money::from_grosz(3060));
+        assert_eq!(breakdown.total, Money::from_grosz(27540 + 500));
+        let breakdown = price(60);
+        assert_eq!(breakdown.quantity_discount_percent, 20.0);
+        assert_eq!(breakdown.total, Money::from_grosz(146880 + 500));
+        assert_eq!(breakdown.minimum_order_adjustment, Money::ZERO);
+    }
+
+    #[test]
+    fn test_order_pricing_is_validated() {
+        let config = |order_pricing: &str| {
+            serde_json::from_str::<PrinterConfiguration>(&format!(
+                r#"{{"materials": [], {}}}"#,
+                order_pricing
+            ))
+        };
+        let parsed = config(r#""minimum_order_value": "20.00""#).unwrap();
+        assert_eq!(parsed.order_fee, Money::from_pln(1));
+        assert_eq!(parsed.minimum_order_value, Money::from_pln(20));
+        assert!(config(r#""order_fee": -1"#).is_err());
+        assert!(config(r#""quantity_discounts": [{"from_copies": 10, "percent": 100}]"#).is_err());
+        assert!(config(
+            r#""quantity_discounts": [{"from_copies": 50, "percent": 20},
+                                      {"from_copies": 10, "percent": 10}]"#
+        )
+        .is_err());
     }
 }
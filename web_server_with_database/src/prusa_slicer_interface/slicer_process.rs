@@ -0,0 +1,401 @@
+/* IMPORTS FROM LIBRARIES */
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use strum::IntoEnumIterator;
+
+/* IMPORTS FROM OTHER MODULES */
+use crate::common_utils::global_types::{
+    EvaluatedPrintingParameters, GcodeMetadata, PrintType, SlicerBackend, SlicerError,
+};
+use crate::common_utils::money::Money;
+
+/* PRIVATE TYPES AND VARIABLES */
+/// Phrases of slicer error messages that mean the model itself cannot be printed. They are
+/// whole phrases, so errors that merely mention an empty setting or path are not blamed on
+/// the model.
+const UNPRINTABLE_MODEL_MESSAGES: [&str; 10] = [
+    "the supplied file is empty",
+    "because it's empty",
+    "empty mesh",
+    "no extrusions",
+    "nothing to print",
+    "nothing to be sliced",
+    "could not fit",
+    "outside of the print volume",
+    "failed loading",
+    "failed to load",
+];
+
+/// How often a running slicer process is checked for completion.
+const SLICER_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/* PUBLIC TYPES AND VARIABLES */
+/// Reads the metadata of a G-code file in the format of one slicer.
+pub type GcodeMetadataReader = fn(&Path) -> io::Result<GcodeMetadata>;
+
+/* PRIVATE FUNCTIONS */
+
+/* PUBLIC FUNCTIONS */
+pub fn io_error(e: io::Error) -> SlicerError {
+    SlicerError::Io(e.to_string())
+}
+
+/**
+ * @brief Turns a non-zero slicer exit into an error.
+ *
+ * Failures caused by the model (empty mesh, nothing to extrude, model off the bed) are told
+ * apart from other slicer failures, so the client can be asked for a different model.
+ *
+ * @param exit_code Exit code of the slicer, if it was not killed by a signal.
+ * @param stderr Standard error output of the slicer.
+ * @return SlicerError The classified error.
+ */
+pub fn classify_slicer_failure(exit_code: Option<i32>, stderr: &str) -> SlicerError {
+    let stderr = stderr.trim().to_string();
+    let stderr_lowercase = stderr.to_lowercase();
+    if UNPRINTABLE_MODEL_MESSAGES
+        .iter()
+        .any(|message| stderr_lowercase.contains(message))
+    {
+        return SlicerError::ModelUnprintable(stderr);
+    }
+    SlicerError::SlicerFailed { exit_code, stderr }
+}
+
+/**
+ * @brief Runs the slicer and kills it if it does not finish in time.
+ *
+ * Standard error is collected on a helper thread so that a chatty slicer cannot block on a
+ * full pipe while it is being waited for.
+ *
+ * @param command Fully configured slicer command.
+ * @param timeout Maximum time the slicer may run.
+ * @return Result<(ExitStatus, String), SlicerError> Exit status and standard error output.
+ */
+pub fn run_slicer_with_timeout(
+    command: &mut Command,
+    timeout: Duration,
+) -> Result<(ExitStatus, String), SlicerError> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            eprintln!("Error running the slicer: {}", e);
+            match e.kind() {
+                io::ErrorKind::NotFound | io::ErrorKind::PermissionDenied => {
+                    SlicerError::SlicerNotFound(format!(
+                        "{}: {}",
+                        command.get_program().to_string_lossy(),
+                        e
+                    ))
+                }
+                _ => io_error(e),
+            }
+        })?;
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        thread::spawn(move || {
+            let mut output = String::new();
+            stderr.read_to_string(&mut output).ok();
+            output
+        })
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait().map_err(io_error)? {
+            Some(status) => break status,
+            None if Instant::now() >= deadline => {
+                eprintln!("The slicer exceeded {:?}, killing it", timeout);
+                child.kill().ok();
+                child.wait().ok();
+                return Err(SlicerError::SlicerTimeout(timeout));
+            }
+            None => thread::sleep(SLICER_POLL_INTERVAL),
+        }
+    };
+    let stderr = stderr_reader
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    Ok((status, stderr))
+}
+
+/**
+ * @brief Returns the path of the print profile of a print type, e.g.
+ *        `data_files/orca_config_files/orca_config_ThickStrong.json`.
+ *
+ * @param ws_path Path to the workspace directory.
+ * @param backend Slicer the profile is written for.
+ * @param print_type Print type of the profile.
+ * @param extension Extension of the profile files of the slicer.
+ * @return PathBuf Path of the profile.
+ */
+pub fn print_profile_path(
+    ws_path: &str,
+    backend: SlicerBackend,
+    print_type: &PrintType,
+    extension: &str,
+) -> PathBuf {
+    Path::new(ws_path).join(backend.profile_dir()).join(format!(
+        "{}_config_{}.{}",
+        backend.profile_prefix(),
+        print_type,
+        extension
+    ))
+}
+
+/**
+ * @brief Checks that the print profiles of all print types and the other profiles the slicer
+ *        needs are present.
+ *
+ * @param ws_path Path to the workspace directory.
+ * @param backend Slicer the profiles are written for.
+ * @param extension Extension of the print profiles of the slicer.
+ * @param other_profiles Names of further files required in the profile directory.
+ * @return io::Result<()> A `NotFound` error naming the first missing profile.
+ */
+pub fn check_profiles_exist(
+    ws_path: &str,
+    backend: SlicerBackend,
+    extension: &str,
+    other_profiles: &[&str],
+) -> io::Result<()> {
+    let profile_dir = Path::new(ws_path).join(backend.profile_dir());
+    let profiles = PrintType::iter()
+        .map(|print_type| print_profile_path(ws_path, backend, &print_type, extension))
+        .chain(other_profiles.iter().map(|name| profile_dir.join(name)));
+    for profile in profiles {
+        if !profile.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} profile not found: {}", backend, profile.display()),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/**
+ * @brief Reads the printing parameters of one copy from the G-code written by the slicer.
+ *
+ * @param gcode_file_path Path of the G-code file.
+ * @param read_metadata Reader of the G-code format of the slicer.
+ * @return Result<EvaluatedPrintingParameters, SlicerError> The parameters, or an error if the
+ *         file cannot be read or lacks the print time or the filament length.
+ */
+pub fn read_output_gcode_file(
+    gcode_file_path: &str,
+    read_metadata: GcodeMetadataReader,
+) -> Result<EvaluatedPrintingParameters, SlicerError> {
+    let metadata = read_metadata(Path::new(gcode_file_path)).map_err(|e| {
+        SlicerError::Io(format!(
+            "Failed to read G-code file {}: {}",
+            gcode_file_path, e
+        ))
+    })?;
+    println!("Extracted G-code metadata: {:?}", metadata);
+    match (metadata.print_time(), metadata.filament_mm()) {
+        (Some(time), Some(material_mm)) => Ok(EvaluatedPrintingParameters {
+            time,
+            material_mm: material_mm as u32,
+            material_g: metadata.filament_g(),
+            material_cm3: metadata.filament_cm3(),
+            filament_cost: metadata.filament_total_cost().and_then(Money::from_pln_f64),
+            silent_mode_time: metadata.silent_mode_time,
+            layer_count: metadata.layer_count,
+            max_z_mm: metadata.max_layer_z,
+            wipe_tower_g: metadata.wipe_tower_g,
+            extrusion: metadata.extrusion,
+            estimated: false,
+        }),
+        (None, _) => Err(SlicerError::MetadataMissing(format!(
+            "estimated printing time not found in {}",
+            gcode_file_path
+        ))),
+        (_, None) => Err(SlicerError::MetadataMissing(format!(
+            "filament usage not found in {}",
+            gcode_file_path
+        ))),
+    }
+}
+
+/* TESTS */
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gcode_metadata::{read_cura_gcode_metadata, read_gcode_metadata};
+
+    fn write_gcode_file(name: &str, content: &str) -> String {
+        let path = std::env::temp_dir().join(format!("{}_{}.gcode", name, std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_classify_slicer_failure() {
+        assert_eq!(
+            classify_slicer_failure(Some(1), "Objects could not fit on the bed\n"),
+            SlicerError::ModelUnprintable("Objects could not fit on the bed".to_string())
+        );
+        assert_eq!(
+            classify_slicer_failure(
+                Some(1),
+                "The supplied file couldn't be read because it's empty"
+            ),
+            SlicerError::ModelUnprintable(
+                "The supplied file couldn't be read because it's empty".to_string()
+            )
+        );
+        assert!(matches!(
+            classify_slicer_failure(Some(1), "Error: the output path is empty"),
+            SlicerError::SlicerFailed { .. }
+        ));
+        assert_eq!(
+            classify_slicer_failure(Some(2), "Unknown option --foo"),
+            SlicerError::SlicerFailed {
+                exit_code: Some(2),
+                stderr: "Unknown option --foo".to_string()
+            }
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_slicer_with_timeout_kills_runaway_process() {
+        let started = Instant::now();
+        let result = run_slicer_with_timeout(
+            Command::new("sh").arg("-c").arg("sleep 30"),
+            Duration::from_millis(200),
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            SlicerError::SlicerTimeout(Duration::from_millis(200))
+        );
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_slicer_with_timeout_collects_stderr() {
+        let (status, stderr) = run_slicer_with_timeout(
+            Command::new("sh")
+                .arg("-c")
+                .arg("echo 'No extrusions' >&2; exit 3"),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+        assert_eq!(status.code(), Some(3));
+        assert_eq!(stderr.trim(), "No extrusions");
+    }
+
+    #[test]
+    fn test_run_slicer_with_timeout_reports_missing_slicer() {
+        assert!(matches!(
+            run_slicer_with_timeout(
+                &mut Command::new("does-not-exist-slicer"),
+                Duration::from_secs(1)
+            ),
+            Err(SlicerError::SlicerNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_output_gcode_file() {
+        let path = write_gcode_file(
+            "complete",
+            "; filament used [mm] = 1234.56\n; estimated printing time (normal mode) = 1h 2m 3s\n",
+        );
+        let params = read_output_gcode_file(&path, read_gcode_metadata).unwrap();
+        assert_eq!(params.time, 3723);
+        assert_eq!(params.material_mm, 1234);
+        assert_eq!(params.material_g, None);
+        assert_eq!(params.filament_cost, None);
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_read_output_gcode_file_with_filament_mass_and_cost() {
+        let path = write_gcode_file(
+            "mass_and_cost",
+            "; filament used [mm] = 1234.56, 100.00\n\
+             ; filament used [cm3] = 2.97, 0.24\n\
+             ; filament used [g] = 3.68, 0.30\n\
+             ; filament cost = 0.09, 0.01\n\
+             ; total filament used [g] = 3.98\n\
+             ; total filament cost = 0.10\n\
+             ; estimated printing time (normal mode) = 2m 3s\n",
+        );
+        let params = read_output_gcode_file(&path, read_gcode_metadata).unwrap();
+        assert_eq!(params.time, 123);
+        assert!((params.material_g.unwrap() - 3.98).abs() < 1e-9);
+        assert!((params.material_cm3.unwrap() - 3.21).abs() < 1e-9);
+        assert_eq!(params.filament_cost, Some(Money::from_grosz(10)));
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_read_output_gcode_file_with_multi_day_print_time() {
+        let path = write_gcode_file(
+            "multi_day",
+            "; filament used [mm] = 1000.00, 234.50\n\
+             ; estimated printing time (normal mode) = 1d 3h 4m 5s\n\
+             ; estimated printing time (silent mode) = 1d 5h 0m 0s\n",
+        );
+        let params = read_output_gcode_file(&path, read_gcode_metadata).unwrap();
+        assert_eq!(params.time, 97_445);
+        assert_eq!(params.material_mm, 1234);
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_read_output_gcode_file_reports_missing_metadata() {
+        let path = write_gcode_file("no_time", "; filament used [mm] = 1234.56\n");
+        assert!(matches!(
+            read_output_gcode_file(&path, read_gcode_metadata),
+            Err(SlicerError::MetadataMissing(_))
+        ));
+        std::fs::remove_file(path).ok();
+
+        assert!(matches!(
+            read_output_gcode_file("does/not/exist.gcode", read_gcode_metadata),
+            Err(SlicerError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_output_gcode_file_of_another_slicer() {
+        let path = write_gcode_file(
+            "cura",
+            ";FLAVOR:Marlin\n;TIME:754\n;Filament used: 1.5m\nG1 X1 E1\n",
+        );
+        let params = read_output_gcode_file(&path, read_cura_gcode_metadata).unwrap();
+        assert_eq!(params.time, 754);
+        assert_eq!(params.material_mm, 1500);
+        // The G-code of other slicers is not read as PrusaSlicer G-code
+        assert!(matches!(
+            read_output_gcode_file(&path, read_gcode_metadata),
+            Err(SlicerError::MetadataMissing(_))
+        ));
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_profiles_are_looked_up_in_the_directory_of_the_slicer() {
+        assert_eq!(
+            print_profile_path(
+                "ws",
+                SlicerBackend::OrcaSlicer,
+                &PrintType::ThickSoft,
+                "json"
+            ),
+            Path::new("ws/data_files/orca_config_files/orca_config_ThickSoft.json")
+        );
+        let error = check_profiles_exist("does/not/exist", SlicerBackend::CuraEngine, "cfg", &[])
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,336 @@
+;FLAVOR:Marlin
+;TIME:6666
+;Filament used: 0m
+;Layer height: 0.2
+;MINX:95.2
+;MINY:95.2
+;MINZ:0.2
+;MAXX:124.8
+;MAXY:124.8
+;MAXZ:0.6
+;TARGET_MACHINE.NAME:Unknown
+;Generated with Cura_SteamEngine 5.7.0
+M140 S60
+M105
+M190 S60
+M104 S200
+M109 S200
+M82 ;absolute extrusion mode
+G92 E0
+;LAYER_COUNT:3
+;LAYER:0
+G0 F6000 X100 Y100 Z0.2
+;TYPE:WALL-OUTER
+G1 X100 Y100 E0.10000
+G1 X101 Y101 E0.20000
+G1 X102 Y102 E0.30000
+G1 X103 Y103 E0.40000
+G1 X104 Y104 E0.50000
+G1 X105 Y100 E0.60000
+G1 X106 Y101 E0.70000
+G1 X100 Y102 E0.80000
+G1 X101 Y103 E0.90000
+G1 X102 Y104 E1.00000
+G1 X103 Y100 E1.10000
+G1 X104 Y101 E1.20000
+G1 X105 Y102 E1.30000
+G1 X106 Y103 E1.40000
+G1 X100 Y104 E1.50000
+G1 X101 Y100 E1.60000
+G1 X102 Y101 E1.70000
+G1 X103 Y102 E1.80000
+G1 X104 Y103 E1.90000
+G1 X105 Y104 E2.00000
+;TYPE:WALL-INNER
+G1 X100 Y100 E2.10000
+G1 X101 Y101 E2.20000
+G1 X102 Y102 E2.30000
+G1 X103 Y103 E2.40000
+G1 X104 Y104 E2.50000
+G1 X105 Y100 E2.60000
+G1 X106 Y101 E2.70000
+G1 X100 Y102 E2.80000
+G1 X101 Y103 E2.90000
+G1 X102 Y104 E3.00000
+G1 X103 Y100 E3.10000
+G1 X104 Y101 E3.20000
+G1 X105 Y102 E3.30000
+G1 X106 Y103 E3.40000
+G1 X100 Y104 E3.50000
+G1 X101 Y100 E3.60000
+G1 X102 Y101 E3.70000
+G1 X103 Y102 E3.80000
+G1 X104 Y103 E3.90000
+G1 X105 Y104 E4.00000
+;TYPE:SKIN
+G1 X100 Y100 E4.10000
+G1 X101 Y101 E4.20000
+G1 X102 Y102 E4.30000
+G1 X103 Y103 E4.40000
+G1 X104 Y104 E4.50000
+G1 X105 Y100 E4.60000
+G1 X106 Y101 E4.70000
+G1 X100 Y102 E4.80000
+G1 X101 Y103 E4.90000
+G1 X102 Y104 E5.00000
+;TYPE:FILL
+G1 X100 Y100 E5.10000
+G1 X101 Y101 E5.20000
+G1 X102 Y102 E5.30000
+G1 X103 Y103 E5.40000
+G1 X104 Y104 E5.50000
+G1 X105 Y100 E5.60000
+G1 X106 Y101 E5.70000
+G1 X100 Y102 E5.80000
+G1 X101 Y103 E5.90000
+G1 X102 Y104 E6.00000
+G1 X103 Y100 E6.10000
+G1 X104 Y101 E6.20000
+G1 X105 Y102 E6.30000
+G1 X106 Y103 E6.40000
+G1 X100 Y104 E6.50000
+G1 X101 Y100 E6.60000
+G1 X102 Y101 E6.70000
+G1 X103 Y102 E6.80000
+G1 X104 Y103 E6.90000
+G1 X105 Y104 E7.00000
+G1 X106 Y100 E7.10000
+G1 X100 Y101 E7.20000
+G1 X101 Y102 E7.30000
+G1 X102 Y103 E7.40000
+G1 X103 Y104 E7.50000
+G1 X104 Y100 E7.60000
+G1 X105 Y101 E7.70000
+G1 X106 Y102 E7.80000
+G1 X100 Y103 E7.90000
+G1 X101 Y104 E8.00000
+;TYPE:SUPPORT
+G1 X100 Y100 E8.10000
+G1 X101 Y101 E8.20000
+G1 X102 Y102 E8.30000
+G1 X103 Y103 E8.40000
+G1 X104 Y104 E8.50000
+G1 X105 Y100 E8.60000
+G1 X106 Y101 E8.70000
+G1 X100 Y102 E8.80000
+G1 X101 Y103 E8.90000
+G1 X102 Y104 E9.00000
+;TYPE:SKIRT
+G1 X100 Y100 E9.10000
+G1 X101 Y101 E9.20000
+G1 X102 Y102 E9.30000
+G1 X103 Y103 E9.40000
+G1 F2700 E8.60000
+G1 F2700 E9.40000
+;TIME_ELAPSED:1203.400000
+;LAYER:1
+G0 F6000 X100 Y100 Z0.4
+;TYPE:WALL-OUTER
+G1 X100 Y100 E9.50000
+G1 X101 Y101 E9.60000
+G1 X102 Y102 E9.70000
+G1 X103 Y103 E9.80000
+G1 X104 Y104 E9.90000
+G1 X105 Y100 E10.00000
+G1 X106 Y101 E10.10000
+G1 X100 Y102 E10.20000
+G1 X101 Y103 E10.30000
+G1 X102 Y104 E10.40000
+G1 X103 Y100 E10.50000
+G1 X104 Y101 E10.60000
+G1 X105 Y102 E10.70000
+G1 X106 Y103 E10.80000
+G1 X100 Y104 E10.90000
+G1 X101 Y100 E11.00000
+G1 X102 Y101 E11.10000
+G1 X103 Y102 E11.20000
+G1 X104 Y103 E11.30000
+G1 X105 Y104 E11.40000
+;TYPE:WALL-INNER
+G1 X100 Y100 E11.50000
+G1 X101 Y101 E11.60000
+G1 X102 Y102 E11.70000
+G1 X103 Y103 E11.80000
+G1 X104 Y104 E11.90000
+G1 X105 Y100 E12.00000
+G1 X106 Y101 E12.10000
+G1 X100 Y102 E12.20000
+G1 X101 Y103 E12.30000
+G1 X102 Y104 E12.40000
+G1 X103 Y100 E12.50000
+G1 X104 Y101 E12.60000
+G1 X105 Y102 E12.70000
+G1 X106 Y103 E12.80000
+G1 X100 Y104 E12.90000
+G1 X101 Y100 E13.00000
+G1 X102 Y101 E13.10000
+G1 X103 Y102 E13.20000
+G1 X104 Y103 E13.30000
+G1 X105 Y104 E13.40000
+;TYPE:SKIN
+G1 X100 Y100 E13.50000
+G1 X101 Y101 E13.60000
+G1 X102 Y102 E13.70000
+G1 X103 Y103 E13.80000
+G1 X104 Y104 E13.90000
+G1 X105 Y100 E14.00000
+G1 X106 Y101 E14.10000
+G1 X100 Y102 E14.20000
+G1 X101 Y103 E14.30000
+G1 X102 Y104 E14.40000
+;TYPE:FILL
+G1 X100 Y100 E14.50000
+G1 X101 Y101 E14.60000
+G1 X102 Y102 E14.70000
+G1 X103 Y103 E14.80000
+G1 X104 Y104 E14.90000
+G1 X105 Y100 E15.00000
+G1 X106 Y101 E15.10000
+G1 X100 Y102 E15.20000
+G1 X101 Y103 E15.30000
+G1 X102 Y104 E15.40000
+G1 X103 Y100 E15.50000
+G1 X104 Y101 E15.60000
+G1 X105 Y102 E15.70000
+G1 X106 Y103 E15.80000
+G1 X100 Y104 E15.90000
+G1 X101 Y100 E16.00000
+G1 X102 Y101 E16.10000
+G1 X103 Y102 E16.20000
+G1 X104 Y103 E16.30000
+G1 X105 Y104 E16.40000
+G1 X106 Y100 E16.50000
+G1 X100 Y101 E16.60000
+G1 X101 Y102 E16.70000
+G1 X102 Y103 E16.80000
+G1 X103 Y104 E16.90000
+G1 X104 Y100 E17.00000
+G1 X105 Y101 E17.10000
+G1 X106 Y102 E17.20000
+G1 X100 Y103 E17.30000
+G1 X101 Y104 E17.40000
+;TYPE:SUPPORT
+G1 X100 Y100 E17.50000
+G1 X101 Y101 E17.60000
+G1 X102 Y102 E17.70000
+G1 X103 Y103 E17.80000
+G1 X104 Y104 E17.90000
+G1 X105 Y100 E18.00000
+G1 X106 Y101 E18.10000
+G1 X100 Y102 E18.20000
+G1 X101 Y103 E18.30000
+G1 X102 Y104 E18.40000
+G1 F2700 E17.60000
+G1 F2700 E18.40000
+;TIME_ELAPSED:2406.800000
+;LAYER:2
+G0 F6000 X100 Y100 Z0.6
+;TYPE:WALL-OUTER
+G1 X100 Y100 E18.50000
+G1 X101 Y101 E18.60000
+G1 X102 Y102 E18.70000
+G1 X103 Y103 E18.80000
+G1 X104 Y104 E18.90000
+G1 X105 Y100 E19.00000
+G1 X106 Y101 E19.10000
+G1 X100 Y102 E19.20000
+G1 X101 Y103 E19.30000
+G1 X102 Y104 E19.40000
+G1 X103 Y100 E19.50000
+G1 X104 Y101 E19.60000
+G1 X105 Y102 E19.70000
+G1 X106 Y103 E19.80000
+G1 X100 Y104 E19.90000
+G1 X101 Y100 E20.00000
+G1 X102 Y101 E20.10000
+G1 X103 Y102 E20.20000
+G1 X104 Y103 E20.30000
+G1 X105 Y104 E20.40000
+;TYPE:WALL-INNER
+G1 X100 Y100 E20.50000
+G1 X101 Y101 E20.60000
+G1 X102 Y102 E20.70000
+G1 X103 Y103 E20.80000
+G1 X104 Y104 E20.90000
+G1 X105 Y100 E21.00000
+G1 X106 Y101 E21.10000
+G1 X100 Y102 E21.20000
+G1 X101 Y103 E21.30000
+G1 X102 Y104 E21.40000
+G1 X103 Y100 E21.50000
+G1 X104 Y101 E21.60000
+G1 X105 Y102 E21.70000
+G1 X106 Y103 E21.80000
+G1 X100 Y104 E21.90000
+G1 X101 Y100 E22.00000
+G1 X102 Y101 E22.10000
+G1 X103 Y102 E22.20000
+G1 X104 Y103 E22.30000
+G1 X105 Y104 E22.40000
+;TYPE:SKIN
+G1 X100 Y100 E22.50000
+G1 X101 Y101 E22.60000
+G1 X102 Y102 E22.70000
+G1 X103 Y103 E22.80000
+G1 X104 Y104 E22.90000
+G1 X105 Y100 E23.00000
+G1 X106 Y101 E23.10000
+G1 X100 Y102 E23.20000
+G1 X101 Y103 E23.30000
+G1 X102 Y104 E23.40000
+;TYPE:FILL
+G1 X100 Y100 E23.50000
+G1 X101 Y101 E23.60000
+G1 X102 Y102 E23.70000
+G1 X103 Y103 E23.80000
+G1 X104 Y104 E23.90000
+G1 X105 Y100 E24.00000
+G1 X106 Y101 E24.10000
+G1 X100 Y102 E24.20000
+G1 X101 Y103 E24.30000
+G1 X102 Y104 E24.40000
+G1 X103 Y100 E24.50000
+G1 X104 Y101 E24.60000
+G1 X105 Y102 E24.70000
+G1 X106 Y103 E24.80000
+G1 X100 Y104 E24.90000
+G1 X101 Y100 E25.00000
+G1 X102 Y101 E25.10000
+G1 X103 Y102 E25.20000
+G1 X104 Y103 E25.30000
+G1 X105 Y104 E25.40000
+G1 X106 Y100 E25.50000
+G1 X100 Y101 E25.60000
+G1 X101 Y102 E25.70000
+G1 X102 Y103 E25.80000
+G1 X103 Y104 E25.90000
+G1 X104 Y100 E26.00000
+G1 X105 Y101 E26.10000
+G1 X106 Y102 E26.20000
+G1 X100 Y103 E26.30000
+G1 X101 Y104 E26.40000
+;TYPE:SUPPORT
+G1 X100 Y100 E26.50000
+G1 X101 Y101 E26.60000
+G1 X102 Y102 E26.70000
+G1 X103 Y103 E26.80000
+G1 X104 Y104 E26.90000
+G1 X105 Y100 E27.00000
+G1 X106 Y101 E27.10000
+G1 X100 Y102 E27.20000
+G1 X101 Y103 E27.30000
+G1 X102 Y104 E27.40000
+G1 F2700 E26.60000
+G1 F2700 E27.40000
+;TIME_ELAPSED:3610.200000
+G1 F2700 E22.40000
+M140 S0
+M107
+G91
+G1 E-2 F2700
+G90
+M84
+M82 ;absolute extrusion mode
+M104 S0
+;End of Gcode
+;SETTING_3 {"global_quality": "[general]\\nversion = 4\\nname = Standard Quality\\n"}
@@ -0,0 +1,363 @@
+; HEADER_BLOCK_START
+; generated by OrcaSlicer 2.0.0 on 2024-05-02 at 14:21:07
+;
+; model printing time: 1h 58m 2s; total estimated time: 2h 0m 10s
+; total layer number: 3
+; total filament length [mm] : 2051.20
+; total filament volume [cm^3] : 4933.75
+; total filament weight [g] : 6.12
+; filament_density: 1.24
+; filament_diameter: 1.75
+; max_z_height: 0.60
+; HEADER_BLOCK_END
+
+; THUMBNAIL_BLOCK_START
+; THUMBNAIL_BLOCK_END
+
+M73 P0 R120
+M190 S60
+M109 S220
+G90
+M83 ; use relative distances for extrusion
+G1 E-.8 F2100
+;LAYER_CHANGE
+;Z:0.2
+;HEIGHT:0.2
+G1 Z0.2 F720
+G1 E.8 F2100
+;TYPE:Outer wall
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+;TYPE:Inner wall
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+;TYPE:Sparse infill
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+G1 X106.000 Y100.000 E.05
+G1 X100.000 Y101.000 E.05
+G1 X101.000 Y102.000 E.05
+G1 X102.000 Y103.000 E.05
+G1 X103.000 Y104.000 E.05
+G1 X104.000 Y100.000 E.05
+G1 X105.000 Y101.000 E.05
+G1 X106.000 Y102.000 E.05
+G1 X100.000 Y103.000 E.05
+G1 X101.000 Y104.000 E.05
+;TYPE:Internal solid infill
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+;TYPE:Support
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+;TYPE:Prime tower
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 E-.8 F2100
+;LAYER_CHANGE
+;Z:0.4
+;HEIGHT:0.2
+G1 Z0.4 F720
+G1 E.8 F2100
+;TYPE:Outer wall
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+;TYPE:Inner wall
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+;TYPE:Sparse infill
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+G1 X106.000 Y100.000 E.05
+G1 X100.000 Y101.000 E.05
+G1 X101.000 Y102.000 E.05
+G1 X102.000 Y103.000 E.05
+G1 X103.000 Y104.000 E.05
+G1 X104.000 Y100.000 E.05
+G1 X105.000 Y101.000 E.05
+G1 X106.000 Y102.000 E.05
+G1 X100.000 Y103.000 E.05
+G1 X101.000 Y104.000 E.05
+;TYPE:Internal solid infill
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+;TYPE:Support
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+;TYPE:Prime tower
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 E-.8 F2100
+;LAYER_CHANGE
+;Z:0.6
+;HEIGHT:0.2
+G1 Z0.6 F720
+G1 E.8 F2100
+;TYPE:Outer wall
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+;TYPE:Inner wall
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+;TYPE:Sparse infill
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+G1 X103.000 Y100.000 E.05
+G1 X104.000 Y101.000 E.05
+G1 X105.000 Y102.000 E.05
+G1 X106.000 Y103.000 E.05
+G1 X100.000 Y104.000 E.05
+G1 X101.000 Y100.000 E.05
+G1 X102.000 Y101.000 E.05
+G1 X103.000 Y102.000 E.05
+G1 X104.000 Y103.000 E.05
+G1 X105.000 Y104.000 E.05
+G1 X106.000 Y100.000 E.05
+G1 X100.000 Y101.000 E.05
+G1 X101.000 Y102.000 E.05
+G1 X102.000 Y103.000 E.05
+G1 X103.000 Y104.000 E.05
+G1 X104.000 Y100.000 E.05
+G1 X105.000 Y101.000 E.05
+G1 X106.000 Y102.000 E.05
+G1 X100.000 Y103.000 E.05
+G1 X101.000 Y104.000 E.05
+;TYPE:Internal solid infill
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 X101.000 Y103.000 E.05
+G1 X102.000 Y104.000 E.05
+;TYPE:Prime tower
+G1 X100.000 Y100.000 E.05
+G1 X101.000 Y101.000 E.05
+G1 X102.000 Y102.000 E.05
+G1 X103.000 Y103.000 E.05
+G1 X104.000 Y104.000 E.05
+G1 X105.000 Y100.000 E.05
+G1 X106.000 Y101.000 E.05
+G1 X100.000 Y102.000 E.05
+G1 E-.8 F2100
+; close powerlosss recovery
+M1003 S0
+;TYPE:Custom
+; filament end gcode 
+M104 S0
+M140 S0
+M84
+; EXECUTABLE_BLOCK_END
+
+; filament used [mm] = 2051.20
+; filament used [cm3] = 4.93
+; filament used [g] = 6.12
+; filament cost = 0.15
+; total filament used [g] = 6.12
+; total filament cost = 0.15
+; total layers count = 3
+; estimated printing time (normal mode) = 2h 0m 10s
+
+; CONFIG_BLOCK_START
+; filament_settings_id = "Generic PLA @System"
+; layer_height = 0.2
+; CONFIG_BLOCK_END
@@ -0,0 +1,567 @@
+; generated by PrusaSlicer 2.5.0+win64 on 2022-09-06 at 08:02:11 UTC
+M73 P0 R1
+M201 X1000 Y1000 Z200 E5000 ; sets maximum accelerations, mm/sec^2
+G21 ; set units to millimeters
+G90 ; use absolute coordinates
+M82 ; use absolute distances for extrusion
+G92 E0
+;LAYER_CHANGE
+;Z:0.2
+G1 Z0.2 F10800
+;TYPE:Perimeter
+G1 X100.000 Y100.000 E0.05000
+G1 X101.000 Y101.000 E0.10000
+G1 X102.000 Y102.000 E0.15000
+G1 X103.000 Y103.000 E0.20000
+G1 X104.000 Y104.000 E0.25000
+G1 X105.000 Y100.000 E0.30000
+G1 X106.000 Y101.000 E0.35000
+G1 X100.000 Y102.000 E0.40000
+G1 X101.000 Y103.000 E0.45000
+G1 X102.000 Y104.000 E0.50000
+G1 X103.000 Y100.000 E0.55000
+G1 X104.000 Y101.000 E0.60000
+G1 X105.000 Y102.000 E0.65000
+G1 X106.000 Y103.000 E0.70000
+G1 X100.000 Y104.000 E0.75000
+G1 X101.000 Y100.000 E0.80000
+G1 X102.000 Y101.000 E0.85000
+G1 X103.000 Y102.000 E0.90000
+G1 X104.000 Y103.000 E0.95000
+G1 X105.000 Y104.000 E1.00000
+G1 X106.000 Y100.000 E1.05000
+G1 X100.000 Y101.000 E1.10000
+G1 X101.000 Y102.000 E1.15000
+G1 X102.000 Y103.000 E1.20000
+G1 X103.000 Y104.000 E1.25000
+G1 X104.000 Y100.000 E1.30000
+G1 X105.000 Y101.000 E1.35000
+G1 X106.000 Y102.000 E1.40000
+G1 X100.000 Y103.000 E1.45000
+G1 X101.000 Y104.000 E1.50000
+G1 X102.000 Y100.000 E1.55000
+G1 X103.000 Y101.000 E1.60000
+G1 X104.000 Y102.000 E1.65000
+G1 X105.000 Y103.000 E1.70000
+G1 X106.000 Y104.000 E1.75000
+G1 X100.000 Y100.000 E1.80000
+G1 X101.000 Y101.000 E1.85000
+G1 X102.000 Y102.000 E1.90000
+G1 X103.000 Y103.000 E1.95000
+G1 X104.000 Y104.000 E2.00000
+G1 X105.000 Y100.000 E2.05000
+G1 X106.000 Y101.000 E2.10000
+G1 X100.000 Y102.000 E2.15000
+G1 X101.000 Y103.000 E2.20000
+G1 X102.000 Y104.000 E2.25000
+G1 X103.000 Y100.000 E2.30000
+G1 X104.000 Y101.000 E2.35000
+G1 X105.000 Y102.000 E2.40000
+G1 X106.000 Y103.000 E2.45000
+G1 X100.000 Y104.000 E2.50000
+G1 X101.000 Y100.000 E2.55000
+G1 X102.000 Y101.000 E2.60000
+G1 X103.000 Y102.000 E2.65000
+G1 X104.000 Y103.000 E2.70000
+G1 X105.000 Y104.000 E2.75000
+G1 X106.000 Y100.000 E2.80000
+G1 X100.000 Y101.000 E2.85000
+G1 X101.000 Y102.000 E2.90000
+G1 X102.000 Y103.000 E2.95000
+G1 X103.000 Y104.000 E3.00000
+G1 X104.000 Y100.000 E3.05000
+G1 X105.000 Y101.000 E3.10000
+G1 X106.000 Y102.000 E3.15000
+G1 X100.000 Y103.000 E3.20000
+G1 X101.000 Y104.000 E3.25000
+G1 X102.000 Y100.000 E3.30000
+G1 X103.000 Y101.000 E3.35000
+G1 X104.000 Y102.000 E3.40000
+G1 X105.000 Y103.000 E3.45000
+G1 X106.000 Y104.000 E3.50000
+G1 X100.000 Y100.000 E3.55000
+G1 X101.000 Y101.000 E3.60000
+G1 X102.000 Y102.000 E3.65000
+G1 X103.000 Y103.000 E3.70000
+G1 X104.000 Y104.000 E3.75000
+G1 X105.000 Y100.000 E3.80000
+G1 X106.000 Y101.000 E3.85000
+G1 X100.000 Y102.000 E3.90000
+G1 X101.000 Y103.000 E3.95000
+G1 X102.000 Y104.000 E4.00000
+G1 X103.000 Y100.000 E4.05000
+G1 X104.000 Y101.000 E4.10000
+G1 X105.000 Y102.000 E4.15000
+G1 X106.000 Y103.000 E4.20000
+G1 X100.000 Y104.000 E4.25000
+G1 X101.000 Y100.000 E4.30000
+G1 X102.000 Y101.000 E4.35000
+G1 X103.000 Y102.000 E4.40000
+G1 X104.000 Y103.000 E4.45000
+G1 X105.000 Y104.000 E4.50000
+G1 X106.000 Y100.000 E4.55000
+G1 X100.000 Y101.000 E4.60000
+G1 X101.000 Y102.000 E4.65000
+G1 X102.000 Y103.000 E4.70000
+G1 X103.000 Y104.000 E4.75000
+G1 X104.000 Y100.000 E4.80000
+G1 X105.000 Y101.000 E4.85000
+G1 X106.000 Y102.000 E4.90000
+G1 X100.000 Y103.000 E4.95000
+G1 X101.000 Y104.000 E5.00000
+G1 X102.000 Y100.000 E5.05000
+G1 X103.000 Y101.000 E5.10000
+G1 X104.000 Y102.000 E5.15000
+G1 X105.000 Y103.000 E5.20000
+G1 X106.000 Y104.000 E5.25000
+G1 X100.000 Y100.000 E5.30000
+G1 X101.000 Y101.000 E5.35000
+G1 X102.000 Y102.000 E5.40000
+G1 X103.000 Y103.000 E5.45000
+G1 X104.000 Y104.000 E5.50000
+G1 X105.000 Y100.000 E5.55000
+G1 X106.000 Y101.000 E5.60000
+G1 X100.000 Y102.000 E5.65000
+G1 X101.000 Y103.000 E5.70000
+G1 X102.000 Y104.000 E5.75000
+G1 X103.000 Y100.000 E5.80000
+G1 X104.000 Y101.000 E5.85000
+G1 X105.000 Y102.000 E5.90000
+G1 X106.000 Y103.000 E5.95000
+G1 X100.000 Y104.000 E6.00000
+G1 X101.000 Y100.000 E6.05000
+G1 X102.000 Y101.000 E6.10000
+G1 X103.000 Y102.000 E6.15000
+G1 X104.000 Y103.000 E6.20000
+G1 X105.000 Y104.000 E6.25000
+G1 X106.000 Y100.000 E6.30000
+G1 X100.000 Y101.000 E6.35000
+G1 X101.000 Y102.000 E6.40000
+G1 X102.000 Y103.000 E6.45000
+G1 X103.000 Y104.000 E6.50000
+G1 X104.000 Y100.000 E6.55000
+G1 X105.000 Y101.000 E6.60000
+G1 X106.000 Y102.000 E6.65000
+G1 X100.000 Y103.000 E6.70000
+G1 X101.000 Y104.000 E6.75000
+G1 X102.000 Y100.000 E6.80000
+G1 X103.000 Y101.000 E6.85000
+G1 X104.000 Y102.000 E6.90000
+G1 X105.000 Y103.000 E6.95000
+G1 X106.000 Y104.000 E7.00000
+G1 X100.000 Y100.000 E7.05000
+G1 X101.000 Y101.000 E7.10000
+G1 X102.000 Y102.000 E7.15000
+G1 X103.000 Y103.000 E7.20000
+G1 X104.000 Y104.000 E7.25000
+G1 X105.000 Y100.000 E7.30000
+G1 X106.000 Y101.000 E7.35000
+G1 X100.000 Y102.000 E7.40000
+G1 X101.000 Y103.000 E7.45000
+G1 X102.000 Y104.000 E7.50000
+G1 X103.000 Y100.000 E7.55000
+G1 X104.000 Y101.000 E7.60000
+G1 X105.000 Y102.000 E7.65000
+G1 X106.000 Y103.000 E7.70000
+G1 X100.000 Y104.000 E7.75000
+G1 X101.000 Y100.000 E7.80000
+G1 X102.000 Y101.000 E7.85000
+G1 X103.000 Y102.000 E7.90000
+G1 X104.000 Y103.000 E7.95000
+G1 X105.000 Y104.000 E8.00000
+G1 X106.000 Y100.000 E8.05000
+G1 X100.000 Y101.000 E8.10000
+G1 X101.000 Y102.000 E8.15000
+G1 X102.000 Y103.000 E8.20000
+G1 X103.000 Y104.000 E8.25000
+G1 X104.000 Y100.000 E8.30000
+G1 X105.000 Y101.000 E8.35000
+G1 X106.000 Y102.000 E8.40000
+G1 X100.000 Y103.000 E8.45000
+G1 X101.000 Y104.000 E8.50000
+G1 X102.000 Y100.000 E8.55000
+G1 X103.000 Y101.000 E8.60000
+G1 X104.000 Y102.000 E8.65000
+G1 X105.000 Y103.000 E8.70000
+G1 X106.000 Y104.000 E8.75000
+G1 X100.000 Y100.000 E8.80000
+G1 X101.000 Y101.000 E8.85000
+G1 X102.000 Y102.000 E8.90000
+G1 X103.000 Y103.000 E8.95000
+G1 X104.000 Y104.000 E9.00000
+G1 X105.000 Y100.000 E9.05000
+G1 X106.000 Y101.000 E9.10000
+G1 X100.000 Y102.000 E9.15000
+G1 X101.000 Y103.000 E9.20000
+G1 X102.000 Y104.000 E9.25000
+G1 X103.000 Y100.000 E9.30000
+G1 X104.000 Y101.000 E9.35000
+G1 X105.000 Y102.000 E9.40000
+G1 X106.000 Y103.000 E9.45000
+G1 X100.000 Y104.000 E9.50000
+G1 X101.000 Y100.000 E9.55000
+G1 X102.000 Y101.000 E9.60000
+G1 X103.000 Y102.000 E9.65000
+G1 X104.000 Y103.000 E9.70000
+G1 X105.000 Y104.000 E9.75000
+G1 X106.000 Y100.000 E9.80000
+G1 X100.000 Y101.000 E9.85000
+G1 X101.000 Y102.000 E9.90000
+G1 X102.000 Y103.000 E9.95000
+G1 X103.000 Y104.000 E10.00000
+G1 E9.20000 F2100
+G1 X20 Y20
+G1 E10.00000
+G92 E0
+;LAYER_CHANGE
+;Z:0.4
+G1 Z0.4 F10800
+;TYPE:Perimeter
+G1 X100.000 Y100.000 E0.05000
+G1 X101.000 Y101.000 E0.10000
+G1 X102.000 Y102.000 E0.15000
+G1 X103.000 Y103.000 E0.20000
+G1 X104.000 Y104.000 E0.25000
+G1 X105.000 Y100.000 E0.30000
+G1 X106.000 Y101.000 E0.35000
+G1 X100.000 Y102.000 E0.40000
+G1 X101.000 Y103.000 E0.45000
+G1 X102.000 Y104.000 E0.50000
+G1 X103.000 Y100.000 E0.55000
+G1 X104.000 Y101.000 E0.60000
+G1 X105.000 Y102.000 E0.65000
+G1 X106.000 Y103.000 E0.70000
+G1 X100.000 Y104.000 E0.75000
+G1 X101.000 Y100.000 E0.80000
+G1 X102.000 Y101.000 E0.85000
+G1 X103.000 Y102.000 E0.90000
+G1 X104.000 Y103.000 E0.95000
+G1 X105.000 Y104.000 E1.00000
+G1 X106.000 Y100.000 E1.05000
+G1 X100.000 Y101.000 E1.10000
+G1 X101.000 Y102.000 E1.15000
+G1 X102.000 Y103.000 E1.20000
+G1 X103.000 Y104.000 E1.25000
+G1 X104.000 Y100.000 E1.30000
+G1 X105.000 Y101.000 E1.35000
+G1 X106.000 Y102.000 E1.40000
+G1 X100.000 Y103.000 E1.45000
+G1 X101.000 Y104.000 E1.50000
+G1 X102.000 Y100.000 E1.55000
+G1 X103.000 Y101.000 E1.60000
+G1 X104.000 Y102.000 E1.65000
+G1 X105.000 Y103.000 E1.70000
+G1 X106.000 Y104.000 E1.75000
+G1 X100.000 Y100.000 E1.80000
+G1 X101.000 Y101.000 E1.85000
+G1 X102.000 Y102.000 E1.90000
+G1 X103.000 Y103.000 E1.95000
+G1 X104.000 Y104.000 E2.00000
+G1 X105.000 Y100.000 E2.05000
+G1 X106.000 Y101.000 E2.10000
+G1 X100.000 Y102.000 E2.15000
+G1 X101.000 Y103.000 E2.20000
+G1 X102.000 Y104.000 E2.25000
+G1 X103.000 Y100.000 E2.30000
+G1 X104.000 Y101.000 E2.35000
+G1 X105.000 Y102.000 E2.40000
+G1 X106.000 Y103.000 E2.45000
+G1 X100.000 Y104.000 E2.50000
+G1 X101.000 Y100.000 E2.55000
+G1 X102.000 Y101.000 E2.60000
+G1 X103.000 Y102.000 E2.65000
+G1 X104.000 Y103.000 E2.70000
+G1 X105.000 Y104.000 E2.75000
+G1 X106.000 Y100.000 E2.80000
+G1 X100.000 Y101.000 E2.85000
+G1 X101.000 Y102.000 E2.90000
+G1 X102.000 Y103.000 E2.95000
+G1 X103.000 Y104.000 E3.00000
+G1 X104.000 Y100.000 E3.05000
+G1 X105.000 Y101.000 E3.10000
+G1 X106.000 Y102.000 E3.15000
+G1 X100.000 Y103.000 E3.20000
+G1 X101.000 Y104.000 E3.25000
+G1 X102.000 Y100.000 E3.30000
+G1 X103.000 Y101.000 E3.35000
+G1 X104.000 Y102.000 E3.40000
+G1 X105.000 Y103.000 E3.45000
+G1 X106.000 Y104.000 E3.50000
+G1 X100.000 Y100.000 E3.55000
+G1 X101.000 Y101.000 E3.60000
+G1 X102.000 Y102.000 E3.65000
+G1 X103.000 Y103.000 E3.70000
+G1 X104.000 Y104.000 E3.75000
+G1 X105.000 Y100.000 E3.80000
+G1 X106.000 Y101.000 E3.85000
+G1 X100.000 Y102.000 E3.90000
+G1 X101.000 Y103.000 E3.95000
+G1 X102.000 Y104.000 E4.00000
+G1 X103.000 Y100.000 E4.05000
+G1 X104.000 Y101.000 E4.10000
+G1 X105.000 Y102.000 E4.15000
+G1 X106.000 Y103.000 E4.20000
+G1 X100.000 Y104.000 E4.25000
+G1 X101.000 Y100.000 E4.30000
+G1 X102.000 Y101.000 E4.35000
+G1 X103.000 Y102.000 E4.40000
+G1 X104.000 Y103.000 E4.45000
+G1 X105.000 Y104.000 E4.50000
+G1 X106.000 Y100.000 E4.55000
+G1 X100.000 Y101.000 E4.60000
+G1 X101.000 Y102.000 E4.65000
+G1 X102.000 Y103.000 E4.70000
+G1 X103.000 Y104.000 E4.75000
+G1 X104.000 Y100.000 E4.80000
+G1 X105.000 Y101.000 E4.85000
+G1 X106.000 Y102.000 E4.90000
+G1 X100.000 Y103.000 E4.95000
+G1 X101.000 Y104.000 E5.00000
+G1 X102.000 Y100.000 E5.05000
+G1 X103.000 Y101.000 E5.10000
+G1 X104.000 Y102.000 E5.15000
+G1 X105.000 Y103.000 E5.20000
+G1 X106.000 Y104.000 E5.25000
+G1 X100.000 Y100.000 E5.30000
+G1 X101.000 Y101.000 E5.35000
+G1 X102.000 Y102.000 E5.40000
+G1 X103.000 Y103.000 E5.45000
+G1 X104.000 Y104.000 E5.50000
+G1 X105.000 Y100.000 E5.55000
+G1 X106.000 Y101.000 E5.60000
+G1 X100.000 Y102.000 E5.65000
+G1 X101.000 Y103.000 E5.70000
+G1 X102.000 Y104.000 E5.75000
+G1 X103.000 Y100.000 E5.80000
+G1 X104.000 Y101.000 E5.85000
+G1 X105.000 Y102.000 E5.90000
+G1 X106.000 Y103.000 E5.95000
+G1 X100.000 Y104.000 E6.00000
+G1 X101.000 Y100.000 E6.05000
+G1 X102.000 Y101.000 E6.10000
+G1 X103.000 Y102.000 E6.15000
+G1 X104.000 Y103.000 E6.20000
+G1 X105.000 Y104.000 E6.25000
+G1 X106.000 Y100.000 E6.30000
+G1 X100.000 Y101.000 E6.35000
+G1 X101.000 Y102.000 E6.40000
+G1 X102.000 Y103.000 E6.45000
+G1 X103.000 Y104.000 E6.50000
+G1 X104.000 Y100.000 E6.55000
+G1 X105.000 Y101.000 E6.60000
+G1 X106.000 Y102.000 E6.65000
+G1 X100.000 Y103.000 E6.70000
+G1 X101.000 Y104.000 E6.75000
+G1 X102.000 Y100.000 E6.80000
+G1 X103.000 Y101.000 E6.85000
+G1 X104.000 Y102.000 E6.90000
+G1 X105.000 Y103.000 E6.95000
+G1 X106.000 Y104.000 E7.00000
+G1 X100.000 Y100.000 E7.05000
+G1 X101.000 Y101.000 E7.10000
+G1 X102.000 Y102.000 E7.15000
+G1 X103.000 Y103.000 E7.20000
+G1 X104.000 Y104.000 E7.25000
+G1 X105.000 Y100.000 E7.30000
+G1 X106.000 Y101.000 E7.35000
+G1 X100.000 Y102.000 E7.40000
+G1 X101.000 Y103.000 E7.45000
+G1 X102.000 Y104.000 E7.50000
+G1 X103.000 Y100.000 E7.55000
+G1 X104.000 Y101.000 E7.60000
+G1 X105.000 Y102.000 E7.65000
+G1 X106.000 Y103.000 E7.70000
+G1 X100.000 Y104.000 E7.75000
+G1 X101.000 Y100.000 E7.80000
+G1 X102.000 Y101.000 E7.85000
+G1 X103.000 Y102.000 E7.90000
+G1 X104.000 Y103.000 E7.95000
+G1 X105.000 Y104.000 E8.00000
+G1 X106.000 Y100.000 E8.05000
+G1 X100.000 Y101.000 E8.10000
+G1 X101.000 Y102.000 E8.15000
+G1 X102.000 Y103.000 E8.20000
+G1 X103.000 Y104.000 E8.25000
+G1 X104.000 Y100.000 E8.30000
+G1 X105.000 Y101.000 E8.35000
+G1 X106.000 Y102.000 E8.40000
+G1 X100.000 Y103.000 E8.45000
+G1 X101.000 Y104.000 E8.50000
+G1 X102.000 Y100.000 E8.55000
+G1 X103.000 Y101.000 E8.60000
+G1 X104.000 Y102.000 E8.65000
+G1 X105.000 Y103.000 E8.70000
+G1 X106.000 Y104.000 E8.75000
+G1 X100.000 Y100.000 E8.80000
+G1 X101.000 Y101.000 E8.85000
+G1 X102.000 Y102.000 E8.90000
+G1 X103.000 Y103.000 E8.95000
+G1 X104.000 Y104.000 E9.00000
+G1 X105.000 Y100.000 E9.05000
+G1 X106.000 Y101.000 E9.10000
+G1 X100.000 Y102.000 E9.15000
+G1 X101.000 Y103.000 E9.20000
+G1 X102.000 Y104.000 E9.25000
+G1 X103.000 Y100.000 E9.30000
+G1 X104.000 Y101.000 E9.35000
+G1 X105.000 Y102.000 E9.40000
+G1 X106.000 Y103.000 E9.45000
+G1 X100.000 Y104.000 E9.50000
+G1 X101.000 Y100.000 E9.55000
+G1 X102.000 Y101.000 E9.60000
+G1 X103.000 Y102.000 E9.65000
+G1 X104.000 Y103.000 E9.70000
+G1 X105.000 Y104.000 E9.75000
+G1 X106.000 Y100.000 E9.80000
+G1 X100.000 Y101.000 E9.85000
+G1 X101.000 Y102.000 E9.90000
+G1 X102.000 Y103.000 E9.95000
+G1 X103.000 Y104.000 E10.00000
+G1 E9.20000 F2100
+G1 X20 Y20
+G1 E10.00000
+G92 E0
+;LAYER_CHANGE
+;Z:0.6
+G1 Z0.6 F10800
+;TYPE:Perimeter
+G1 X100.000 Y100.000 E0.05000
+G1 X101.000 Y101.000 E0.10000
+G1 X102.000 Y102.000 E0.15000
+G1 X103.000 Y103.000 E0.20000
+G1 X104.000 Y104.000 E0.25000
+G1 X105.000 Y100.000 E0.30000
+G1 X106.000 Y101.000 E0.35000
+G1 X100.000 Y102.000 E0.40000
+G1 X101.000 Y103.000 E0.45000
+G1 X102.000 Y104.000 E0.50000
+G1 X103.000 Y100.000 E0.55000
+G1 X104.000 Y101.000 E0.60000
+G1 X105.000 Y102.000 E0.65000
+G1 X106.000 Y103.000 E0.70000
+G1 X100.000 Y104.000 E0.75000
+G1 X101.000 Y100.000 E0.80000
+G1 X102.000 Y101.000 E0.85000
+G1 X103.000 Y102.000 E0.90000
+G1 X104.000 Y103.000 E0.95000
+G1 X105.000 Y104.000 E1.00000
+G1 X106.000 Y100.000 E1.05000
+G1 X100.000 Y101.000 E1.10000
+G1 X101.000 Y102.000 E1.15000
+G1 X102.000 Y103.000 E1.20000
+G1 X103.000 Y104.000 E1.25000
+G1 X104.000 Y100.000 E1.30000
+G1 X105.000 Y101.000 E1.35000
+G1 X106.000 Y102.000 E1.40000
+G1 X100.000 Y103.000 E1.45000
+G1 X101.000 Y104.000 E1.50000
+G1 X102.000 Y100.000 E1.55000
+G1 X103.000 Y101.000 E1.60000
+G1 X104.000 Y102.000 E1.65000
+G1 X105.000 Y103.000 E1.70000
+G1 X106.000 Y104.000 E1.75000
+G1 X100.000 Y100.000 E1.80000
+G1 X101.000 Y101.000 E1.85000
+G1 X102.000 Y102.000 E1.90000
+G1 X103.000 Y103.000 E1.95000
+G1 X104.000 Y104.000 E2.00000
+G1 X105.000 Y100.000 E2.05000
+G1 X106.000 Y101.000 E2.10000
+G1 X100.000 Y102.000 E2.15000
+G1 X101.000 Y103.000 E2.20000
+G1 X102.000 Y104.000 E2.25000
+G1 X103.000 Y100.000 E2.30000
+G1 X104.000 Y101.000 E2.35000
+G1 X105.000 Y102.000 E2.40000
+G1 X106.000 Y103.000 E2.45000
+G1 X100.000 Y104.000 E2.50000
+G1 X101.000 Y100.000 E2.55000
+G1 X102.000 Y101.000 E2.60000
+G1 X103.000 Y102.000 E2.65000
+G1 X104.000 Y103.000 E2.70000
+G1 X105.000 Y104.000 E2.75000
+G1 X106.000 Y100.000 E2.80000
+G1 X100.000 Y101.000 E2.85000
+G1 X101.000 Y102.000 E2.90000
+G1 X102.000 Y103.000 E2.95000
+G1 X103.000 Y104.000 E3.00000
+G1 X104.000 Y100.000 E3.05000
+G1 X105.000 Y101.000 E3.10000
+G1 X106.000 Y102.000 E3.15000
+G1 X100.000 Y103.000 E3.20000
+G1 X101.000 Y104.000 E3.25000
+G1 X102.000 Y100.000 E3.30000
+G1 X103.000 Y101.000 E3.35000
+G1 X104.000 Y102.000 E3.40000
+G1 X105.000 Y103.000 E3.45000
+G1 X106.000 Y104.000 E3.50000
+G1 X100.000 Y100.000 E3.55000
+G1 X101.000 Y101.000 E3.60000
+G1 X102.000 Y102.000 E3.65000
+G1 X103.000 Y103.000 E3.70000
+G1 X104.000 Y104.000 E3.75000
+G1 X105.000 Y100.000 E3.80000
+G1 X106.000 Y101.000 E3.85000
+G1 X100.000 Y102.000 E3.90000
+G1 X101.000 Y103.000 E3.95000
+G1 X102.000 Y104.000 E4.00000
+G1 X103.000 Y100.000 E4.05000
+G1 X104.000 Y101.000 E4.10000
+G1 X105.000 Y102.000 E4.15000
+G1 X106.000 Y103.000 E4.20000
+G1 X100.000 Y104.000 E4.25000
+G1 X101.000 Y100.000 E4.30000
+G1 X102.000 Y101.000 E4.35000
+G1 X103.000 Y102.000 E4.40000
+G1 X104.000 Y103.000 E4.45000
+G1 X105.000 Y104.000 E4.50000
+G1 X106.000 Y100.000 E4.55000
+G1 X100.000 Y101.000 E4.60000
+G1 X101.000 Y102.000 E4.65000
+G1 X102.000 Y103.000 E4.70000
+G1 X103.000 Y104.000 E4.75000
+G1 X104.000 Y100.000 E4.80000
+G1 X105.000 Y101.000 E4.85000
+G1 X106.000 Y102.000 E4.90000
+G1 X100.000 Y103.000 E4.95000
+G1 X101.000 Y104.000 E5.00000
+G1 E4.20000 F2100
+G1 X20 Y20
+G1 E5.00000
+G92 E0
+M73 P100 R0
+M107
+; filament used [mm] = 25.00
+; filament used [cm3] = 0.06
+; estimated printing time (normal mode) = 1m 5s
+
+; prusaslicer_config = begin
+; autoemit_temperature_commands = 1
+; avoid_crossing_curled_overhangs = 0
+; bed_shape = 0x0,250x0,250x210,0x210
+; bed_temperature = 90,60
+; binary_gcode = 0
+; bottom_solid_layers = 5
+; compatible_printers_condition_cummulative = "printer_notes=~/.*PRINTER_VENDOR_PRUSA3D.*/ and printer_notes=~/.*PRINTER_MODEL_MK3.*/ and nozzle_diameter[0]==0.4";"printer_notes=~/.*PRINTER_VENDOR_PRUSA3D.*/ and printer_notes=~/.*PRINTER_MODEL_MK3.*/ and nozzle_diameter[0]!=0.8"
+; extrusion_multiplier = 1,1
+; fill_density = 15%
+; filament_colour = #FF8000;#FFFFFF
+; filament_cost = 25.4
+; filament_density = 1.27,1.24
+; filament_diameter = 1.75,1.75
+; filament_settings_id = "Prusament PETG";"Prusament PLA"
+; filament_type = PETG
+; first_layer_height = 0.2
+; layer_height = 0.15
+; machine_max_feedrate_e = 120,120
+; max_print_speed = 200
+; nozzle_diameter = 0.4,0.4
+; perimeters = 2
+; print_settings_id = 0.15mm QUALITY @MK3
+; printer_model = MK3S
+; printer_settings_id = Original Prusa i3 MK3S & MK3S+ MMU2S
+; silent_mode = 1
+; single_extruder_multi_material = 1
+; temperature = 240,215
+; wipe_tower = 1
+; prusaslicer_config = end
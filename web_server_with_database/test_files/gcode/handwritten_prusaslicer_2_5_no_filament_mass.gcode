@@ -0,0 +1,442 @@
+; generated by PrusaSlicer 2.5.0+win64 on 2022-09-06 at 08:02:11 UTC
+M73 P0 R45
+M201 X1000 Y1000 Z200 E5000 ; sets maximum accelerations, mm/sec^2
+G21 ; set units to millimeters
+G90 ; use absolute coordinates
+M83 ; use relative distances for extrusion
+G1 X99.259 Y98.608 E0.07057
+G1 X99.140 Y98.843 E0.06365
+G1 X100.150 Y97.945 E0.03902
+G1 X101.820 Y98.062 E0.03307
+G1 X102.341 Y97.101 E0.07171
+G1 X100.506 Y98.408 E0.05532
+G1 X99.921 Y100.167 E0.03124
+G1 X98.895 Y98.447 E0.05388
+G1 X99.910 Y99.159 E0.04302
+G1 X101.141 Y97.604 E0.03456
+G1 X101.720 Y99.473 E0.06071
+G1 X102.488 Y100.572 E0.04156
+G1 X104.249 Y101.542 E0.03734
+G1 X103.819 Y102.765 E0.03798
+G1 X102.562 Y104.251 E0.05254
+G1 X102.647 Y104.929 E0.08212
+G1 X101.181 Y104.284 E0.01528
+G1 X100.834 Y104.292 E0.07815
+G1 X101.505 Y104.603 E0.04229
+G1 X101.800 Y103.699 E0.07758
+G1 X102.954 Y105.052 E0.02209
+G1 X103.640 Y106.069 E0.05005
+G1 X105.234 Y107.664 E0.06944
+G1 X106.518 Y108.259 E0.08029
+G1 X105.043 Y109.076 E0.06630
+G1 X105.492 Y108.176 E0.01538
+G1 X105.906 Y109.473 E0.03184
+G1 X104.758 Y108.369 E0.01751
+G1 X105.462 Y110.268 E0.07417
+G1 X104.901 Y111.066 E0.01577
+G1 X106.255 Y110.366 E0.01027
+G1 X106.772 Y108.921 E0.03200
+G1 X105.009 Y108.704 E0.05439
+G1 X106.238 Y106.863 E0.07619
+G1 X104.680 Y105.760 E0.06036
+G1 X104.041 Y105.085 E0.05548
+G1 X102.912 Y106.258 E0.02672
+G1 X104.270 Y107.493 E0.05297
+G1 X102.392 Y108.606 E0.01227
+G1 X102.410 Y108.301 E0.01504
+G1 X102.930 Y109.199 E0.05679
+G1 X102.531 Y109.248 E0.05710
+G1 X101.436 Y110.718 E0.08966
+G1 X102.653 Y112.564 E0.03635
+G1 X104.598 Y110.849 E0.04823
+G1 X103.133 Y110.665 E0.06461
+G1 X103.966 Y110.484 E0.03733
+G1 X102.726 Y110.095 E0.03261
+G1 X101.503 Y111.039 E0.05130
+G1 X101.257 Y109.830 E0.06630
+G1 X100.044 Y108.893 E0.05482
+G1 X100.849 Y110.785 E0.06981
+G1 X102.642 Y112.464 E0.06780
+G1 X103.521 Y110.715 E0.02645
+G1 X101.573 Y112.170 E0.06776
+G1 X102.093 Y111.225 E0.03843
+G1 X100.748 Y111.754 E0.08932
+G1 X99.971 Y109.931 E0.02401
+G1 X99.392 Y111.527 E0.07436
+G1 X99.212 Y109.935 E0.01854
+G1 X97.828 Y111.045 E0.04770
+G1 X99.790 Y112.692 E0.07358
+G1 X99.695 Y113.980 E0.02027
+G1 X98.130 Y114.233 E0.05063
+G1 X96.968 Y113.241 E0.01170
+G1 X98.603 Y114.082 E0.08563
+G1 X100.525 Y113.829 E0.06859
+G1 X100.062 Y115.076 E0.07731
+G1 X98.597 Y113.128 E0.02712
+G1 X98.939 Y112.643 E0.01073
+G1 X100.260 Y113.788 E0.04710
+G1 X98.433 Y115.344 E0.05273
+G1 X96.717 Y114.637 E0.05997
+G1 X98.258 Y114.575 E0.06116
+G1 X97.081 Y113.549 E0.08246
+G1 X96.611 Y111.965 E0.05730
+G1 X95.116 Y110.765 E0.04651
+G1 X95.458 Y111.310 E0.06656
+G1 X95.217 Y109.580 E0.06796
+G1 X93.432 Y109.463 E0.04202
+G1 X94.124 Y110.318 E0.02918
+G1 X94.722 Y111.086 E0.04774
+G1 X93.289 Y112.722 E0.05793
+G1 X91.540 Y111.677 E0.08895
+G1 X90.455 Y111.246 E0.07304
+G1 X91.750 Y111.781 E0.06933
+G1 X89.903 Y110.157 E0.08809
+G1 X91.114 Y108.309 E0.01389
+G1 X90.076 Y110.032 E0.02757
+G1 X90.763 Y111.753 E0.06109
+G1 X92.440 Y110.805 E0.02227
+G1 X90.513 Y111.833 E0.01831
+G1 X92.406 Y112.673 E0.02496
+G1 X93.634 Y111.324 E0.05097
+G1 X92.057 Y112.472 E0.08117
+G1 X93.723 Y110.481 E0.07811
+G1 X93.946 Y111.767 E0.05020
+G1 X94.426 Y112.145 E0.07396
+G1 X92.736 Y110.362 E0.05364
+G1 X91.900 Y109.950 E0.01061
+G1 X92.880 Y108.046 E0.07637
+G1 X94.126 Y107.878 E0.01977
+G1 X94.727 Y106.707 E0.04432
+G1 X93.168 Y108.612 E0.05369
+G1 X92.578 Y106.988 E0.06841
+G1 X93.977 Y108.382 E0.01811
+G1 X93.448 Y107.593 E0.07099
+G1 X92.039 Y108.018 E0.08829
+G1 X93.114 Y106.046 E0.01600
+G1 X91.569 Y106.816 E0.05790
+G1 X91.649 Y106.638 E0.04259
+G1 X92.093 Y107.233 E0.08331
+G1 X93.024 Y108.419 E0.08303
+G1 X94.373 Y109.286 E0.01245
+G1 X95.096 Y110.686 E0.04446
+G1 X96.609 Y109.405 E0.08542
+G1 X96.376 Y110.231 E0.03021
+G1 X95.578 Y109.625 E0.03595
+G1 X93.957 Y109.396 E0.08847
+G1 X94.573 Y111.125 E0.07099
+G1 X95.920 Y113.102 E0.07022
+G1 X95.017 Y112.101 E0.04299
+G1 X93.101 Y111.024 E0.08090
+G1 X94.784 Y110.339 E0.07163
+G1 X95.884 Y111.898 E0.07357
+G1 X96.012 Y110.318 E0.07604
+G1 X95.267 Y110.826 E0.03937
+G1 X95.416 Y112.688 E0.02289
+G1 X95.540 Y113.288 E0.05307
+G1 X97.291 Y112.918 E0.08310
+G1 X98.051 Y114.788 E0.01717
+G1 X96.900 Y113.937 E0.08252
+G1 X94.955 Y112.978 E0.06726
+G1 X96.913 Y111.683 E0.04504
+G1 X97.661 Y112.446 E0.06968
+G1 X98.673 Y111.440 E0.03057
+G1 X96.784 Y112.204 E0.02674
+G1 X95.822 Y114.062 E0.06146
+G1 X96.187 Y114.686 E0.05783
+G1 X96.966 Y113.902 E0.01512
+G1 X95.234 Y111.960 E0.03892
+G1 X93.803 Y110.411 E0.04950
+G1 X95.681 Y111.161 E0.03188
+G1 X96.759 Y109.873 E0.01801
+G1 X95.972 Y109.509 E0.06516
+G1 X95.751 Y110.422 E0.01759
+G1 X97.480 Y109.791 E0.07658
+G1 X95.603 Y111.106 E0.02810
+G1 X97.023 Y112.318 E0.06366
+G1 X96.134 Y110.357 E0.02520
+G1 X97.753 Y108.989 E0.06274
+G1 X98.101 Y109.634 E0.02445
+G1 X96.676 Y108.023 E0.08862
+G1 X96.208 Y108.631 E0.05557
+G1 X95.101 Y106.891 E0.01119
+G1 X96.511 Y105.411 E0.08705
+G1 X95.966 Y106.301 E0.02107
+G1 X97.118 Y105.308 E0.03930
+G1 X97.210 Y103.754 E0.02986
+G1 X98.394 Y102.895 E0.04046
+G1 X99.453 Y101.791 E0.02551
+G1 X98.329 Y101.328 E0.03923
+G1 X98.895 Y101.215 E0.07957
+G1 X97.097 Y101.869 E0.07691
+G1 X96.036 Y99.987 E0.04507
+G1 X94.500 Y99.827 E0.06692
+G1 X92.875 Y98.298 E0.04836
+G1 X91.570 Y97.221 E0.04522
+G1 X90.043 Y95.492 E0.03889
+G1 X89.920 Y97.239 E0.05438
+G1 X88.206 Y96.128 E0.06954
+G1 X88.457 Y97.609 E0.08700
+G1 X89.889 Y96.050 E0.08550
+G1 X89.988 Y95.008 E0.02365
+G1 X91.447 Y93.858 E0.01665
+G1 X90.508 Y95.554 E0.04687
+G1 X91.434 Y93.852 E0.04624
+G1 X90.705 Y92.673 E0.06303
+G1 X90.150 Y91.152 E0.08873
+G1 X90.076 Y89.872 E0.01087
+G1 X90.688 Y89.931 E0.01196
+G1 X90.569 Y90.893 E0.05297
+G1 X89.506 Y90.889 E0.05839
+G1 X90.110 Y89.469 E0.07429
+G1 X91.892 Y90.430 E0.07859
+G1 X91.363 Y92.041 E0.02454
+G1 X90.271 Y92.433 E0.08213
+G1 X88.599 Y91.301 E0.01287
+G1 X88.355 Y89.863 E0.02532
+G1 X89.351 Y90.196 E0.08516
+G1 X88.958 Y90.912 E0.01101
+G1 X90.752 Y89.845 E0.04816
+G1 X90.799 Y91.638 E0.04937
+G1 X92.766 Y92.123 E0.02731
+G1 X94.102 Y90.931 E0.08997
+G1 X93.928 Y89.836 E0.08690
+G1 X93.215 Y89.464 E0.03745
+G1 X93.890 Y87.556 E0.03992
+G1 X92.538 Y88.868 E0.01001
+G1 X92.968 Y87.899 E0.04633
+G1 X93.216 Y88.746 E0.02102
+G1 X92.178 Y87.228 E0.08682
+G1 X90.774 Y85.776 E0.05178
+G1 X91.100 Y87.323 E0.01455
+G1 X90.037 Y85.993 E0.05685
+G1 X89.847 Y85.628 E0.08107
+G1 X90.494 Y87.069 E0.08655
+G1 X89.569 Y88.837 E0.04262
+G1 X87.776 Y90.496 E0.01833
+G1 X85.846 Y89.655 E0.03312
+G1 X87.713 Y91.137 E0.04361
+G1 X87.831 Y92.532 E0.07456
+G1 X88.444 Y92.583 E0.01933
+G1 X87.419 Y93.216 E0.05690
+G1 X88.624 Y94.811 E0.08699
+G1 X87.394 Y93.115 E0.08180
+G1 X87.676 Y91.841 E0.06537
+G1 X86.698 Y90.787 E0.03930
+G1 X86.794 Y91.497 E0.01587
+G1 X87.759 Y91.994 E0.04773
+G1 X88.447 Y93.192 E0.01077
+G1 X88.349 Y93.904 E0.06673
+G1 X88.939 Y92.625 E0.08668
+G1 X90.082 Y91.556 E0.04445
+G1 X91.913 Y90.385 E0.04273
+G1 X93.760 Y91.985 E0.02860
+G1 X94.701 Y91.424 E0.06307
+G1 X95.768 Y89.934 E0.02781
+G1 X94.628 Y88.999 E0.01285
+G1 X93.172 Y88.623 E0.04366
+G1 X91.483 Y88.952 E0.08539
+G1 X91.791 Y88.375 E0.06635
+G1 X91.540 Y87.077 E0.04854
+G1 X89.610 Y87.781 E0.02288
+G1 X89.089 Y89.631 E0.07134
+G1 X90.431 Y90.199 E0.06077
+G1 X91.251 Y92.064 E0.02570
+G1 X92.316 Y91.268 E0.03046
+G1 X93.602 Y91.672 E0.07797
+G1 X95.102 Y92.027 E0.02587
+G1 X93.162 Y92.167 E0.06805
+G1 X92.252 Y90.447 E0.01038
+G1 X90.945 Y91.231 E0.01031
+G1 X89.865 Y90.291 E0.06689
+G1 X91.814 Y88.368 E0.01914
+G1 X93.552 Y90.248 E0.02189
+G1 X92.894 Y90.338 E0.03561
+G1 X92.563 Y90.253 E0.03068
+G1 X90.783 Y88.589 E0.02300
+G1 X89.149 Y89.085 E0.06573
+G1 X88.200 Y90.252 E0.06830
+G1 X87.567 Y90.219 E0.02507
+G1 X89.283 Y90.460 E0.01410
+G1 X87.899 Y91.231 E0.04082
+G1 X88.767 Y90.149 E0.07377
+G1 X89.975 Y88.525 E0.05690
+G1 X88.740 Y89.356 E0.07432
+G1 X89.905 Y88.281 E0.01747
+G1 X90.559 Y88.542 E0.02106
+G1 X89.330 Y88.872 E0.01863
+G1 X89.866 Y87.835 E0.03068
+G1 X89.560 Y87.968 E0.06795
+G1 X87.683 Y88.865 E0.02768
+G1 X86.846 Y89.424 E0.06530
+G1 X87.305 Y91.032 E0.02637
+G1 X86.550 Y91.682 E0.03086
+G1 X85.179 Y90.587 E0.07171
+G1 X86.487 Y91.452 E0.08670
+G1 X87.665 Y90.691 E0.03524
+G1 X88.549 Y88.914 E0.05874
+G1 X86.906 Y87.110 E0.05110
+G1 X85.511 Y88.837 E0.08018
+G1 X85.358 Y87.627 E0.01957
+G1 X85.385 Y87.713 E0.03903
+G1 X86.250 Y87.830 E0.07203
+G1 X84.675 Y86.110 E0.04096
+G1 X84.609 Y85.120 E0.06348
+G1 X83.497 Y84.393 E0.04815
+G1 X84.346 Y85.474 E0.03973
+G1 X84.134 Y87.185 E0.08471
+G1 X84.609 Y85.605 E0.04646
+G1 X85.156 Y84.719 E0.01299
+G1 X87.080 Y86.357 E0.02032
+G1 X86.944 Y86.835 E0.03400
+G1 X85.218 Y87.838 E0.07166
+G1 X84.968 Y86.180 E0.04151
+G1 X83.344 Y88.034 E0.01410
+G1 X82.496 Y89.106 E0.02080
+G1 X80.922 Y87.389 E0.02312
+G1 X81.049 Y88.721 E0.02353
+G1 X79.744 Y89.781 E0.04406
+G1 X79.096 Y88.274 E0.02943
+G1 X80.983 Y86.742 E0.03077
+G1 X81.946 Y88.309 E0.08234
+G1 X81.837 Y90.135 E0.05832
+G1 X80.992 Y89.995 E0.06728
+G1 X81.928 Y88.514 E0.02549
+G1 X83.761 Y86.942 E0.07507
+G1 X83.116 Y85.934 E0.03041
+G1 X82.993 Y87.896 E0.02188
+G1 X84.411 Y87.181 E0.02382
+G1 X85.390 Y86.547 E0.02500
+G1 X85.064 Y87.834 E0.07904
+G1 X85.363 Y85.876 E0.07107
+G1 X85.789 Y87.473 E0.08616
+G1 X85.098 Y88.867 E0.07551
+G1 X84.162 Y88.331 E0.03997
+G1 X83.573 Y87.844 E0.01882
+G1 X82.482 Y89.482 E0.04285
+G1 X83.025 Y91.031 E0.07045
+G1 X82.002 Y92.709 E0.07433
+G1 X83.965 Y93.621 E0.07039
+G1 X85.217 Y92.634 E0.06247
+G1 X84.740 Y93.993 E0.02069
+G1 X84.896 Y93.339 E0.07565
+G1 X84.277 Y94.714 E0.07783
+G1 X85.793 Y93.271 E0.08506
+G1 X86.770 Y93.978 E0.06220
+G1 X84.962 Y95.459 E0.05382
+G1 X84.785 Y94.816 E0.07263
+G1 X85.913 Y96.296 E0.02713
+G1 X85.275 Y95.293 E0.01803
+G1 X84.584 Y93.397 E0.07372
+G1 X83.492 Y91.680 E0.01541
+G1 X84.457 Y90.473 E0.04697
+G1 X84.064 Y91.683 E0.08633
+G1 X83.303 Y92.212 E0.08158
+G1 X83.185 Y93.811 E0.06870
+G1 X82.431 Y95.307 E0.05586
+G1 X80.855 Y95.656 E0.07634
+G1 X80.929 Y95.593 E0.04331
+G1 X82.451 Y96.255 E0.02663
+G1 X81.900 Y95.708 E0.08669
+G1 X82.684 Y94.207 E0.08315
+G1 X80.824 Y94.571 E0.04459
+G1 X81.694 Y94.288 E0.01739
+G1 X81.788 Y95.570 E0.07311
+G1 X81.215 Y94.459 E0.06959
+G1 X82.422 Y93.335 E0.08065
+G1 X84.391 Y93.069 E0.04045
+G1 X85.231 Y94.788 E0.02614
+G1 X84.438 Y94.104 E0.06858
+G1 X83.185 Y94.292 E0.05002
+G1 X83.859 Y92.865 E0.08653
+G1 X85.859 Y93.109 E0.07362
+G1 X84.592 Y94.750 E0.05411
+G1 X85.630 Y96.224 E0.03894
+G1 X87.326 Y95.053 E0.01187
+G1 X87.336 Y96.648 E0.08204
+G1 X89.156 Y96.691 E0.08461
+G1 X89.395 Y95.266 E0.06049
+G1 X90.609 Y94.961 E0.05817
+G1 X89.646 Y94.065 E0.04362
+G1 X89.699 Y93.938 E0.01739
+G1 X87.721 Y93.299 E0.06735
+G1 X88.715 Y92.247 E0.03045
+G1 X88.781 Y90.949 E0.05823
+G1 X90.398 Y89.757 E0.05684
+G1 X91.281 Y90.754 E0.06697
+G1 X92.123 Y89.844 E0.07707
+G1 X93.824 Y88.055 E0.08553
+G1 X93.594 Y86.400 E0.01557
+G1 X94.782 Y87.110 E0.02137
+G1 X94.622 Y87.665 E0.08981
+G1 X93.966 Y88.732 E0.02961
+G1 X92.761 Y87.376 E0.04281
+G1 X93.234 Y86.589 E0.02295
+G1 X92.108 Y84.929 E0.02545
+G1 X91.371 Y84.947 E0.02469
+G1 X91.290 Y84.707 E0.08784
+G1 X91.235 Y86.486 E0.04771
+G1 X90.027 Y86.854 E0.02157
+G1 X88.704 Y85.147 E0.06611
+G1 X90.572 Y84.761 E0.03833
+G1 X90.272 Y84.169 E0.06526
+G1 X89.840 Y82.778 E0.07915
+G1 X90.130 Y80.803 E0.07796
+G1 X91.044 Y80.221 E0.06040
+G1 X92.725 Y79.828 E0.04461
+G1 X91.918 Y80.045 E0.06302
+G1 X92.858 Y81.842 E0.02163
+G1 X92.322 Y83.248 E0.07328
+G1 X92.682 Y83.957 E0.03720
+G1 X94.461 Y84.155 E0.04220
+G1 X93.191 Y82.617 E0.08180
+G1 X94.393 Y80.724 E0.03586
+G1 X94.311 Y80.706 E0.03908
+G1 X95.892 Y80.106 E0.05256
+G1 X97.609 Y80.662 E0.04815
+G1 X96.940 Y80.211 E0.05873
+G1 X98.084 Y79.253 E0.03964
+G1 X97.634 Y78.705 E0.08304
+G1 X97.790 Y77.808 E0.03659
+G1 X99.076 Y76.449 E0.06520
+G1 X97.163 Y75.222 E0.01476
+G1 X98.385 Y73.809 E0.02824
+G1 X96.616 Y72.864 E0.06867
+G1 X97.496 Y74.506 E0.08576
+G1 X97.700 Y76.194 E0.01717
+G1 X99.400 Y75.930 E0.02543
+M73 P100 R0
+M107
+; filament used [mm] = 2486.91
+; filament used [cm3] = 5.98
+; estimated printing time (normal mode) = 45m 12s
+
+; prusaslicer_config = begin
+; autoemit_temperature_commands = 1
+; avoid_crossing_curled_overhangs = 0
+; bed_shape = 0x0,250x0,250x210,0x210
+; bed_temperature = 90,60
+; binary_gcode = 0
+; bottom_solid_layers = 5
+; compatible_printers_condition_cummulative = "printer_notes=~/.*PRINTER_VENDOR_PRUSA3D.*/ and printer_notes=~/.*PRINTER_MODEL_MK3.*/ and nozzle_diameter[0]==0.4";"printer_notes=~/.*PRINTER_VENDOR_PRUSA3D.*/ and printer_notes=~/.*PRINTER_MODEL_MK3.*/ and nozzle_diameter[0]!=0.8"
+; extrusion_multiplier = 1,1
+; fill_density = 15%
+; filament_colour = #FF8000;#FFFFFF
+; filament_cost = 25.4
+; filament_density = 1.27,1.24
+; filament_diameter = 1.75,1.75
+; filament_settings_id = "Prusament PETG";"Prusament PLA"
+; filament_type = PETG
+; first_layer_height = 0.2
+; layer_height = 0.15
+; machine_max_feedrate_e = 120,120
+; max_print_speed = 200
+; nozzle_diameter = 0.4,0.4
+; perimeters = 2
+; print_settings_id = 0.15mm QUALITY @MK3
+; printer_model = MK3S
+; printer_settings_id = Original Prusa i3 MK3S & MK3S+ MMU2S
+; silent_mode = 1
+; single_extruder_multi_material = 1
+; temperature = 240,215
+; wipe_tower = 1
+; prusaslicer_config = end
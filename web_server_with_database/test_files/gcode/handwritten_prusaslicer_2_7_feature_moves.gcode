@@ -0,0 +1,622 @@
+; generated by PrusaSlicer 2.7.1+linux-x64-GTK3 on 2024-01-15 at 10:21:42 UTC
+
+; 
+
+; external perimeters extrusion width = 0.45mm
+; perimeters extrusion width = 0.45mm
+; infill extrusion width = 0.45mm
+; solid infill extrusion width = 0.45mm
+; top infill extrusion width = 0.40mm
+; first layer extrusion width = 0.42mm
+
+M73 P0 R1
+M201 X1000 Y1000 Z200 E5000 ; sets maximum accelerations, mm/sec^2
+M203 X200 Y200 Z12 E120 ; sets maximum feedrates, mm / sec
+G21 ; set units to millimeters
+G90 ; use absolute coordinates
+M83 ; use relative distances for extrusion
+;TYPE:Skirt/Brim
+G1 X10 Y10 E.5
+;LAYER_CHANGE
+;Z:0.2
+;HEIGHT:0.15
+G1 Z0.2 F10800
+;TYPE:External perimeter
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X101.750 Y102.750 E0.05000
+G1 X101.500 Y103.000 E0.05000
+G1 X102.000 Y103.250 E0.05000
+G1 X101.750 Y103.500 E0.05000
+G1 X102.250 Y103.750 E0.05000
+G1 X102.000 Y104.000 E0.05000
+G1 X102.500 Y104.250 E0.05000
+G1 X102.250 Y104.500 E0.05000
+G1 X102.750 Y104.750 E0.05000
+G1 X102.500 Y105.000 E0.05000
+G1 X103.000 Y105.250 E0.05000
+G1 X102.750 Y105.500 E0.05000
+G1 X103.250 Y105.750 E0.05000
+G1 X103.000 Y106.000 E0.05000
+G1 X103.500 Y106.250 E0.05000
+G1 X103.250 Y106.500 E0.05000
+G1 X103.750 Y106.750 E0.05000
+G1 X103.500 Y107.000 E0.05000
+G1 X104.000 Y107.250 E0.05000
+G1 X103.750 Y107.500 E0.05000
+G1 X104.250 Y107.750 E0.05000
+G1 X104.000 Y108.000 E0.05000
+G1 X104.500 Y108.250 E0.05000
+G1 X104.250 Y108.500 E0.05000
+G1 X104.750 Y108.750 E0.05000
+G1 X104.500 Y109.000 E0.05000
+G1 X105.000 Y109.250 E0.05000
+G1 X104.750 Y109.500 E0.05000
+G1 X105.250 Y109.750 E0.05000
+G1 X105.000 Y110.000 E0.05000
+G1 X105.500 Y110.250 E0.05000
+G1 X105.250 Y110.500 E0.05000
+G1 X105.750 Y110.750 E0.05000
+G1 X105.500 Y111.000 E0.05000
+G1 X106.000 Y111.250 E0.05000
+G1 X105.750 Y111.500 E0.05000
+G1 X106.250 Y111.750 E0.05000
+G1 X106.000 Y112.000 E0.05000
+G1 X106.500 Y112.250 E0.05000
+G1 X106.250 Y112.500 E0.05000
+G1 X106.750 Y112.750 E0.05000
+G1 X106.500 Y113.000 E0.05000
+G1 X107.000 Y113.250 E0.05000
+G1 X106.750 Y113.500 E0.05000
+G1 X107.250 Y113.750 E0.05000
+G1 X107.000 Y114.000 E0.05000
+G1 X107.500 Y114.250 E0.05000
+G1 X107.250 Y114.500 E0.05000
+G1 X107.750 Y114.750 E0.05000
+G1 X107.500 Y115.000 E0.05000
+G1 X108.000 Y115.250 E0.05000
+G1 X107.750 Y115.500 E0.05000
+G1 X108.250 Y115.750 E0.05000
+G1 X108.000 Y116.000 E0.05000
+G1 X108.500 Y116.250 E0.05000
+G1 X108.250 Y116.500 E0.05000
+G1 X108.750 Y116.750 E0.05000
+G1 X108.500 Y117.000 E0.05000
+G1 X109.000 Y117.250 E0.05000
+G1 X108.750 Y117.500 E0.05000
+G1 X109.250 Y117.750 E0.05000
+G1 X109.000 Y118.000 E0.05000
+G1 X109.500 Y118.250 E0.05000
+G1 X109.250 Y118.500 E0.05000
+G1 X109.750 Y118.750 E0.05000
+G1 X109.500 Y119.000 E0.05000
+G1 X110.000 Y119.250 E0.05000
+G1 X109.750 Y119.500 E0.05000
+G1 X110.250 Y119.750 E0.05000
+G1 X110.000 Y120.000 E0.05000
+G1 X110.500 Y120.250 E0.05000
+G1 X110.250 Y120.500 E0.05000
+G1 X110.750 Y120.750 E0.05000
+G1 X110.500 Y121.000 E0.05000
+G1 X111.000 Y121.250 E0.05000
+G1 X110.750 Y121.500 E0.05000
+G1 X111.250 Y121.750 E0.05000
+G1 X111.000 Y122.000 E0.05000
+G1 X111.500 Y122.250 E0.05000
+G1 X111.250 Y122.500 E0.05000
+G1 X111.750 Y122.750 E0.05000
+G1 X111.500 Y123.000 E0.05000
+G1 X112.000 Y123.250 E0.05000
+G1 X111.750 Y123.500 E0.05000
+G1 X112.250 Y123.750 E0.05000
+G1 X112.000 Y124.000 E0.05000
+G1 X112.500 Y124.250 E0.05000
+G1 X112.250 Y124.500 E0.05000
+G1 X112.750 Y124.750 E0.05000
+G1 X112.500 Y125.000 E0.05000
+G1 X111.500 Y125.000 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+;TYPE:Perimeter
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X101.750 Y102.750 E0.05000
+G1 X101.500 Y103.000 E0.05000
+G1 X102.000 Y103.250 E0.05000
+G1 X101.750 Y103.500 E0.05000
+G1 X102.250 Y103.750 E0.05000
+G1 X102.000 Y104.000 E0.05000
+G1 X102.500 Y104.250 E0.05000
+G1 X102.250 Y104.500 E0.05000
+G1 X102.750 Y104.750 E0.05000
+G1 X102.500 Y105.000 E0.05000
+G1 X103.000 Y105.250 E0.05000
+G1 X102.750 Y105.500 E0.05000
+G1 X103.250 Y105.750 E0.05000
+G1 X103.000 Y106.000 E0.05000
+G1 X103.500 Y106.250 E0.05000
+G1 X103.250 Y106.500 E0.05000
+G1 X103.750 Y106.750 E0.05000
+G1 X103.500 Y107.000 E0.05000
+G1 X104.000 Y107.250 E0.05000
+G1 X103.750 Y107.500 E0.05000
+G1 X104.250 Y107.750 E0.05000
+G1 X104.000 Y108.000 E0.05000
+G1 X104.500 Y108.250 E0.05000
+G1 X104.250 Y108.500 E0.05000
+G1 X104.750 Y108.750 E0.05000
+G1 X104.500 Y109.000 E0.05000
+G1 X105.000 Y109.250 E0.05000
+G1 X104.750 Y109.500 E0.05000
+G1 X105.250 Y109.750 E0.05000
+G1 X105.000 Y110.000 E0.05000
+G1 X105.500 Y110.250 E0.05000
+G1 X105.250 Y110.500 E0.05000
+G1 X105.750 Y110.750 E0.05000
+G1 X105.500 Y111.000 E0.05000
+G1 X106.000 Y111.250 E0.05000
+G1 X105.750 Y111.500 E0.05000
+G1 X106.250 Y111.750 E0.05000
+G1 X106.000 Y112.000 E0.05000
+G1 X106.500 Y112.250 E0.05000
+G1 X106.250 Y112.500 E0.05000
+G1 X106.750 Y112.750 E0.05000
+G1 X106.500 Y113.000 E0.05000
+G1 X107.000 Y113.250 E0.05000
+G1 X106.750 Y113.500 E0.05000
+G1 X107.250 Y113.750 E0.05000
+G1 X107.000 Y114.000 E0.05000
+G1 X107.500 Y114.250 E0.05000
+G1 X107.250 Y114.500 E0.05000
+G1 X107.750 Y114.750 E0.05000
+G1 X107.500 Y115.000 E0.05000
+G1 X108.000 Y115.250 E0.05000
+G1 X107.750 Y115.500 E0.05000
+G1 X108.250 Y115.750 E0.05000
+G1 X108.000 Y116.000 E0.05000
+G1 X108.500 Y116.250 E0.05000
+G1 X108.250 Y116.500 E0.05000
+G1 X108.750 Y116.750 E0.05000
+G1 X108.500 Y117.000 E0.05000
+G1 X109.000 Y117.250 E0.05000
+G1 X108.750 Y117.500 E0.05000
+G1 X109.250 Y117.750 E0.05000
+G1 X109.000 Y118.000 E0.05000
+G1 X109.500 Y118.250 E0.05000
+G1 X109.250 Y118.500 E0.05000
+G1 X109.750 Y118.750 E0.05000
+G1 X109.500 Y119.000 E0.05000
+G1 X110.000 Y119.250 E0.05000
+G1 X109.750 Y119.500 E0.05000
+G1 X110.250 Y119.750 E0.05000
+G1 X110.000 Y120.000 E0.05000
+G1 X110.500 Y120.250 E0.05000
+G1 X110.250 Y120.500 E0.05000
+G1 X110.750 Y120.750 E0.05000
+G1 X110.500 Y121.000 E0.05000
+G1 X111.000 Y121.250 E0.05000
+G1 X110.750 Y121.500 E0.05000
+G1 X111.250 Y121.750 E0.05000
+G1 X111.000 Y122.000 E0.05000
+G1 X111.500 Y122.250 E0.05000
+G1 X111.250 Y122.500 E0.05000
+G1 X111.750 Y122.750 E0.05000
+G1 X111.500 Y123.000 E0.05000
+G1 X112.000 Y123.250 E0.05000
+G1 X111.750 Y123.500 E0.05000
+G1 X112.250 Y123.750 E0.05000
+G1 X112.000 Y124.000 E0.05000
+G1 X112.500 Y124.250 E0.05000
+G1 X112.250 Y124.500 E0.05000
+G1 X112.750 Y124.750 E0.05000
+G1 X112.500 Y125.000 E0.05000
+G1 X111.500 Y125.000 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+;TYPE:Solid infill
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X101.750 Y102.750 E0.05000
+G1 X101.500 Y103.000 E0.05000
+G1 X102.000 Y103.250 E0.05000
+G1 X101.750 Y103.500 E0.05000
+G1 X102.250 Y103.750 E0.05000
+G1 X102.000 Y104.000 E0.05000
+G1 X102.500 Y104.250 E0.05000
+G1 X102.250 Y104.500 E0.05000
+G1 X102.750 Y104.750 E0.05000
+G1 X102.500 Y105.000 E0.05000
+G1 X103.000 Y105.250 E0.05000
+G1 X102.750 Y105.500 E0.05000
+G1 X103.250 Y105.750 E0.05000
+G1 X103.000 Y106.000 E0.05000
+G1 X103.500 Y106.250 E0.05000
+G1 X103.250 Y106.500 E0.05000
+G1 X103.750 Y106.750 E0.05000
+G1 X103.500 Y107.000 E0.05000
+G1 X104.000 Y107.250 E0.05000
+G1 X103.750 Y107.500 E0.05000
+G1 X104.250 Y107.750 E0.05000
+G1 X104.000 Y108.000 E0.05000
+G1 X104.500 Y108.250 E0.05000
+G1 X104.250 Y108.500 E0.05000
+G1 X104.750 Y108.750 E0.05000
+G1 X104.500 Y109.000 E0.05000
+G1 X105.000 Y109.250 E0.05000
+G1 X104.750 Y109.500 E0.05000
+G1 X105.250 Y109.750 E0.05000
+G1 X105.000 Y110.000 E0.05000
+G1 X105.500 Y110.250 E0.05000
+G1 X105.250 Y110.500 E0.05000
+G1 X105.750 Y110.750 E0.05000
+G1 X105.500 Y111.000 E0.05000
+G1 X106.000 Y111.250 E0.05000
+G1 X105.750 Y111.500 E0.05000
+G1 X106.250 Y111.750 E0.05000
+G1 X106.000 Y112.000 E0.05000
+G1 X106.500 Y112.250 E0.05000
+G1 X106.250 Y112.500 E0.05000
+G1 X106.750 Y112.750 E0.05000
+G1 X106.500 Y113.000 E0.05000
+G1 X107.000 Y113.250 E0.05000
+G1 X106.750 Y113.500 E0.05000
+G1 X107.250 Y113.750 E0.05000
+G1 X107.000 Y114.000 E0.05000
+G1 X107.500 Y114.250 E0.05000
+G1 X107.250 Y114.500 E0.05000
+G1 X107.750 Y114.750 E0.05000
+G1 X107.500 Y115.000 E0.05000
+G1 X106.500 Y115.000 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+;TYPE:Wipe tower
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X101.750 Y102.750 E0.05000
+G1 X101.500 Y103.000 E0.05000
+G1 X102.000 Y103.250 E0.05000
+G1 X101.750 Y103.500 E0.05000
+G1 X102.250 Y103.750 E0.05000
+G1 X102.000 Y104.000 E0.05000
+G1 X102.500 Y104.250 E0.05000
+G1 X102.250 Y104.500 E0.05000
+G1 X102.750 Y104.750 E0.05000
+G1 X102.500 Y105.000 E0.05000
+G1 X103.000 Y105.250 E0.05000
+G1 X102.750 Y105.500 E0.05000
+G1 X103.250 Y105.750 E0.05000
+G1 X103.000 Y106.000 E0.05000
+G1 X103.500 Y106.250 E0.05000
+G1 X103.250 Y106.500 E0.05000
+G1 X103.750 Y106.750 E0.05000
+G1 X103.500 Y107.000 E0.05000
+G1 X104.000 Y107.250 E0.05000
+G1 X103.750 Y107.500 E0.05000
+G1 X104.250 Y107.750 E0.05000
+G1 X104.000 Y108.000 E0.05000
+G1 X104.500 Y108.250 E0.05000
+G1 X104.250 Y108.500 E0.05000
+G1 X104.750 Y108.750 E0.05000
+G1 X104.500 Y109.000 E0.05000
+G1 X105.000 Y109.250 E0.05000
+G1 X104.750 Y109.500 E0.05000
+G1 X105.250 Y109.750 E0.05000
+G1 X105.000 Y110.000 E0.05000
+G1 X104.000 Y110.000 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+T1
+;LAYER_CHANGE
+;Z:0.35
+;HEIGHT:0.15
+G1 Z0.35 F10800
+;TYPE:Overhang perimeter
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X101.750 Y102.750 E0.05000
+G1 X101.500 Y103.000 E0.05000
+G1 X102.000 Y103.250 E0.05000
+G1 X101.750 Y103.500 E0.05000
+G1 X102.250 Y103.750 E0.05000
+G1 X102.000 Y104.000 E0.05000
+G1 X102.500 Y104.250 E0.05000
+G1 X102.250 Y104.500 E0.05000
+G1 X102.750 Y104.750 E0.05000
+G1 X102.500 Y105.000 E0.05000
+G1 X101.500 Y105.000 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+;TYPE:Internal infill
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X101.750 Y102.750 E0.05000
+G1 X101.500 Y103.000 E0.05000
+G1 X102.000 Y103.250 E0.05000
+G1 X101.750 Y103.500 E0.05000
+G1 X102.250 Y103.750 E0.05000
+G1 X102.000 Y104.000 E0.05000
+G1 X102.500 Y104.250 E0.05000
+G1 X102.250 Y104.500 E0.05000
+G1 X102.750 Y104.750 E0.05000
+G1 X102.500 Y105.000 E0.05000
+G1 X103.000 Y105.250 E0.05000
+G1 X102.750 Y105.500 E0.05000
+G1 X103.250 Y105.750 E0.05000
+G1 X103.000 Y106.000 E0.05000
+G1 X103.500 Y106.250 E0.05000
+G1 X103.250 Y106.500 E0.05000
+G1 X103.750 Y106.750 E0.05000
+G1 X103.500 Y107.000 E0.05000
+G1 X104.000 Y107.250 E0.05000
+G1 X103.750 Y107.500 E0.05000
+G1 X104.250 Y107.750 E0.05000
+G1 X104.000 Y108.000 E0.05000
+G1 X104.500 Y108.250 E0.05000
+G1 X104.250 Y108.500 E0.05000
+G1 X104.750 Y108.750 E0.05000
+G1 X104.500 Y109.000 E0.05000
+G1 X105.000 Y109.250 E0.05000
+G1 X104.750 Y109.500 E0.05000
+G1 X105.250 Y109.750 E0.05000
+G1 X105.000 Y110.000 E0.05000
+G1 X105.500 Y110.250 E0.05000
+G1 X105.250 Y110.500 E0.05000
+G1 X105.750 Y110.750 E0.05000
+G1 X105.500 Y111.000 E0.05000
+G1 X106.000 Y111.250 E0.05000
+G1 X105.750 Y111.500 E0.05000
+G1 X106.250 Y111.750 E0.05000
+G1 X106.000 Y112.000 E0.05000
+G1 X106.500 Y112.250 E0.05000
+G1 X106.250 Y112.500 E0.05000
+G1 X106.750 Y112.750 E0.05000
+G1 X106.500 Y113.000 E0.05000
+G1 X107.000 Y113.250 E0.05000
+G1 X106.750 Y113.500 E0.05000
+G1 X107.250 Y113.750 E0.05000
+G1 X107.000 Y114.000 E0.05000
+G1 X107.500 Y114.250 E0.05000
+G1 X107.250 Y114.500 E0.05000
+G1 X107.750 Y114.750 E0.05000
+G1 X107.500 Y115.000 E0.05000
+G1 X108.000 Y115.250 E0.05000
+G1 X107.750 Y115.500 E0.05000
+G1 X108.250 Y115.750 E0.05000
+G1 X108.000 Y116.000 E0.05000
+G1 X108.500 Y116.250 E0.05000
+G1 X108.250 Y116.500 E0.05000
+G1 X108.750 Y116.750 E0.05000
+G1 X108.500 Y117.000 E0.05000
+G1 X109.000 Y117.250 E0.05000
+G1 X108.750 Y117.500 E0.05000
+G1 X109.250 Y117.750 E0.05000
+G1 X109.000 Y118.000 E0.05000
+G1 X109.500 Y118.250 E0.05000
+G1 X109.250 Y118.500 E0.05000
+G1 X109.750 Y118.750 E0.05000
+G1 X109.500 Y119.000 E0.05000
+G1 X110.000 Y119.250 E0.05000
+G1 X109.750 Y119.500 E0.05000
+G1 X110.250 Y119.750 E0.05000
+G1 X110.000 Y120.000 E0.05000
+G1 X109.000 Y120.000 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+;TYPE:Support material
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X101.750 Y102.750 E0.05000
+G1 X101.500 Y103.000 E0.05000
+G1 X102.000 Y103.250 E0.05000
+G1 X101.750 Y103.500 E0.05000
+G1 X102.250 Y103.750 E0.05000
+G1 X102.000 Y104.000 E0.05000
+G1 X102.500 Y104.250 E0.05000
+G1 X102.250 Y104.500 E0.05000
+G1 X102.750 Y104.750 E0.05000
+G1 X102.500 Y105.000 E0.05000
+G1 X103.000 Y105.250 E0.05000
+G1 X102.750 Y105.500 E0.05000
+G1 X103.250 Y105.750 E0.05000
+G1 X103.000 Y106.000 E0.05000
+G1 X103.500 Y106.250 E0.05000
+G1 X103.250 Y106.500 E0.05000
+G1 X103.750 Y106.750 E0.05000
+G1 X103.500 Y107.000 E0.05000
+G1 X104.000 Y107.250 E0.05000
+G1 X103.750 Y107.500 E0.05000
+G1 X104.250 Y107.750 E0.05000
+G1 X104.000 Y108.000 E0.05000
+G1 X104.500 Y108.250 E0.05000
+G1 X104.250 Y108.500 E0.05000
+G1 X104.750 Y108.750 E0.05000
+G1 X104.500 Y109.000 E0.05000
+G1 X105.000 Y109.250 E0.05000
+G1 X104.750 Y109.500 E0.05000
+G1 X105.250 Y109.750 E0.05000
+G1 X105.000 Y110.000 E0.05000
+G1 X105.500 Y110.250 E0.05000
+G1 X105.250 Y110.500 E0.05000
+G1 X105.750 Y110.750 E0.05000
+G1 X105.500 Y111.000 E0.05000
+G1 X106.000 Y111.250 E0.05000
+G1 X105.750 Y111.500 E0.05000
+G1 X106.250 Y111.750 E0.05000
+G1 X106.000 Y112.000 E0.05000
+G1 X106.500 Y112.250 E0.05000
+G1 X106.250 Y112.500 E0.05000
+G1 X105.250 Y112.500 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+;TYPE:Support material interface
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X100.250 Y102.500 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+;TYPE:Wipe tower
+;WIDTH:0.45
+G1 X100.500 Y100.250 E0.05000
+G1 X100.250 Y100.500 E0.05000
+G1 X100.750 Y100.750 E0.05000
+G1 X100.500 Y101.000 E0.05000
+G1 X101.000 Y101.250 E0.05000
+G1 X100.750 Y101.500 E0.05000
+G1 X101.250 Y101.750 E0.05000
+G1 X101.000 Y102.000 E0.05000
+G1 X101.500 Y102.250 E0.05000
+G1 X101.250 Y102.500 E0.05000
+G1 X101.750 Y102.750 E0.05000
+G1 X101.500 Y103.000 E0.05000
+G1 X102.000 Y103.250 E0.05000
+G1 X101.750 Y103.500 E0.05000
+G1 X102.250 Y103.750 E0.05000
+G1 X102.000 Y104.000 E0.05000
+G1 X102.500 Y104.250 E0.05000
+G1 X102.250 Y104.500 E0.05000
+G1 X102.750 Y104.750 E0.05000
+G1 X102.500 Y105.000 E0.05000
+G1 X103.000 Y105.250 E0.05000
+G1 X102.750 Y105.500 E0.05000
+G1 X103.250 Y105.750 E0.05000
+G1 X103.000 Y106.000 E0.05000
+G1 X103.500 Y106.250 E0.05000
+G1 X103.250 Y106.500 E0.05000
+G1 X103.750 Y106.750 E0.05000
+G1 X103.500 Y107.000 E0.05000
+G1 X104.000 Y107.250 E0.05000
+G1 X103.750 Y107.500 E0.05000
+G1 X104.250 Y107.750 E0.05000
+G1 X104.000 Y108.000 E0.05000
+G1 X104.500 Y108.250 E0.05000
+G1 X104.250 Y108.500 E0.05000
+G1 X104.750 Y108.750 E0.05000
+G1 X104.500 Y109.000 E0.05000
+G1 X105.000 Y109.250 E0.05000
+G1 X104.750 Y109.500 E0.05000
+G1 X105.250 Y109.750 E0.05000
+G1 X105.000 Y110.000 E0.05000
+G1 X104.000 Y110.000 E-.10000
+G1 E-.70000 F2100
+G1 X50 Y50 F10800
+G1 E.80000 F1500
+;TYPE:Custom
+G1 Z5.35 F720
+M73 P100 R0
+M107
+; filament used [mm] = 15.50, 10.00
+; filament used [cm3] = 0.04, 0.02
+; filament used [g] = 0.05, 0.03
+; total layers count = 2
+; estimated printing time (normal mode) = 1m 12s
+
+; prusaslicer_config = begin
+; autoemit_temperature_commands = 1
+; avoid_crossing_curled_overhangs = 0
+; bed_shape = 0x0,250x0,250x210,0x210
+; bed_temperature = 90,60
+; binary_gcode = 0
+; bottom_solid_layers = 5
+; compatible_printers_condition_cummulative = "printer_notes=~/.*PRINTER_VENDOR_PRUSA3D.*/ and printer_notes=~/.*PRINTER_MODEL_MK3.*/ and nozzle_diameter[0]==0.4";"printer_notes=~/.*PRINTER_VENDOR_PRUSA3D.*/ and printer_notes=~/.*PRINTER_MODEL_MK3.*/ and nozzle_diameter[0]!=0.8"
+; extrusion_multiplier = 1,1
+; fill_density = 15%
+; filament_colour = #FF8000;#FFFFFF
+; filament_cost = 25.4,20.99
+; filament_density = 1.27,1.24
+; filament_diameter = 1.75,1.75
+; filament_settings_id = "Prusament PETG";"Prusament PLA"
+; filament_type = PETG;PLA
+; first_layer_height = 0.2
+; layer_height = 0.15
+; machine_max_feedrate_e = 120,120
+; max_print_speed = 200
+; nozzle_diameter = 0.4,0.4
+; perimeters = 2
+; print_settings_id = 0.15mm QUALITY @MK3
+; printer_model = MK3S
+; printer_settings_id = Original Prusa i3 MK3S & MK3S+ MMU2S
+; silent_mode = 1
+; single_extruder_multi_material = 1
+; temperature = 240,215
+; wipe_tower = 1
+; prusaslicer_config = end
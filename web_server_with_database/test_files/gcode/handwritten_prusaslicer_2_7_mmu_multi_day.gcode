@@ -0,0 +1,1272 @@
+; generated by PrusaSlicer 2.7.1+linux-x64-GTK3 on 2024-01-15 at 10:21:42 UTC
+
+; 
+
+; external perimeters extrusion width = 0.45mm
+; perimeters extrusion width = 0.45mm
+; infill extrusion width = 0.45mm
+; solid infill extrusion width = 0.45mm
+; top infill extrusion width = 0.40mm
+; first layer extrusion width = 0.42mm
+
+M73 P0 R1624
+M73 Q0 S1752
+M201 X1000 Y1000 Z200 E5000 ; sets maximum accelerations, mm/sec^2
+M203 X200 Y200 Z12 E120 ; sets maximum feedrates, mm / sec
+G21 ; set units to millimeters
+G90 ; use absolute coordinates
+M83 ; use relative distances for extrusion
+;LAYER_CHANGE
+;Z:0.2
+;HEIGHT:0.2
+G1 Z.2 F10800
+G1 X99.295 Y98.603 E0.06207
+G1 X97.585 Y98.747 E0.03926
+G1 X95.817 Y98.777 E0.01300
+G1 X95.552 Y97.056 E0.01726
+G1 X95.250 Y98.363 E0.01990
+G1 X94.143 Y98.873 E0.08582
+G1 X94.451 Y98.460 E0.08810
+G1 X92.637 Y99.894 E0.03317
+G1 X91.214 Y98.365 E0.03468
+G1 X92.479 Y97.088 E0.05653
+G1 X93.035 Y96.577 E0.05382
+G1 X91.286 Y94.816 E0.02648
+G1 X92.007 Y94.526 E0.03513
+G1 X92.350 Y94.339 E0.03398
+G1 X93.527 Y95.135 E0.02953
+G1 X93.825 Y95.236 E0.08001
+G1 X94.743 Y94.388 E0.08841
+G1 X93.215 Y94.060 E0.07057
+G1 X91.823 Y94.016 E0.01314
+G1 X92.496 Y95.074 E0.05584
+G1 X93.998 Y94.329 E0.06562
+G1 X94.375 Y94.649 E0.04650
+G1 X95.735 Y96.427 E0.04793
+G1 X96.392 Y94.670 E0.06612
+G1 X96.980 Y96.643 E0.07575
+G1 X96.118 Y96.186 E0.06349
+G1 X94.209 Y96.032 E0.02344
+G1 X92.677 Y94.268 E0.07146
+G1 X91.194 Y93.259 E0.04128
+G1 X92.680 Y91.581 E0.04593
+G1 X92.878 Y93.115 E0.07554
+G1 X94.334 Y92.228 E0.04322
+G1 X93.769 Y93.765 E0.08662
+G1 X92.373 Y92.470 E0.02856
+G1 X91.306 Y92.410 E0.05713
+G1 X90.357 Y90.426 E0.04352
+G1 X89.834 Y90.692 E0.08625
+G1 X90.596 Y90.753 E0.05941
+G1 X91.301 Y88.969 E0.08196
+G1 X92.421 Y90.467 E0.07383
+G1 X91.990 Y90.063 E0.01828
+G1 X92.527 Y88.312 E0.01539
+G1 X91.362 Y86.962 E0.03720
+G1 X89.573 Y84.963 E0.02210
+G1 X87.978 Y84.417 E0.01204
+G1 X89.476 Y84.873 E0.02188
+G1 X88.485 Y84.263 E0.03913
+G1 X86.976 Y85.659 E0.08945
+G1 X86.840 Y85.594 E0.01687
+G1 X85.249 Y84.964 E0.03118
+G1 X86.564 Y83.610 E0.01185
+G1 X88.368 Y83.723 E0.02173
+G1 X88.541 Y81.831 E0.05225
+G1 X90.455 Y83.285 E0.06570
+G1 X89.499 Y82.752 E0.02336
+G1 X90.587 Y82.882 E0.07232
+G1 X89.906 Y81.774 E0.07492
+G1 X91.846 Y83.185 E0.07449
+G1 X93.119 Y84.144 E0.02814
+G1 X93.189 Y83.566 E0.01232
+G1 X91.301 Y82.684 E0.03073
+G1 X92.071 Y84.510 E0.04578
+G1 X93.819 Y86.462 E0.08640
+G1 X93.278 Y85.344 E0.02815
+G1 X92.065 Y84.162 E0.05993
+G1 X93.666 Y85.523 E0.04836
+G1 X94.278 Y86.722 E0.01678
+G1 X94.920 Y88.361 E0.07258
+G1 X95.921 Y88.273 E0.02428
+G1 X97.077 Y87.603 E0.07407
+G1 X98.964 Y87.187 E0.04211
+G1 X100.751 Y88.086 E0.02360
+G1 X99.259 Y86.690 E0.08239
+G1 X100.485 Y85.275 E0.07612
+G1 X102.407 Y85.904 E0.03803
+G1 X102.601 Y84.428 E0.01114
+G1 X104.485 Y85.027 E0.05213
+G1 X106.219 Y84.762 E0.07974
+G1 X107.524 Y83.606 E0.03015
+G1 X106.696 Y82.568 E0.05691
+G1 X105.733 Y82.244 E0.02049
+G1 X107.373 Y81.659 E0.04665
+G1 X107.707 Y83.277 E0.04365
+G1 X109.378 Y83.283 E0.05255
+G1 X109.472 Y81.358 E0.04521
+G1 X108.204 Y79.374 E0.07393
+G1 X106.893 Y79.268 E0.06802
+G1 X107.119 Y78.572 E0.05147
+G1 X107.341 Y79.709 E0.01849
+G1 X107.582 Y78.703 E0.03215
+G1 X108.671 Y78.734 E0.05494
+G1 X109.711 Y80.384 E0.04546
+G1 X110.161 Y80.406 E0.05097
+G1 X110.932 Y80.215 E0.05266
+G1 X110.844 Y81.981 E0.06594
+G1 X112.351 Y83.750 E0.03077
+G1 X112.589 Y85.523 E0.07720
+G1 X111.137 Y84.009 E0.04537
+G1 X109.427 Y82.972 E0.01585
+G1 X110.105 Y84.108 E0.08176
+G1 X108.723 Y84.972 E0.06282
+G1 X107.295 Y86.504 E0.08740
+G1 X106.173 Y88.314 E0.04186
+G1 X106.122 Y90.273 E0.07660
+G1 X104.768 Y89.999 E0.05125
+G1 X104.125 Y88.782 E0.03548
+G1 X105.013 Y86.860 E0.05432
+G1 X104.775 Y84.932 E0.03652
+G1 X105.271 Y84.981 E0.01514
+G1 X107.211 Y86.135 E0.08774
+G1 X105.630 Y85.197 E0.01317
+G1 X106.746 Y84.279 E0.02036
+G1 X106.435 Y85.925 E0.07552
+G1 X105.470 Y84.522 E0.08353
+G1 X105.752 Y85.324 E0.01716
+G1 X103.982 Y86.077 E0.04403
+G1 X102.272 Y87.830 E0.06076
+G1 X103.478 Y86.165 E0.07850
+G1 X101.745 Y87.616 E0.04630
+G1 X101.101 Y87.828 E0.08413
+G1 X100.173 Y86.345 E0.05215
+G1 X99.127 Y84.783 E0.02292
+G1 X97.328 Y83.590 E0.03496
+G1 X96.548 Y84.628 E0.03320
+G1 X96.549 Y83.340 E0.03776
+G1 X94.621 Y82.341 E0.01123
+G1 X95.553 Y82.546 E0.02516
+G1 X95.453 Y84.284 E0.01850
+G1 X96.728 Y84.013 E0.04960
+G1 X98.067 Y83.585 E0.05053
+G1 X98.818 Y85.515 E0.03742
+G1 X100.147 Y86.342 E0.06088
+G1 X99.766 Y85.732 E0.01435
+G1 X98.285 Y84.015 E0.06927
+G1 X97.307 Y82.668 E0.01676
+G1 X98.672 Y84.150 E0.06364
+G1 X97.800 Y83.119 E0.03344
+G1 X97.638 Y81.749 E0.04567
+G1 X96.691 Y83.596 E0.08781
+G1 X96.879 Y82.574 E0.08725
+G1 X96.117 Y82.000 E0.01009
+G1 X95.644 Y81.899 E0.05022
+G1 X94.448 Y81.918 E0.01040
+G1 X93.504 Y80.277 E0.04196
+G1 X91.671 Y78.367 E0.03434
+G1 X90.602 Y78.709 E0.05234
+G1 X91.604 Y79.339 E0.06728
+G1 X93.121 Y78.897 E0.03609
+G1 X95.060 Y77.495 E0.06793
+G1 X95.633 Y75.670 E0.07682
+G1 X97.200 Y76.180 E0.06871
+G1 X98.449 Y74.737 E0.05190
+G1 X98.467 Y76.077 E0.07437
+G1 X99.772 Y76.413 E0.08143
+G1 X100.504 Y77.186 E0.02840
+G1 X98.629 Y75.719 E0.03886
+G1 X97.048 Y77.062 E0.05468
+G1 X97.559 Y77.567 E0.06445
+G1 X97.517 Y75.580 E0.07382
+G1 X98.510 Y75.592 E0.05282
+G1 X99.147 Y73.856 E0.06894
+G1 X98.156 Y72.154 E0.03124
+G1 X99.073 Y70.975 E0.06919
+G1 X100.976 Y70.951 E0.04060
+G1 X100.892 Y71.686 E0.07136
+G1 X101.360 Y72.257 E0.01620
+G1 X99.949 Y71.272 E0.06946
+G1 X99.167 Y71.543 E0.01100
+G1 X97.410 Y70.618 E0.06376
+G1 X98.179 Y71.321 E0.03327
+G1 X98.245 Y71.180 E0.04731
+G1 X96.719 Y72.755 E0.02594
+G1 X98.631 Y74.500 E0.01140
+G1 X98.467 Y75.779 E0.08745
+G1 X98.265 Y74.854 E0.02679
+G1 X100.047 Y73.697 E0.05652
+G1 X98.614 Y73.793 E0.08622
+G1 X97.145 Y75.074 E0.05070
+G1 X98.692 Y75.887 E0.02851
+G1 X100.283 Y75.832 E0.01199
+G1 X98.297 Y75.798 E0.04606
+G1 X97.505 Y74.361 E0.03752
+G1 X96.769 Y75.722 E0.01014
+G1 X97.772 Y77.079 E0.01960
+G1 X99.478 Y77.931 E0.08213
+G1 X98.637 Y77.420 E0.04143
+G1 X100.632 Y77.776 E0.03886
+G1 X100.345 Y76.877 E0.01386
+G1 X98.751 Y78.216 E0.03285
+G1 X100.494 Y77.213 E0.03126
+G1 X100.538 Y75.972 E0.03987
+G1 X102.362 Y77.509 E0.07496
+G1 X102.886 Y79.163 E0.08526
+G1 X103.083 Y80.041 E0.01396
+G1 X104.012 Y79.845 E0.07021
+G1 X104.590 Y78.990 E0.01392
+G1 X106.297 Y77.499 E0.04777
+G1 X105.672 Y76.690 E0.06912
+G1 X107.577 Y75.731 E0.06248
+G1 X106.780 Y75.960 E0.04155
+G1 X105.450 Y74.607 E0.02663
+G1 X107.074 Y74.595 E0.02760
+G1 X108.699 Y76.581 E0.04600
+G1 X107.257 Y75.350 E0.01726
+G1 X106.625 Y73.715 E0.02913
+G1 X105.658 Y73.993 E0.08098
+G1 X106.657 Y73.644 E0.04311
+G1 X106.754 Y73.152 E0.03706
+G1 X105.002 Y72.262 E0.08741
+G1 X103.505 Y72.276 E0.06037
+G1 X104.957 Y71.139 E0.03168
+G1 X103.951 Y70.738 E0.04567
+G1 X105.766 Y72.133 E0.07983
+G1 X103.854 Y70.262 E0.06676
+G1 X105.436 Y70.155 E0.05697
+G1 X103.437 Y69.721 E0.08415
+G1 X104.739 Y71.143 E0.08778
+G1 X103.733 Y69.579 E0.02235
+G1 X103.823 Y70.308 E0.08532
+G1 X104.710 Y70.897 E0.07118
+G1 X104.539 Y71.103 E0.01316
+G1 X105.668 Y70.033 E0.08359
+G1 X106.250 Y69.248 E0.02024
+G1 X105.257 Y69.794 E0.06589
+G1 X103.706 Y68.075 E0.05195
+G1 X104.038 Y67.627 E0.02789
+G1 X104.442 Y65.669 E0.03412
+G1 X104.285 Y67.505 E0.06157
+G1 X105.820 Y67.406 E0.02878
+G1 X104.808 Y69.249 E0.06637
+G1 X104.037 Y67.336 E0.04986
+G1 X104.735 Y67.016 E0.03058
+G1 X105.405 Y68.717 E0.02814
+G1 X103.541 Y68.069 E0.04364
+G1 X104.271 Y66.861 E0.07377
+G1 X105.228 Y66.881 E0.02642
+G1 X107.107 Y66.127 E0.07560
+G1 X106.031 Y65.013 E0.07084
+G1 X105.210 Y66.821 E0.04966
+G1 X103.960 Y65.714 E0.04336
+G1 X104.621 Y67.509 E0.02171
+G1 X104.195 Y66.361 E0.08793
+G1 X102.762 Y64.568 E0.01481
+G1 X102.336 Y66.161 E0.08069
+G1 X103.266 Y68.151 E0.08453
+G1 X102.583 Y66.893 E0.08487
+G1 X103.569 Y65.021 E0.06315
+G1 X103.083 Y64.516 E0.03654
+G1 X101.760 Y62.528 E0.03238
+G1 X101.166 Y64.350 E0.01990
+G1 X103.023 Y63.180 E0.03853
+G1 X104.309 Y64.468 E0.04460
+G1 X102.506 Y64.361 E0.03982
+G1 X104.184 Y63.133 E0.03914
+G1 X105.772 Y61.255 E0.04286
+G1 X107.020 Y62.321 E0.01325
+G1 X105.159 Y60.572 E0.08361
+G1 X104.187 Y61.561 E0.08188
+G1 X103.543 Y60.650 E0.08662
+G1 X104.011 Y59.699 E0.06733
+G1 X103.277 Y58.801 E0.01030
+G1 X104.300 Y60.467 E0.06072
+G1 X106.073 Y58.564 E0.02871
+G1 X105.974 Y60.391 E0.08631
+G1 X105.520 Y59.395 E0.04440
+G1 X105.494 Y61.108 E0.02464
+G1 X106.704 Y62.062 E0.07582
+G1 X107.795 Y62.491 E0.03622
+G1 X107.073 Y61.938 E0.07258
+G1 X105.389 Y60.727 E0.07023
+G1 X104.379 Y58.986 E0.01271
+G1 X104.589 Y58.289 E0.08842
+G1 X106.123 Y60.241 E0.03119
+G1 X104.459 Y58.626 E0.04988
+G1 X105.298 Y58.414 E0.02874
+G1 X104.966 Y58.895 E0.06393
+G1 X105.958 Y60.283 E0.06315
+G1 X104.442 Y61.647 E0.03350
+G1 X104.710 Y61.139 E0.06905
+G1 X103.507 Y60.129 E0.02963
+G1 X102.120 Y61.665 E0.05626
+G1 X101.425 Y61.249 E0.08940
+G1 X101.454 Y60.175 E0.07468
+G1 X102.068 Y62.139 E0.01819
+G1 X101.967 Y63.415 E0.07724
+G1 X103.624 Y61.577 E0.03349
+G1 X102.101 Y60.335 E0.08784
+G1 X102.434 Y62.056 E0.03978
+G1 X103.898 Y61.852 E0.03080
+G1 X105.010 Y63.635 E0.01846
+G1 X105.394 Y64.115 E0.02741
+G1 X104.869 Y62.680 E0.02632
+G1 X103.889 Y63.078 E0.06213
+G1 X102.702 Y61.123 E0.03618
+G1 X103.416 Y59.864 E0.03498
+G1 X102.229 Y61.045 E0.05384
+G1 X100.482 Y59.451 E0.04162
+G1 X100.683 Y60.007 E0.01729
+G1 X99.338 Y60.789 E0.04278
+G1 X98.471 Y60.019 E0.08626
+G1 X97.720 Y60.285 E0.03857
+G1 X97.386 Y61.742 E0.08973
+G1 X96.841 Y60.531 E0.06824
+G1 X95.656 Y58.555 E0.08213
+G1 X95.351 Y59.836 E0.04250
+G1 X96.882 Y59.680 E0.02300
+G1 X94.942 Y59.886 E0.06125
+G1 X96.581 Y58.242 E0.05978
+G1 X96.064 Y58.260 E0.02167
+G1 X95.197 Y58.345 E0.08404
+G1 X93.633 Y58.307 E0.07439
+G1 X95.500 Y57.096 E0.02013
+G1 X97.272 Y58.998 E0.04862
+G1 X95.486 Y60.703 E0.04103
+G1 X97.103 Y61.184 E0.07596
+G1 X95.744 Y62.328 E0.02777
+G1 X95.362 Y63.713 E0.07634
+G1 X94.094 Y62.586 E0.04198
+G1 X94.165 Y62.120 E0.01984
+G1 X93.153 Y63.019 E0.08178
+G1 X91.318 Y63.269 E0.07060
+G1 X89.470 Y64.622 E0.01942
+G1 X89.868 Y64.822 E0.06016
+G1 X89.093 Y64.502 E0.05661
+G1 X88.796 Y65.137 E0.04574
+G1 X88.550 Y63.231 E0.05951
+G1 X88.508 Y62.172 E0.07109
+G1 X89.628 Y62.005 E0.02437
+G1 X89.520 Y60.433 E0.02028
+G1 X89.243 Y58.800 E0.04536
+G1 X89.284 Y56.963 E0.06091
+G1 X87.612 Y57.897 E0.07221
+G1 X87.658 Y56.114 E0.05031
+G1 X87.170 Y57.918 E0.02089
+G1 X88.598 Y59.902 E0.06857
+G1 X89.858 Y58.677 E0.08854
+G1 X89.826 Y60.504 E0.08328
+G1 X88.486 Y61.657 E0.08445
+G1 X86.748 Y61.061 E0.07049
+G1 X85.383 Y62.647 E0.03200
+G1 X86.646 Y61.221 E0.05018
+G1 X88.325 Y60.055 E0.03103
+G1 X88.349 Y59.331 E0.01295
+G1 X87.078 Y57.976 E0.08491
+G1 X87.796 Y59.557 E0.02350
+G1 X88.936 Y58.018 E0.05246
+G1 X89.481 Y57.457 E0.07984
+G1 X89.702 Y57.777 E0.08060
+G1 X88.120 Y59.749 E0.06038
+G1 X87.697 Y60.940 E0.03118
+G1 X89.659 Y61.249 E0.03882
+G1 X90.718 Y61.018 E0.02414
+G1 X91.692 Y59.211 E0.07559
+G1 X90.707 Y59.768 E0.08872
+G1 X91.050 Y60.423 E0.03501
+G1 X89.058 Y58.558 E0.02195
+G1 X89.522 Y58.287 E0.05101
+G1 X91.104 Y56.815 E0.02818
+G1 X91.716 Y54.904 E0.01021
+G1 X91.136 Y53.330 E0.03857
+G1 X90.033 Y53.664 E0.05713
+G1 X88.850 Y54.160 E0.04799
+G1 X87.389 Y55.906 E0.02949
+G1 X85.986 Y54.289 E0.06106
+G1 X87.471 Y55.418 E0.04216
+G1 X86.528 Y53.464 E0.06160
+G1 X86.778 Y52.865 E0.06165
+G1 X86.553 Y54.614 E0.06868
+G1 X85.547 Y56.228 E0.01352
+G1 X85.673 Y55.852 E0.02901
+G1 X83.906 Y56.967 E0.01099
+G1 X84.110 Y58.731 E0.02138
+G1 X82.908 Y59.163 E0.05056
+G1 X83.474 Y60.417 E0.02397
+G1 X82.712 Y59.618 E0.01388
+G1 X84.269 Y60.750 E0.06723
+G1 X82.295 Y62.128 E0.06961
+G1 X82.156 Y63.095 E0.04620
+G1 X81.060 Y61.516 E0.02858
+G1 X79.215 Y60.858 E0.06997
+G1 X79.995 Y62.239 E0.06693
+G1 X79.059 Y62.454 E0.04488
+G1 X80.213 Y62.547 E0.03122
+G1 X80.781 Y64.408 E0.02736
+G1 X82.301 Y62.469 E0.03083
+G1 X81.246 Y63.444 E0.08558
+G1 X82.230 Y62.752 E0.08041
+G1 X81.544 Y61.709 E0.08261
+G1 X82.067 Y62.480 E0.06322
+G1 X83.983 Y62.358 E0.07718
+G1 X84.774 Y63.788 E0.04498
+G1 X85.672 Y64.069 E0.03462
+G1 X84.520 Y64.560 E0.01622
+G1 X86.163 Y63.138 E0.01215
+G1 X84.590 Y64.854 E0.03759
+G1 X83.157 Y62.969 E0.01333
+G1 X83.928 Y63.504 E0.06576
+G1 X84.875 Y61.767 E0.05724
+G1 X84.329 Y63.038 E0.07557
+G1 X85.894 Y61.302 E0.07942
+G1 X87.551 Y63.079 E0.01857
+G1 X86.374 Y61.527 E0.01275
+G1 X87.765 Y62.775 E0.06073
+G1 X89.065 Y63.301 E0.03299
+G1 X87.465 Y61.692 E0.07059
+G1 X86.285 Y60.969 E0.04390
+G1 X84.369 Y59.996 E0.03261
+G1 X85.232 Y59.468 E0.03567
+G1 X87.088 Y59.483 E0.07811
+G1 X87.561 Y57.607 E0.04303
+G1 X87.306 Y58.699 E0.03774
+G1 X88.125 Y58.850 E0.02733
+G1 X89.574 Y57.214 E0.07558
+G1 X88.256 Y55.219 E0.02616
+G1 X89.304 Y57.131 E0.01035
+G1 X89.268 Y57.097 E0.07374
+G1 X88.006 Y57.075 E0.03777
+G1 X89.333 Y56.117 E0.08551
+G1 X88.468 Y54.976 E0.06596
+G1 X88.461 Y53.416 E0.06092
+G1 X86.785 Y54.567 E0.06577
+G1 X87.932 Y55.079 E0.03845
+G1 X87.538 Y54.657 E0.08123
+G1 X85.882 Y56.211 E0.01201
+G1 X84.707 Y55.264 E0.08210
+G1 X84.711 Y54.781 E0.08072
+G1 X83.646 Y54.625 E0.05252
+G1 X84.664 Y55.637 E0.06170
+G1 X84.058 Y54.943 E0.02243
+G1 X85.430 Y55.592 E0.06936
+G1 X84.108 Y55.347 E0.07187
+G1 X84.425 Y53.851 E0.04696
+G1 X85.965 Y52.803 E0.02533
+G1 X85.171 Y53.616 E0.07749
+G1 X83.790 Y52.240 E0.02981
+G1 X83.096 Y52.328 E0.02287
+G1 X82.408 Y51.085 E0.08801
+G1 X83.323 Y49.493 E0.08699
+G1 X81.730 Y49.030 E0.08871
+G1 X82.909 Y49.963 E0.04479
+G1 X81.694 Y50.515 E0.01855
+G1 X80.520 Y50.068 E0.01271
+G1 X80.116 Y51.232 E0.06548
+G1 X80.118 Y51.762 E0.04706
+G1 X78.685 Y52.176 E0.04238
+G1 X79.649 Y53.808 E0.04440
+G1 X79.945 Y54.805 E0.04369
+G1 X78.859 Y55.694 E0.08041
+G1 X79.955 Y56.494 E0.07820
+G1 X80.674 Y57.060 E0.04631
+G1 X79.926 Y57.573 E0.01783
+G1 X79.604 Y58.703 E0.06705
+G1 X80.123 Y57.703 E0.04389
+G1 X79.943 Y58.189 E0.04275
+G1 X80.644 Y59.910 E0.02464
+G1 X81.262 Y61.023 E0.04110
+G1 X81.222 Y62.921 E0.01305
+G1 X81.395 Y61.565 E0.07254
+G1 X83.157 Y61.642 E0.01809
+G1 X83.456 Y61.806 E0.06738
+G1 X83.504 Y62.363 E0.07632
+G1 X83.591 Y62.004 E0.08584
+G1 X82.432 Y62.742 E0.04140
+G1 X83.482 Y61.231 E0.08876
+G1 X82.904 Y59.458 E0.03195
+G1 X82.503 Y57.511 E0.04349
+G1 X82.185 Y58.304 E0.03817
+G1 X81.246 Y57.202 E0.06932
+G1 X83.006 Y57.310 E0.02751
+G1 X84.211 Y56.878 E0.02696
+G1 X82.729 Y57.984 E0.07477
+G1 X83.266 Y57.861 E0.05496
+G1 X82.170 Y59.716 E0.03825
+G1 X82.725 Y60.991 E0.07529
+G1 X82.597 Y60.169 E0.05386
+G1 X81.098 Y61.504 E0.03838
+G1 X82.501 Y60.573 E0.04009
+G1 X81.515 Y60.278 E0.02487
+G1 X79.526 Y61.165 E0.03250
+G1 X78.506 Y60.372 E0.04836
+G1 X78.220 Y60.921 E0.06274
+G1 X77.669 Y62.636 E0.07836
+G1 X75.898 Y63.948 E0.08246
+G1 X77.034 Y62.509 E0.07651
+G1 X77.566 Y60.569 E0.01092
+G1 X79.373 Y61.193 E0.03000
+G1 X77.779 Y59.764 E0.02869
+G1 X78.885 Y59.150 E0.02221
+G1 X80.501 Y60.317 E0.02343
+G1 X82.066 Y60.750 E0.07250
+G1 X82.739 Y62.326 E0.07305
+G1 X84.095 Y61.115 E0.06542
+G1 X84.218 Y62.083 E0.04509
+G1 X85.749 Y62.303 E0.03116
+G1 X84.685 Y60.861 E0.04945
+G1 X82.919 Y60.729 E0.02155
+G1 X82.885 Y60.722 E0.05316
+G1 X84.336 Y58.748 E0.07726
+G1 X84.208 Y58.998 E0.06322
+G1 X85.570 Y58.498 E0.04351
+G1 X87.413 Y56.800 E0.06096
+G1 X87.957 Y54.914 E0.05877
+G1 X88.687 Y56.640 E0.03644
+G1 X90.614 Y56.682 E0.04877
+G1 X92.205 Y54.818 E0.06745
+G1 X92.706 Y54.172 E0.07894
+G1 X92.170 Y54.070 E0.05204
+G1 X93.253 Y52.913 E0.04482
+G1 X92.942 Y53.129 E0.07614
+G1 X92.114 Y54.440 E0.04230
+G1 X92.129 Y53.527 E0.05051
+G1 X94.029 Y54.145 E0.07336
+G1 X93.352 Y53.414 E0.03394
+G1 X93.698 Y53.953 E0.07274
+G1 X91.858 Y54.844 E0.08085
+G1 X92.040 Y53.043 E0.03403
+G1 X90.065 Y51.802 E0.08371
+G1 X90.499 Y52.434 E0.07312
+G1 X92.139 Y52.881 E0.05934
+G1 X92.646 Y53.667 E0.05770
+G1 X93.370 Y52.517 E0.06336
+G1 X93.201 Y53.568 E0.01811
+G1 X91.927 Y51.716 E0.07196
+G1 X93.583 Y52.338 E0.03951
+G1 X94.873 Y53.485 E0.05497
+G1 X93.905 Y52.693 E0.04374
+G1 X93.179 Y52.416 E0.06134
+G1 X94.915 Y50.634 E0.05540
+G1 X93.072 Y49.109 E0.07483
+G1 X93.374 Y50.784 E0.04572
+G1 X91.430 Y50.332 E0.05736
+G1 X93.181 Y52.256 E0.04804
+G1 X92.831 Y50.664 E0.06156
+G1 X91.680 Y49.271 E0.01124
+G1 X89.699 Y50.006 E0.01973
+G1 X91.564 Y48.358 E0.07956
+G1 X90.080 Y46.430 E0.06755
+G1 X89.049 Y47.364 E0.02499
+G1 X87.250 Y48.460 E0.06708
+G1 X88.672 Y49.379 E0.01674
+G1 X89.186 Y50.216 E0.04685
+G1 X90.916 Y49.232 E0.08715
+G1 X91.784 Y47.277 E0.01118
+G1 X92.387 Y48.547 E0.01637
+G1 X91.632 Y49.465 E0.02328
+G1 X93.075 Y49.410 E0.01478
+G1 X92.546 Y49.710 E0.04510
+G1 X93.253 Y48.289 E0.07379
+G1 X92.706 Y48.869 E0.06038
+G1 X92.378 Y48.412 E0.07290
+G1 X94.158 Y49.550 E0.05535
+G1 X93.327 Y47.793 E0.08792
+G1 X94.140 Y49.103 E0.03656
+G1 X94.564 Y51.012 E0.07650
+G1 X94.968 Y50.247 E0.04428
+G1 X96.521 Y49.753 E0.06479
+G1 X96.928 Y51.338 E0.07460
+G1 X96.061 Y49.345 E0.03104
+G1 X95.751 Y49.691 E0.07528
+G1 X97.301 Y47.860 E0.07666
+G1 X98.548 Y49.329 E0.05575
+G1 X97.643 Y50.734 E0.07456
+G1 X98.382 Y52.389 E0.03775
+G1 X96.722 Y52.604 E0.07379
+G1 X95.524 Y53.604 E0.08454
+G1 X94.460 Y54.032 E0.06421
+G1 X94.321 Y52.858 E0.03038
+G1 X95.326 Y54.025 E0.04678
+G1 X93.677 Y55.251 E0.07177
+G1 X92.608 Y55.570 E0.08175
+G1 X94.148 Y55.657 E0.04813
+G1 X94.506 Y54.414 E0.02539
+G1 X93.228 Y55.218 E0.03903
+G1 X93.486 Y54.828 E0.05138
+G1 X92.082 Y53.006 E0.08977
+G1 X91.578 Y51.431 E0.06062
+G1 X92.728 Y50.055 E0.05778
+G1 X92.107 Y50.133 E0.01165
+G1 X90.242 Y52.095 E0.07929
+G1 X90.187 Y52.364 E0.03093
+G1 X91.304 Y52.067 E0.08572
+G1 X92.373 Y53.343 E0.08708
+G1 X91.389 Y51.494 E0.02608
+G1 X90.112 Y49.829 E0.01408
+G1 X90.341 Y51.312 E0.04666
+G1 X92.130 Y52.951 E0.01513
+G1 X92.522 Y52.541 E0.01959
+G1 X94.360 Y51.570 E0.05516
+G1 X94.922 Y53.395 E0.06358
+G1 X94.495 Y53.189 E0.02278
+G1 X96.358 Y55.155 E0.02774
+G1 X94.512 Y54.179 E0.03816
+G1 X96.123 Y55.797 E0.07698
+G1 X94.311 Y56.943 E0.06677
+G1 X94.898 Y58.884 E0.01446
+G1 X93.477 Y59.904 E0.08515
+G1 X94.185 Y59.099 E0.05732
+G1 X95.216 Y57.521 E0.03591
+G1 X94.244 Y56.018 E0.04851
+G1 X92.919 Y54.971 E0.02145
+T1
+;LAYER_CHANGE
+;Z:0.35
+;HEIGHT:0.15
+G1 X100.711 Y98.050 E0.06738
+G1 X99.491 Y96.195 E0.08421
+G1 X98.373 Y97.930 E0.07934
+G1 X99.928 Y96.489 E0.04578
+G1 X98.316 Y98.205 E0.07738
+G1 X98.829 Y98.014 E0.03718
+G1 X100.122 Y97.924 E0.06025
+G1 X98.693 Y96.811 E0.01454
+G1 X99.548 Y97.024 E0.02158
+G1 X101.031 Y96.090 E0.04294
+G1 X99.653 Y95.174 E0.07717
+G1 X98.991 Y93.845 E0.04928
+G1 X98.264 Y95.458 E0.01913
+G1 X100.178 Y93.685 E0.08160
+G1 X100.851 Y92.530 E0.04820
+G1 X99.996 Y91.561 E0.02613
+G1 X99.453 Y93.525 E0.08985
+G1 X101.154 Y91.916 E0.03315
+G1 X102.738 Y90.146 E0.06812
+G1 X101.912 Y92.060 E0.01128
+G1 X103.141 Y91.424 E0.02121
+G1 X101.148 Y92.753 E0.05213
+G1 X99.892 Y92.494 E0.08296
+G1 X98.765 Y92.779 E0.02105
+G1 X97.485 Y93.861 E0.06693
+G1 X96.272 Y92.178 E0.01699
+G1 X96.706 Y92.160 E0.03191
+G1 X95.530 Y92.610 E0.06662
+G1 X96.777 Y92.941 E0.02618
+G1 X95.039 Y93.872 E0.04265
+G1 X95.926 Y92.094 E0.07485
+G1 X95.267 Y93.461 E0.07916
+G1 X95.239 Y91.523 E0.08282
+G1 X95.145 Y93.011 E0.03130
+G1 X93.890 Y94.338 E0.03937
+G1 X92.544 Y93.822 E0.05759
+G1 X90.562 Y93.902 E0.04566
+G1 X90.625 Y92.385 E0.06717
+G1 X91.891 Y93.846 E0.03568
+G1 X92.736 Y93.372 E0.07011
+G1 X90.980 Y94.863 E0.08632
+G1 X90.960 Y94.917 E0.05244
+G1 X91.109 Y92.999 E0.08739
+G1 X90.004 Y91.729 E0.01821
+G1 X89.006 Y92.997 E0.01241
+G1 X87.391 Y93.793 E0.02561
+G1 X85.462 Y94.191 E0.05612
+G1 X85.554 Y95.002 E0.01823
+G1 X87.032 Y95.870 E0.01361
+G1 X85.524 Y95.844 E0.05006
+G1 X84.643 Y94.332 E0.04245
+G1 X83.190 Y94.700 E0.07889
+G1 X81.779 Y94.991 E0.06973
+G1 X80.437 Y96.295 E0.08501
+G1 X79.992 Y95.977 E0.07718
+G1 X80.094 Y95.560 E0.08530
+G1 X81.202 Y94.914 E0.02923
+G1 X80.542 Y94.656 E0.08850
+G1 X81.760 Y96.307 E0.07520
+G1 X83.150 Y94.521 E0.05139
+G1 X84.982 Y96.259 E0.02994
+G1 X84.670 Y96.789 E0.03915
+G1 X84.793 Y95.067 E0.04464
+G1 X84.812 Y93.150 E0.02115
+G1 X86.691 Y94.256 E0.08495
+G1 X87.224 Y95.493 E0.08075
+G1 X88.763 Y93.631 E0.06133
+G1 X87.826 Y94.344 E0.03187
+G1 X87.995 Y96.042 E0.05970
+G1 X86.997 Y96.123 E0.04470
+G1 X88.800 Y95.273 E0.03443
+G1 X89.391 Y93.755 E0.05754
+G1 X91.215 Y93.810 E0.03147
+G1 X91.081 Y93.945 E0.02187
+G1 X89.576 Y92.471 E0.03349
+G1 X89.202 Y91.624 E0.02947
+G1 X87.554 Y91.809 E0.07718
+G1 X87.994 Y92.090 E0.06203
+G1 X86.798 Y92.931 E0.04687
+G1 X86.990 Y93.383 E0.04752
+G1 X86.233 Y92.352 E0.02773
+G1 X86.282 Y91.884 E0.05685
+G1 X84.330 Y91.295 E0.07895
+G1 X83.284 Y91.522 E0.04931
+G1 X82.423 Y93.472 E0.03364
+G1 X83.512 Y92.106 E0.01534
+G1 X84.997 Y91.866 E0.01496
+G1 X84.548 Y91.625 E0.06883
+G1 X82.985 Y90.526 E0.08674
+G1 X83.940 Y89.144 E0.03696
+G1 X83.350 Y89.846 E0.05930
+G1 X84.750 Y91.130 E0.05142
+G1 X85.705 Y92.103 E0.07078
+G1 X85.606 Y93.243 E0.06668
+G1 X87.265 Y91.752 E0.07967
+G1 X85.282 Y92.815 E0.05687
+G1 X85.273 Y94.666 E0.05576
+G1 X84.945 Y95.801 E0.07982
+G1 X85.374 Y95.319 E0.04618
+G1 X85.206 Y96.211 E0.03343
+G1 X84.769 Y96.433 E0.04076
+G1 X84.057 Y97.581 E0.07797
+G1 X84.055 Y97.357 E0.02474
+G1 X83.271 Y95.937 E0.05603
+G1 X83.597 Y94.289 E0.08361
+G1 X82.893 Y95.662 E0.07705
+G1 X84.728 Y94.479 E0.04412
+G1 X86.370 Y92.522 E0.01380
+G1 X86.630 Y92.512 E0.08362
+G1 X87.724 Y92.666 E0.08987
+G1 X87.794 Y92.735 E0.06482
+G1 X87.352 Y92.166 E0.05758
+G1 X86.756 Y93.957 E0.06412
+G1 X86.857 Y92.353 E0.03995
+G1 X86.461 Y92.598 E0.05592
+G1 X87.980 Y94.456 E0.04894
+G1 X87.741 Y94.955 E0.08969
+G1 X87.114 Y95.075 E0.07527
+G1 X85.797 Y94.348 E0.08827
+G1 X87.101 Y94.398 E0.01884
+G1 X88.679 Y95.157 E0.07564
+G1 X90.640 Y96.710 E0.04367
+G1 X89.265 Y95.870 E0.05093
+G1 X89.285 Y94.622 E0.02459
+G1 X89.805 Y95.035 E0.03825
+G1 X91.780 Y95.581 E0.01339
+G1 X91.426 Y96.731 E0.03454
+G1 X92.189 Y94.747 E0.03436
+G1 X93.557 Y95.092 E0.06345
+G1 X92.344 Y95.083 E0.05426
+G1 X91.408 Y95.670 E0.05252
+G1 X93.397 Y95.968 E0.04289
+G1 X91.883 Y94.595 E0.07076
+G1 X90.309 Y92.996 E0.02364
+G1 X90.399 Y94.288 E0.05904
+G1 X91.626 Y92.537 E0.01100
+G1 X92.708 Y91.828 E0.06724
+G1 X92.123 Y90.506 E0.03133
+G1 X90.521 Y92.121 E0.05658
+G1 X89.917 Y91.921 E0.04085
+G1 X88.135 Y93.483 E0.05661
+G1 X89.974 Y93.241 E0.05961
+G1 X88.971 Y91.417 E0.08447
+G1 X90.390 Y90.676 E0.08191
+G1 X91.654 Y89.891 E0.05820
+G1 X93.494 Y89.873 E0.08598
+G1 X92.465 Y89.432 E0.06748
+G1 X91.351 Y88.669 E0.08002
+G1 X91.289 Y89.840 E0.02947
+G1 X89.982 Y89.274 E0.02492
+G1 X91.869 Y88.436 E0.05492
+G1 X90.328 Y88.571 E0.04085
+G1 X89.941 Y86.833 E0.01986
+G1 X91.244 Y86.238 E0.02959
+G1 X90.009 Y85.373 E0.02897
+G1 X88.149 Y86.030 E0.03731
+G1 X86.772 Y86.853 E0.01741
+G1 X85.851 Y88.193 E0.02022
+G1 X85.624 Y89.538 E0.07440
+G1 X84.261 Y88.950 E0.06780
+G1 X83.769 Y90.784 E0.02664
+G1 X85.572 Y90.803 E0.02818
+G1 X85.383 Y89.327 E0.06652
+G1 X84.426 Y90.925 E0.05701
+G1 X83.898 Y89.910 E0.05866
+G1 X82.748 Y91.400 E0.01982
+G1 X82.800 Y91.570 E0.03163
+G1 X83.887 Y91.110 E0.06260
+G1 X84.158 Y90.353 E0.04119
+G1 X82.502 Y89.061 E0.07808
+G1 X81.786 Y89.712 E0.01872
+G1 X82.034 Y89.158 E0.05003
+G1 X81.222 Y87.421 E0.03490
+G1 X80.128 Y85.926 E0.06734
+G1 X79.257 Y85.539 E0.08271
+G1 X80.357 Y87.071 E0.07890
+G1 X78.886 Y86.177 E0.01237
+G1 X79.605 Y86.831 E0.03811
+G1 X79.255 Y87.467 E0.06594
+G1 X78.249 Y88.854 E0.03817
+G1 X78.764 Y87.581 E0.01922
+G1 X80.415 Y88.517 E0.06701
+G1 X78.576 Y86.677 E0.02296
+G1 X77.369 Y85.889 E0.04046
+G1 X75.526 Y85.133 E0.06107
+G1 X74.244 Y86.491 E0.05561
+G1 X75.111 Y85.510 E0.04479
+G1 X75.848 Y84.906 E0.01008
+G1 X77.185 Y86.012 E0.03291
+G1 X75.357 Y87.428 E0.05859
+G1 X73.547 Y86.406 E0.01889
+G1 X74.712 Y85.247 E0.08316
+G1 X75.710 Y83.591 E0.06557
+G1 X75.285 Y84.581 E0.07630
+G1 X74.410 Y82.941 E0.08571
+G1 X74.106 Y84.662 E0.06533
+G1 X75.060 Y85.982 E0.06025
+G1 X74.871 Y84.199 E0.06586
+G1 X74.584 Y84.247 E0.08425
+G1 X73.095 Y85.294 E0.01350
+G1 X73.906 Y86.517 E0.03090
+G1 X74.092 Y88.395 E0.06100
+G1 X74.267 Y87.394 E0.01475
+G1 X73.699 Y87.040 E0.02611
+G1 X72.941 Y85.587 E0.06656
+G1 X73.622 Y84.538 E0.02934
+G1 X73.684 Y84.318 E0.08487
+G1 X73.090 Y83.516 E0.08077
+G1 X71.657 Y83.769 E0.03669
+G1 X72.919 Y83.962 E0.07084
+G1 X71.596 Y84.628 E0.05789
+G1 X71.440 Y85.693 E0.07649
+G1 X69.898 Y84.850 E0.03884
+G1 X68.724 Y83.091 E0.03247
+G1 X67.512 Y83.898 E0.04584
+G1 X65.964 Y83.196 E0.04749
+G1 X65.416 Y81.868 E0.01575
+G1 X63.459 Y83.836 E0.07004
+G1 X61.795 Y84.705 E0.08842
+G1 X62.050 Y83.140 E0.04911
+G1 X61.787 Y81.899 E0.05345
+G1 X59.820 Y83.578 E0.06156
+G1 X60.331 Y85.319 E0.06221
+G1 X59.337 Y84.303 E0.02109
+G1 X57.447 Y85.400 E0.07717
+G1 X56.633 Y84.143 E0.06105
+G1 X58.016 Y85.850 E0.02348
+G1 X59.154 Y87.172 E0.06939
+G1 X58.461 Y85.910 E0.07603
+G1 X57.741 Y85.384 E0.05409
+G1 X57.219 Y86.710 E0.02915
+G1 X55.384 Y86.977 E0.06026
+G1 X56.662 Y87.799 E0.08242
+G1 X58.442 Y87.777 E0.04996
+G1 X57.072 Y86.975 E0.05649
+G1 X55.393 Y87.727 E0.02309
+G1 X55.166 Y89.606 E0.01717
+G1 X53.326 Y89.364 E0.02527
+G1 X54.217 Y87.376 E0.07727
+G1 X55.639 Y88.523 E0.04404
+G1 X54.772 Y89.170 E0.05117
+G1 X54.457 Y88.524 E0.04510
+G1 X55.121 Y89.829 E0.08232
+G1 X53.779 Y89.012 E0.04545
+G1 X54.032 Y88.404 E0.02563
+G1 X52.372 Y87.699 E0.04684
+G1 X54.258 Y89.334 E0.07923
+G1 X56.155 Y91.181 E0.05959
+G1 X57.400 Y89.421 E0.06412
+G1 X57.836 Y88.609 E0.05569
+G1 X59.648 Y88.532 E0.06179
+G1 X58.845 Y87.906 E0.08081
+G1 X56.956 Y86.661 E0.06429
+G1 X56.746 Y85.002 E0.06284
+G1 X56.234 Y85.325 E0.04331
+G1 X56.354 Y85.584 E0.04171
+G1 X54.811 Y84.306 E0.08120
+G1 X55.003 Y82.755 E0.07897
+G1 X54.017 Y81.135 E0.05246
+G1 X53.023 Y81.092 E0.05432
+G1 X51.929 Y81.383 E0.01904
+G1 X51.982 Y81.737 E0.01642
+G1 X51.614 Y80.031 E0.04516
+G1 X53.068 Y80.233 E0.06717
+G1 X54.096 Y78.692 E0.08925
+G1 X54.982 Y77.100 E0.07642
+G1 X54.550 Y75.785 E0.08680
+G1 X54.802 Y76.885 E0.02094
+G1 X55.907 Y75.115 E0.02895
+G1 X55.396 Y73.176 E0.05754
+G1 X54.249 Y72.376 E0.06659
+G1 X53.953 Y73.930 E0.05969
+G1 X55.441 Y74.182 E0.08340
+G1 X56.924 Y72.854 E0.06963
+G1 X56.290 Y73.908 E0.06444
+G1 X57.592 Y72.399 E0.03984
+G1 X58.541 Y74.191 E0.06774
+G1 X56.715 Y74.607 E0.01797
+G1 X56.911 Y75.819 E0.01904
+G1 X58.612 Y76.519 E0.03037
+G1 X57.385 Y76.307 E0.07705
+G1 X57.710 Y74.761 E0.01168
+G1 X56.152 Y75.964 E0.02482
+G1 X56.369 Y75.124 E0.06497
+G1 X55.892 Y73.701 E0.08003
+G1 X56.046 Y74.459 E0.07466
+G1 X57.841 Y72.514 E0.03739
+G1 X56.445 Y72.521 E0.07984
+G1 X57.646 Y70.663 E0.02458
+G1 X58.920 Y71.381 E0.04141
+G1 X58.823 Y70.014 E0.07761
+G1 X58.396 Y71.506 E0.05887
+G1 X56.700 Y70.823 E0.02731
+G1 X58.276 Y71.180 E0.01349
+G1 X56.955 Y70.624 E0.04742
+G1 X57.263 Y70.176 E0.03829
+G1 X55.287 Y70.492 E0.03670
+G1 X53.369 Y70.330 E0.08891
+G1 X51.550 Y68.913 E0.06368
+G1 X50.641 Y68.007 E0.05000
+G1 X49.689 Y68.282 E0.05225
+G1 X51.517 Y70.251 E0.01273
+G1 X51.760 Y71.335 E0.07979
+G1 X52.857 Y71.867 E0.06077
+G1 X52.308 Y70.994 E0.07363
+G1 X53.800 Y72.748 E0.06451
+G1 X53.016 Y73.801 E0.06916
+G1 X53.051 Y74.342 E0.03803
+G1 X53.254 Y73.966 E0.01484
+G1 X52.603 Y73.259 E0.08907
+G1 X52.529 Y72.728 E0.02947
+G1 X51.468 Y72.125 E0.02085
+G1 X49.497 Y73.609 E0.04625
+G1 X49.279 Y73.884 E0.03419
+G1 X47.955 Y72.149 E0.03412
+G1 X47.189 Y73.056 E0.05410
+G1 X48.939 Y72.418 E0.08370
+G1 X49.272 Y70.738 E0.02430
+G1 X49.594 Y72.688 E0.03856
+G1 X50.692 Y72.401 E0.07946
+G1 X48.963 Y72.339 E0.08193
+G1 X48.066 Y71.369 E0.01185
+G1 X46.724 Y70.441 E0.06635
+G1 X45.598 Y70.039 E0.02603
+G1 X46.009 Y71.496 E0.06185
+G1 X44.796 Y72.431 E0.08705
+G1 X45.200 Y70.749 E0.07476
+G1 X46.702 Y70.113 E0.02093
+G1 X45.455 Y70.261 E0.08004
+G1 X46.015 Y71.952 E0.02698
+G1 X45.322 Y72.950 E0.06191
+G1 X44.943 Y73.666 E0.03702
+G1 X43.173 Y73.323 E0.01364
+G1 X43.678 Y72.661 E0.04955
+G1 X44.069 Y71.689 E0.04707
+G1 X42.124 Y73.390 E0.05513
+G1 X44.074 Y71.614 E0.05912
+G1 X44.970 Y70.931 E0.01748
+G1 X43.595 Y69.501 E0.07138
+G1 X41.955 Y70.757 E0.04386
+G1 X42.109 Y71.111 E0.05440
+G1 X42.739 Y71.518 E0.03647
+G1 X43.703 Y70.549 E0.06691
+G1 X44.756 Y71.653 E0.03474
+G1 X45.847 Y73.563 E0.04625
+G1 X44.960 Y73.656 E0.08528
+G1 X43.487 Y71.692 E0.04806
+G1 X44.109 Y72.789 E0.03900
+G1 X46.067 Y71.701 E0.07053
+G1 X44.426 Y69.813 E0.02073
+G1 X42.667 Y69.820 E0.05442
+G1 X41.394 Y71.579 E0.03925
+G1 X39.992 Y70.289 E0.06902
+G1 X41.677 Y68.938 E0.01232
+G1 X42.790 Y67.908 E0.08859
+G1 X42.786 Y68.452 E0.03754
+G1 X43.988 Y68.293 E0.03591
+G1 X45.602 Y66.724 E0.06867
+G1 X43.863 Y67.306 E0.04215
+G1 X45.320 Y65.546 E0.05514
+G1 X44.959 Y67.222 E0.08560
+G1 X45.468 Y66.119 E0.03015
+G1 X44.517 Y65.854 E0.02851
+G1 X43.330 Y66.890 E0.06142
+G1 X42.524 Y68.868 E0.02733
+G1 X42.802 Y67.495 E0.07905
+G1 X44.279 Y66.564 E0.07012
+G1 X45.570 Y65.694 E0.03652
+G1 X45.513 Y67.258 E0.02293
+G1 X46.244 Y67.648 E0.04624
+G1 X46.561 Y69.180 E0.02679
+G1 X48.095 Y68.621 E0.07239
+G1 X49.548 Y67.350 E0.07912
+G1 X51.527 Y66.541 E0.01195
+G1 X49.974 Y68.438 E0.01075
+G1 X51.620 Y67.041 E0.06888
+G1 X50.010 Y65.716 E0.06462
+G1 X48.371 Y65.074 E0.08348
+G1 X49.237 Y66.602 E0.08837
+G1 X47.368 Y65.541 E0.07337
+G1 X48.126 Y63.692 E0.05038
+G1 X47.053 Y63.414 E0.01839
+G1 X45.132 Y65.377 E0.03532
+G1 X46.647 Y63.859 E0.04899
+G1 X45.190 Y63.573 E0.02432
+G1 X45.932 Y62.165 E0.06906
+G1 X45.934 Y60.614 E0.03829
+G1 X45.919 Y62.289 E0.03796
+G1 X44.780 Y64.159 E0.08065
+G1 X45.706 Y63.251 E0.02418
+G1 X44.764 Y61.527 E0.01346
+G1 X44.799 Y61.159 E0.05453
+G1 X44.250 Y59.201 E0.06505
+G1 X44.862 Y59.377 E0.05390
+G1 X45.623 Y61.307 E0.07993
+G1 X46.494 Y60.904 E0.03546
+G1 X46.171 Y62.796 E0.04097
+G1 X45.713 Y62.435 E0.02144
+G1 X47.706 Y60.456 E0.05863
+G1 X49.411 Y59.475 E0.05887
+G1 X48.919 Y58.438 E0.02587
+G1 X47.384 Y59.810 E0.07272
+G1 X49.018 Y58.008 E0.06554
+G1 X48.315 Y58.593 E0.05392
+G1 X47.578 Y60.480 E0.01007
+G1 X48.563 Y61.894 E0.05081
+G1 X48.932 Y63.873 E0.02875
+G1 X49.450 Y64.846 E0.04031
+G1 X50.298 Y64.420 E0.05210
+G1 X50.750 Y65.129 E0.03577
+G1 X51.265 Y65.301 E0.02786
+G1 X51.715 Y64.361 E0.08270
+G1 X51.608 Y65.247 E0.05176
+G1 X51.515 Y64.132 E0.02137
+G1 X53.224 Y64.247 E0.05191
+G1 X53.334 Y65.500 E0.02909
+G1 X52.024 Y66.788 E0.04682
+G1 X52.586 Y68.098 E0.08152
+G1 X54.057 Y66.271 E0.04050
+G1 X55.385 Y67.542 E0.01984
+G1 X54.001 Y66.548 E0.01822
+G1 X53.427 Y67.761 E0.05171
+G1 X53.238 Y66.113 E0.04164
+G1 X55.226 Y66.893 E0.04595
+G1 X55.140 Y68.086 E0.07070
+G1 X53.739 Y68.806 E0.03935
+G1 X53.822 Y67.757 E0.03966
+G1 X53.182 Y67.282 E0.01142
+G1 X51.986 Y67.564 E0.01462
+G1 X50.699 Y68.436 E0.03197
+G1 X49.996 Y67.404 E0.07673
+G1 X48.361 Y67.948 E0.07871
+G1 X47.168 Y67.641 E0.07339
+G1 X47.639 Y67.127 E0.01351
+G1 X47.409 Y66.596 E0.06700
+G1 X46.590 Y66.228 E0.06185
+G1 X47.833 Y65.637 E0.04083
+G1 X48.148 Y67.336 E0.02533
+G1 X50.034 Y68.184 E0.03979
+G1 X50.696 Y67.502 E0.01566
+G1 X51.720 Y67.019 E0.05207
+G1 X51.707 Y68.625 E0.07056
+G1 X49.809 Y68.996 E0.04700
+G1 X49.658 Y70.354 E0.04319
+G1 X49.552 Y71.916 E0.04519
+G1 X49.517 Y71.963 E0.07597
+G1 X50.199 Y72.925 E0.04213
+G1 X48.361 Y73.644 E0.05431
+G1 X49.438 Y74.723 E0.01945
+G1 X48.321 Y73.032 E0.07540
+G1 X46.728 Y71.385 E0.07026
+G1 X46.985 Y69.605 E0.06448
+G1 X47.830 Y69.536 E0.01438
+G1 X48.594 Y69.208 E0.05672
+G1 X50.586 Y70.475 E0.07975
+G1 X49.168 Y69.813 E0.05146
+G1 X47.192 Y71.767 E0.03197
+G1 X46.242 Y71.019 E0.03040
+G1 X47.677 Y71.242 E0.05088
+G1 X47.358 Y69.447 E0.03436
+G1 X48.825 Y70.655 E0.07853
+G1 X47.853 Y69.463 E0.01417
+G1 X48.001 Y68.958 E0.04714
+G1 X47.957 Y69.293 E0.03926
+G1 X49.163 Y68.094 E0.08355
+G1 X49.387 Y66.299 E0.03514
+G1 X49.519 Y65.935 E0.05519
+G1 X48.814 Y65.029 E0.07369
+G1 X47.980 Y65.871 E0.07420
+G1 X48.348 Y65.689 E0.08479
+G1 X48.128 Y67.202 E0.01462
+G1 X47.863 Y67.759 E0.01392
+G1 X49.313 Y66.046 E0.05770
+G1 X48.034 Y67.736 E0.05488
+G1 X49.237 Y67.729 E0.06391
+G1 X49.936 Y66.909 E0.02688
+G1 X51.290 Y65.492 E0.08343
+G1 X50.117 Y63.895 E0.01762
+G1 X51.254 Y65.699 E0.04318
+G1 X51.890 Y64.729 E0.08247
+G1 X52.633 Y63.348 E0.01453
+G1 X53.416 Y61.515 E0.07689
+G1 X52.591 Y60.446 E0.05656
+G1 X51.866 Y60.688 E0.02232
+G1 X53.513 Y59.986 E0.07730
+G1 X52.121 Y61.183 E0.08841
+G1 X51.687 Y59.315 E0.04040
+G1 X52.250 Y58.209 E0.05366
+G1 X50.624 Y58.066 E0.06826
+G1 X50.344 Y58.782 E0.01915
+G1 X51.658 Y57.270 E0.08387
+G1 X53.642 Y59.028 E0.05211
+G1 X52.805 Y58.420 E0.07003
+G1 X52.792 Y60.139 E0.01744
+G1 X52.731 Y61.595 E0.05782
+G1 X52.893 Y59.949 E0.02118
+G1 X51.978 Y61.521 E0.07763
+G1 X50.887 Y63.220 E0.01259
+G1 X51.282 Y65.089 E0.03754
+G1 X53.060 Y65.715 E0.01400
+G1 X52.392 Y65.514 E0.02979
+G1 X53.362 Y64.229 E0.07302
+G1 X52.554 Y62.507 E0.05473
+G1 X50.937 Y62.713 E0.07304
+G1 X51.320 Y62.559 E0.01270
+G1 X51.373 Y60.948 E0.06174
+G1 X49.901 Y61.260 E0.03823
+G1 X49.400 Y61.912 E0.02311
+G1 X48.078 Y63.678 E0.03653
+G1 X49.448 Y65.172 E0.04842
+G1 X48.044 Y63.548 E0.08032
+G1 X46.512 Y63.533 E0.05288
+G1 X44.982 Y63.404 E0.02312
+G1 X45.124 Y63.431 E0.03935
+G1 X43.915 Y63.046 E0.02628
+G1 X42.424 Y62.005 E0.07972
+G1 X42.431 Y63.568 E0.01121
+G1 X44.204 Y63.522 E0.07328
+G1 X44.486 Y64.277 E0.02834
+G1 X45.486 Y62.892 E0.03113
+G1 X43.610 Y62.465 E0.05145
+G1 X42.777 Y64.027 E0.01675
+G1 X43.091 Y62.963 E0.05762
+G1 X44.227 Y63.806 E0.01497
+G1 X43.211 Y64.203 E0.08864
+G1 X41.375 Y64.676 E0.06535
+G1 X42.634 Y64.044 E0.07484
+G1 X42.481 Y65.727 E0.01086
+G1 X44.242 Y65.375 E0.04257
+G1 X42.595 Y64.354 E0.06870
+G1 X43.310 Y62.959 E0.03755
+G1 X41.871 Y61.752 E0.02757
+G1 X41.196 Y63.656 E0.08978
+G1 X42.362 Y63.575 E0.04979
+G1 X43.479 Y65.207 E0.07012
+G1 X44.024 Y64.004 E0.06001
+G1 X45.407 Y65.150 E0.01739
+G1 X46.277 Y64.547 E0.02298
+G1 X48.140 Y65.238 E0.06964
+G1 X46.680 Y66.551 E0.08497
+G1 X48.299 Y67.531 E0.07660
+G1 X49.508 Y67.893 E0.04483
+G1 X50.808 Y69.031 E0.07967
+G1 X50.004 Y70.874 E0.05253
+G1 X51.788 Y69.338 E0.08748
+G1 X52.938 Y68.346 E0.07707
+G1 X51.866 Y67.138 E0.04663
+G1 X50.813 Y67.108 E0.08265
+G1 X51.554 Y67.950 E0.04136
+G1 X52.690 Y69.124 E0.06463
+G1 X54.456 Y70.427 E0.04250
+G1 X52.805 Y71.037 E0.07690
+G1 X52.163 Y71.417 E0.07690
+G1 X53.335 Y69.435 E0.04912
+G1 X51.400 Y67.877 E0.07499
+G1 X51.075 Y68.296 E0.04660
+G1 X50.417 Y67.151 E0.03830
+G1 X51.795 Y67.628 E0.03337
+G1 X50.147 Y66.712 E0.06609
+G1 X49.915 Y67.356 E0.07457
+G1 X48.398 Y68.088 E0.01332
+G1 X49.689 Y66.824 E0.03172
+G1 X51.520 Y66.274 E0.02794
+G1 X53.080 Y66.715 E0.08151
+G1 X52.657 Y66.713 E0.08646
+G1 X52.684 Y68.668 E0.02516
+G1 X54.007 Y67.316 E0.05218
+G1 X52.008 Y66.018 E0.08560
+G1 X51.826 Y67.255 E0.03006
+G1 X51.236 Y65.659 E0.05421
+G1 X52.685 Y65.715 E0.04014
+G1 X54.399 Y67.290 E0.06330
+G1 X52.703 Y67.786 E0.04553
+G1 X54.534 Y67.233 E0.06289
+G1 X55.062 Y66.737 E0.05177
+G1 X55.768 Y68.365 E0.04985
+G1 X55.223 Y70.270 E0.01456
+G1 X56.562 Y71.004 E0.05459
+G1 X56.353 Y72.009 E0.08129
+G1 X57.268 Y73.008 E0.01281
+G1 X56.569 Y71.556 E0.08624
+G1 X58.135 Y70.134 E0.05700
+G1 X58.442 Y68.321 E0.04138
+G1 X59.431 Y68.887 E0.03247
+G1 X60.481 Y68.051 E0.05354
+G1 X60.164 Y69.964 E0.06190
+G1 X61.384 Y70.670 E0.04044
+G1 X63.236 Y71.509 E0.06527
+G1 X62.346 Y70.156 E0.05601
+G1 X63.649 Y71.331 E0.03778
+G1 X62.209 Y71.395 E0.08019
+G1 X60.857 Y72.348 E0.02365
+G1 X60.105 Y70.562 E0.03381
+G1 X59.637 Y72.430 E0.08697
+G1 X58.386 Y71.667 E0.08550
+G1 X57.175 Y70.951 E0.04506
+G1 X55.609 Y69.992 E0.04152
+G1 X55.151 Y71.846 E0.03135
+G1 X53.967 Y73.481 E0.04602
+G1 X55.315 Y74.030 E0.07229
+M73 P100 R0
+M73 Q100 S0
+M107
+; filament used [mm] = 152340.27, 10234.55
+; filament used [cm3] = 366.42, 24.62
+; filament used [g] = 465.35, 30.53
+; filament cost = 11.82, 0.64
+; total filament used for wipe tower [g] = 12.08
+; total filament used [g] = 495.88
+; total filament cost = 12.46
+; total layers count = 812
+; estimated printing time (normal mode) = 1d 3h 4m 5s
+; estimated first layer printing time (normal mode) = 9m 12s
+; estimated printing time (silent mode) = 1d 5h 12m 40s
+; estimated first layer printing time (silent mode) = 9m 30s
+
+; prusaslicer_config = begin
+; autoemit_temperature_commands = 1
+; avoid_crossing_curled_overhangs = 0
+; bed_shape = 0x0,250x0,250x210,0x210
+; bed_temperature = 90,60
+; binary_gcode = 0
+; bottom_solid_layers = 5
+; compatible_printers_condition_cummulative = "printer_notes=~/.*PRINTER_VENDOR_PRUSA3D.*/ and printer_notes=~/.*PRINTER_MODEL_MK3.*/ and nozzle_diameter[0]==0.4";"printer_notes=~/.*PRINTER_VENDOR_PRUSA3D.*/ and printer_notes=~/.*PRINTER_MODEL_MK3.*/ and nozzle_diameter[0]!=0.8"
+; extrusion_multiplier = 1,1
+; fill_density = 15%
+; filament_colour = #FF8000;#FFFFFF
+; filament_cost = 25.4,20.99
+; filament_density = 1.27,1.24
+; filament_diameter = 1.75,1.75
+; filament_settings_id = "Prusament PETG";"Prusament PLA"
+; filament_type = PETG;PLA
+; first_layer_height = 0.2
+; layer_height = 0.15
+; machine_max_feedrate_e = 120,120
+; max_print_speed = 200
+; nozzle_diameter = 0.4,0.4
+; perimeters = 2
+; print_settings_id = 0.15mm QUALITY @MK3
+; printer_model = MK3S
+; printer_settings_id = Original Prusa i3 MK3S & MK3S+ MMU2S
+; silent_mode = 1
+; single_extruder_multi_material = 1
+; temperature = 240,215
+; wipe_tower = 1
+; prusaslicer_config = end